@@ -0,0 +1,338 @@
+//! This module contains a data dictionary which is built at run-time.
+//!
+//! Entries can be added one by one, or loaded from the JSON file produced
+//! by `dicom-dictionary-builder` when the `json` feature is enabled.
+//! The file is an object mapping each tag to an entry of the following form:
+//!
+//! ```json
+//! { "tag": "(0010,0010)", "name": "Patient's Name", "alias": "PatientName", "vr": "PN", "vm": "1" }
+//! ```
+
+use super::{DataDictionary, DictionaryEntryBuf, TagRange};
+use crate::header::Tag;
+use std::collections::HashMap;
+
+#[cfg(feature = "json")]
+use super::{ValueMultiplicity, VirtualVr};
+#[cfg(feature = "json")]
+use crate::header::VR;
+#[cfg(feature = "json")]
+use quick_error::quick_error;
+#[cfg(feature = "json")]
+use serde::Deserialize;
+#[cfg(feature = "json")]
+use std::collections::BTreeMap;
+#[cfg(feature = "json")]
+use std::fs::File;
+#[cfg(feature = "json")]
+use std::io::{self, BufReader, Read};
+#[cfg(feature = "json")]
+use std::path::Path;
+
+#[cfg(feature = "json")]
+quick_error! {
+    /// An error which may occur when loading a data dictionary.
+    #[derive(Debug)]
+    pub enum LoadDictionaryError {
+        /// The dictionary file could not be read.
+        Io(err: io::Error) {
+            description("failed to read dictionary")
+            from()
+            display("failed to read dictionary: {}", err)
+        }
+        /// The dictionary file is not valid JSON of the expected format.
+        Json(err: serde_json::Error) {
+            description("invalid JSON dictionary")
+            from()
+            display("invalid JSON dictionary: {}", err)
+        }
+    }
+}
+
+/// An entry of a JSON dictionary, as written by `dicom-dictionary-builder`.
+#[cfg(feature = "json")]
+#[derive(Debug, Deserialize)]
+struct JsonEntry {
+    tag: String,
+    name: Option<String>,
+    alias: Option<String>,
+    vr: Option<String>,
+    vm: Option<String>,
+    obs: Option<String>,
+}
+
+/// A data dictionary with entries owned in memory,
+/// which can be extended at run-time.
+///
+/// ```
+/// # use dicom_core::dictionary::{DataDictionary, DictionaryEntry, DictionaryEntryBuf, InMemDataDictionary, TagRange, ValueMultiplicity};
+/// # use dicom_core::{Tag, VR};
+/// let dict = InMemDataDictionary::new().with_entry(DictionaryEntryBuf {
+///     tag: TagRange::Single(Tag(0x0011, 0x1001)),
+///     alias: "SiteRoutingCode".to_string(),
+///     name: Some("Site Routing Code".to_string()),
+///     vr: VR::LO.into(),
+///     vm: Some(ValueMultiplicity::ONE),
+///     retired: false,
+/// });
+/// assert_eq!(dict.by_name("SiteRoutingCode").map(|e| e.tag()), Some(Tag(0x0011, 0x1001)));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InMemDataDictionary {
+    /// mapping: tag (with open portions zeroed) → entry
+    by_tag: HashMap<Tag, DictionaryEntryBuf>,
+    /// mapping: alias → tag
+    by_name: HashMap<String, Tag>,
+    /// mapping: private creator → (group, element low byte) → entry
+    by_private: HashMap<String, HashMap<(u16, u8), DictionaryEntryBuf>>,
+}
+
+impl InMemDataDictionary {
+    /// Create a new empty dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an entry to the dictionary, replacing any existing entry
+    /// with the same tag.
+    pub fn insert(&mut self, entry: DictionaryEntryBuf) -> &mut Self {
+        let tag = entry.tag.inner();
+        if let Some(old) = self.by_tag.get(&tag) {
+            self.by_name.remove(&old.alias);
+        }
+        self.by_name.insert(entry.alias.clone(), tag);
+        self.by_tag.insert(tag, entry);
+        self
+    }
+
+    /// Add an entry to the dictionary, replacing any existing entry
+    /// with the same tag.
+    pub fn with_entry(mut self, entry: DictionaryEntryBuf) -> Self {
+        self.insert(entry);
+        self
+    }
+
+    /// Add a private attribute entry to the dictionary, reserved by the
+    /// given private creator. The tag of the entry should have the form
+    /// `(gggg,00ee)`, where `ee` is the low byte of the element number.
+    pub fn insert_private<S: Into<String>>(
+        &mut self,
+        creator: S,
+        entry: DictionaryEntryBuf,
+    ) -> &mut Self {
+        let tag = entry.tag.inner();
+        self.by_private
+            .entry(creator.into())
+            .or_default()
+            .insert((tag.group(), tag.element() as u8), entry);
+        self
+    }
+
+    /// Add a private attribute entry to the dictionary, reserved by the
+    /// given private creator.
+    pub fn with_private_entry<S: Into<String>>(
+        mut self,
+        creator: S,
+        entry: DictionaryEntryBuf,
+    ) -> Self {
+        self.insert_private(creator, entry);
+        self
+    }
+
+    /// Retrieve the number of public attribute entries in the dictionary.
+    pub fn len(&self) -> usize {
+        self.by_tag.len()
+    }
+
+    /// Check whether the dictionary has no public attribute entries.
+    pub fn is_empty(&self) -> bool {
+        self.by_tag.is_empty()
+    }
+}
+
+#[cfg(feature = "json")]
+impl InMemDataDictionary {
+    /// Load a dictionary from a JSON file produced by
+    /// `dicom-dictionary-builder`.
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self, LoadDictionaryError> {
+        Self::from_json_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Load a dictionary from JSON data in the format produced by
+    /// `dicom-dictionary-builder`.
+    ///
+    /// Entries without an alias or with a tag which cannot be represented
+    /// as a [`TagRange`] are ignored. Entries without a known VR are
+    /// recorded as `UN`, and the value multiplicity is left unknown if it
+    /// cannot be parsed. When more than one value multiplicity is listed,
+    /// the first one is used.
+    pub fn from_json_reader<R: Read>(reader: R) -> Result<Self, LoadDictionaryError> {
+        let entries: BTreeMap<String, JsonEntry> = serde_json::from_reader(reader)?;
+        let mut dict = Self::new();
+        for entry in entries.into_values() {
+            let alias = match entry.alias {
+                Some(alias) if !alias.is_empty() => alias,
+                _ => continue,
+            };
+            let tag = match entry.tag.parse::<TagRange>() {
+                Ok(tag) => tag,
+                Err(_) => continue,
+            };
+            let vr = match entry.vr.as_ref().map(|vr| vr.parse::<VirtualVr>()) {
+                Some(Ok(VirtualVr::Ox)) if is_pixel_data_like(tag) => VirtualVr::Px,
+                Some(Ok(vr)) => vr,
+                _ => VirtualVr::Exact(VR::UN),
+            };
+            let vm = entry
+                .vm
+                .as_ref()
+                .and_then(|vm| vm.split(" or ").next())
+                .and_then(|vm| vm.parse::<ValueMultiplicity>().ok());
+            let retired = entry.obs.as_ref().is_some_and(|obs| obs.starts_with("RET"));
+            dict.insert(DictionaryEntryBuf {
+                tag,
+                alias,
+                name: entry.name.filter(|name| !name.is_empty()),
+                vr,
+                vm,
+                retired,
+            });
+        }
+        Ok(dict)
+    }
+}
+
+/// Check whether the attribute is pixel data or overlay data,
+/// of which the VR is `OB or OW` but always `OW` in implicit VR.
+#[cfg(feature = "json")]
+fn is_pixel_data_like(tag: TagRange) -> bool {
+    matches!(
+        tag,
+        TagRange::Single(Tag(0x7FE0, 0x0010))
+            | TagRange::Group100(Tag(0x6000, 0x3000))
+            | TagRange::Group100(Tag(0x7F00, 0x0010))
+    )
+}
+
+impl DataDictionary for InMemDataDictionary {
+    type Entry = DictionaryEntryBuf;
+
+    fn by_name(&self, name: &str) -> Option<&DictionaryEntryBuf> {
+        self.by_name.get(name).and_then(|tag| self.by_tag.get(tag))
+    }
+
+    fn by_tag(&self, tag: Tag) -> Option<&DictionaryEntryBuf> {
+        if let Some(entry) = self.by_tag.get(&tag) {
+            return Some(entry);
+        }
+        let group_trimmed = Tag(tag.0 & 0xFF00, tag.1);
+        if let Some(
+            e @ DictionaryEntryBuf {
+                tag: TagRange::Group100(_),
+                ..
+            },
+        ) = self.by_tag.get(&group_trimmed)
+        {
+            return Some(e);
+        }
+        let elem_trimmed = Tag(tag.0, tag.1 & 0xFF00);
+        match self.by_tag.get(&elem_trimmed) {
+            Some(
+                e @ DictionaryEntryBuf {
+                    tag: TagRange::Element100(_),
+                    ..
+                },
+            ) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn by_private_tag(
+        &self,
+        creator: &str,
+        group: u16,
+        element: u8,
+    ) -> Option<&DictionaryEntryBuf> {
+        self.by_private
+            .get(creator)
+            .and_then(|entries| entries.get(&(group, element)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::DictionaryEntry;
+    use crate::header::VR;
+
+    #[cfg(feature = "json")]
+    const JSON: &str = r#"{
+        "(0010,0010)": {"tag": "(0010,0010)", "name": "Patient's Name", "alias": "PatientName", "vr": "PN", "vm": "1"},
+        "(60xx,3000)": {"tag": "(60xx,3000)", "name": "Overlay Data", "alias": "OverlayData", "vr": "OB or OW", "vm": "1"},
+        "(0020,31xx)": {"tag": "(0020,31xx)", "name": "Source Image IDs", "alias": "SourceImageIDs", "vr": "CS", "vm": "1-n", "obs": "RET"},
+        "(0028,04x0)": {"tag": "(0028,04x0)", "name": "Rows For Nth Order Coefficients", "alias": "RowsForNthOrderCoefficients", "vr": "US", "vm": "1", "obs": "RET"},
+        "(FFFE,E000)": {"tag": "(FFFE,E000)", "name": "Item", "alias": "Item", "vr": "See Note", "vm": "1"},
+        "(0018,9445)": {"tag": "(0018,9445)", "name": null, "alias": null, "vr": null, "vm": null}
+    }"#;
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn load_from_json() {
+        use crate::dictionary::{ValueMultiplicity, VirtualVr};
+
+        let dict = InMemDataDictionary::from_json_reader(JSON.as_bytes()).unwrap();
+        assert_eq!(dict.len(), 4);
+
+        let e = dict.by_name("PatientName").unwrap();
+        assert_eq!(e.tag(), Tag(0x0010, 0x0010));
+        assert_eq!(e.name(), Some("Patient's Name"));
+        assert_eq!(e.vr(), VR::PN);
+        assert_eq!(e.vm(), Some(ValueMultiplicity::ONE));
+        assert!(!e.is_retired());
+
+        let e = dict.by_tag(Tag(0x6002, 0x3000)).unwrap();
+        assert_eq!(e.alias(), "OverlayData");
+        assert_eq!(e.vr(), VR::OB);
+        assert_eq!(e.virtual_vr(), VirtualVr::Px);
+
+        let e = dict.by_tag(Tag(0x0020, 0x3105)).unwrap();
+        assert_eq!(e.alias(), "SourceImageIDs");
+        assert_eq!(
+            e.vm(),
+            Some(ValueMultiplicity {
+                min: 1,
+                max: None,
+                step: 1
+            })
+        );
+        assert!(e.is_retired());
+
+        assert_eq!(dict.by_tag(Tag(0xFFFE, 0xE000)).unwrap().vr(), VR::UN);
+        assert!(dict.by_tag(Tag(0x0018, 0x9445)).is_none());
+        assert!(dict.by_tag(Tag(0x0010, 0x0011)).is_none());
+    }
+
+    #[test]
+    fn replace_entries() {
+        let mut dict = InMemDataDictionary::new();
+        dict.insert(DictionaryEntryBuf {
+            tag: TagRange::Single(Tag(0x0009, 0x1001)),
+            alias: "Foo".to_string(),
+            name: None,
+            vr: VR::LO.into(),
+            vm: None,
+            retired: false,
+        });
+        dict.insert(DictionaryEntryBuf {
+            tag: TagRange::Single(Tag(0x0009, 0x1001)),
+            alias: "Bar".to_string(),
+            name: None,
+            vr: VR::SH.into(),
+            vm: None,
+            retired: false,
+        });
+        assert!(dict.by_name("Foo").is_none());
+        assert_eq!(dict.by_name("Bar").unwrap().vr(), VR::SH);
+        assert_eq!(dict.len(), 1);
+    }
+}
@@ -0,0 +1,201 @@
+//! This module contains a data dictionary composed of other dictionaries.
+
+use super::{DataDictionary, DictionaryEntry};
+use crate::header::Tag;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// A dictionary entry behind a trait object.
+type DynEntry = dyn DictionaryEntry + 'static;
+
+/// Object safe counterpart of [`DataDictionary`],
+/// with entries behind trait objects.
+trait DynDataDictionary: Debug + Send + Sync {
+    fn by_name(&self, name: &str) -> Option<&DynEntry>;
+
+    fn by_tag(&self, tag: Tag) -> Option<&DynEntry>;
+
+    fn by_private_tag(&self, creator: &str, group: u16, element: u8) -> Option<&DynEntry>;
+}
+
+fn erase<E: DictionaryEntry + 'static>(entry: &E) -> &DynEntry {
+    entry
+}
+
+impl<D> DynDataDictionary for D
+where
+    D: DataDictionary + Send + Sync,
+    D::Entry: Sized + 'static,
+{
+    fn by_name(&self, name: &str) -> Option<&DynEntry> {
+        DataDictionary::by_name(self, name).map(erase)
+    }
+
+    fn by_tag(&self, tag: Tag) -> Option<&DynEntry> {
+        DataDictionary::by_tag(self, tag).map(erase)
+    }
+
+    fn by_private_tag(&self, creator: &str, group: u16, element: u8) -> Option<&DynEntry> {
+        DataDictionary::by_private_tag(self, creator, group, element).map(erase)
+    }
+}
+
+/// A data dictionary made of a stack of other dictionaries.
+///
+/// Lookups are performed from the top of the stack to the bottom, so
+/// layers added later take precedence over the ones added before.
+/// An entry overridden by tag in a higher layer is hidden from lookups
+/// by name as well, so both kinds of lookup agree on the entries in effect.
+/// A typical use is to place site-specific entries on top of the
+/// standard dictionary:
+///
+/// ```
+/// # use dicom_core::dictionary::{DataDictionary, DictionaryEntry, DictionaryEntryBuf, InMemDataDictionary, LayeredDataDictionary, TagRange, ValueMultiplicity};
+/// # use dicom_core::dictionary::stub::StubDataDictionary;
+/// # use dicom_core::{Tag, VR};
+/// # let standard = StubDataDictionary;
+/// let site = InMemDataDictionary::new().with_entry(DictionaryEntryBuf {
+///     tag: TagRange::Single(Tag(0x0011, 0x1001)),
+///     alias: "SiteRoutingCode".to_string(),
+///     name: Some("Site Routing Code".to_string()),
+///     vr: VR::LO.into(),
+///     vm: Some(ValueMultiplicity::ONE),
+///     retired: false,
+/// });
+/// let dict = LayeredDataDictionary::new()
+///     .with_layer(standard)
+///     .with_layer(site);
+/// assert_eq!(dict.by_tag(Tag(0x0011, 0x1001)).map(|e| e.vr()), Some(VR::LO));
+/// ```
+///
+/// Layers are shared, so cloning the dictionary is cheap.
+#[derive(Debug, Default, Clone)]
+pub struct LayeredDataDictionary {
+    /// the layers, from the bottom to the top of the stack
+    layers: Vec<Arc<dyn DynDataDictionary>>,
+}
+
+impl LayeredDataDictionary {
+    /// Create a new dictionary without any layers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a dictionary to the top of the stack, so that it takes
+    /// precedence over all layers added before.
+    pub fn push_layer<D>(&mut self, dict: D) -> &mut Self
+    where
+        D: DataDictionary + Send + Sync + 'static,
+        D::Entry: Sized + 'static,
+    {
+        self.layers.push(Arc::new(dict));
+        self
+    }
+
+    /// Add a dictionary to the top of the stack, so that it takes
+    /// precedence over all layers added before.
+    pub fn with_layer<D>(mut self, dict: D) -> Self
+    where
+        D: DataDictionary + Send + Sync + 'static,
+        D::Entry: Sized + 'static,
+    {
+        self.push_layer(dict);
+        self
+    }
+
+    /// Retrieve the number of layers in the dictionary.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Check whether the dictionary has no layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+impl DataDictionary for LayeredDataDictionary {
+    type Entry = DynEntry;
+
+    fn by_name(&self, name: &str) -> Option<&Self::Entry> {
+        self.layers
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, d)| d.by_name(name).map(|e| (i, e)))
+            .find(|(i, e)| {
+                let tag = e.tag();
+                self.layers[i + 1..].iter().all(|d| d.by_tag(tag).is_none())
+            })
+            .map(|(_, e)| e)
+    }
+
+    fn by_tag(&self, tag: Tag) -> Option<&Self::Entry> {
+        self.layers.iter().rev().find_map(|d| d.by_tag(tag))
+    }
+
+    fn by_private_tag(&self, creator: &str, group: u16, element: u8) -> Option<&Self::Entry> {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|d| d.by_private_tag(creator, group, element))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::stub::StubDataDictionary;
+    use crate::dictionary::{DictionaryEntryBuf, InMemDataDictionary, TagRange};
+    use crate::VR;
+
+    fn entry(tag: Tag, alias: &str, vr: VR) -> DictionaryEntryBuf {
+        DictionaryEntryBuf {
+            tag: TagRange::Single(tag),
+            alias: alias.to_string(),
+            name: None,
+            vr: vr.into(),
+            vm: None,
+            retired: false,
+        }
+    }
+
+    #[test]
+    fn top_layer_takes_precedence() {
+        let base = InMemDataDictionary::new()
+            .with_entry(entry(Tag(0x0010, 0x0010), "PatientName", VR::PN))
+            .with_entry(entry(Tag(0x0009, 0x1001), "Foo", VR::LO));
+        let site = InMemDataDictionary::new()
+            .with_entry(entry(Tag(0x0009, 0x1001), "Bar", VR::SH))
+            .with_private_entry("ACME 1.0", entry(Tag(0x0009, 0x0002), "Baz", VR::DS));
+        let dict = LayeredDataDictionary::new()
+            .with_layer(StubDataDictionary)
+            .with_layer(base)
+            .with_layer(site);
+        assert_eq!(dict.len(), 3);
+
+        assert_eq!(
+            dict.by_tag(Tag(0x0010, 0x0010)).map(|e| e.alias()),
+            Some("PatientName")
+        );
+        assert_eq!(
+            dict.by_tag(Tag(0x0009, 0x1001)).map(|e| e.vr()),
+            Some(VR::SH)
+        );
+        // the overridden entry is hidden by name as well
+        assert!(dict.by_name("Foo").is_none());
+        assert_eq!(
+            dict.by_name("Bar").map(|e| e.tag()),
+            Some(Tag(0x0009, 0x1001))
+        );
+        assert_eq!(
+            dict.by_private_tag("ACME 1.0", 0x0009, 0x02)
+                .map(|e| e.alias()),
+            Some("Baz")
+        );
+        assert!(dict.by_tag(Tag(0x0008, 0x0060)).is_none());
+        assert!(LayeredDataDictionary::new()
+            .by_name("PatientName")
+            .is_none());
+    }
+}
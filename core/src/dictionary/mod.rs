@@ -2,9 +2,15 @@
 //!
 //! The standard data dictionary is available in the `dicom-std-dict` crate.
 
+pub mod inmem;
+pub mod layered;
 pub mod stub;
 
+pub use self::inmem::InMemDataDictionary;
+pub use self::layered::LayeredDataDictionary;
+
 use crate::header::{Tag, VR};
+use std::fmt;
 use std::fmt::Debug;
 use std::str::FromStr;
 
@@ -39,6 +45,14 @@ impl TagRange {
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
 pub struct TagRangeParseError(&'static str);
 
+impl fmt::Display for TagRangeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for TagRangeParseError {}
+
 impl FromStr for TagRange {
     type Err = TagRangeParseError;
 
@@ -105,7 +119,7 @@ impl FromStr for TagRange {
  */
 pub trait DataDictionary: Debug {
     /// The type of the dictionary entry.
-    type Entry: DictionaryEntry;
+    type Entry: DictionaryEntry + ?Sized;
 
     /// Fetch an entry by its usual alias (e.g. "PatientName" or "SOPInstanceUID").
     /// Aliases are usually case sensitive and not separated by spaces.
@@ -113,6 +127,60 @@ pub trait DataDictionary: Debug {
 
     /// Fetch an entry by its tag.
     fn by_tag(&self, tag: Tag) -> Option<&Self::Entry>;
+
+    /// Fetch the entry of a private attribute, identified by the value of
+    /// its private creator data element, its group, and the low byte of
+    /// its element number (the high byte being the reserved block).
+    ///
+    /// The tag of the returned entry has the form `(gggg,00ee)`.
+    /// Dictionaries without private attributes always return `None`.
+    fn by_private_tag(&self, _creator: &str, _group: u16, _element: u8) -> Option<&Self::Entry> {
+        None
+    }
+
+    /// Parse a tag from text, either in one of the forms accepted by
+    /// [`Tag::from_str`] (e.g. `"(0010,0010)"`) or as the alias of an
+    /// attribute in this dictionary (e.g. `"PatientName"`).
+    ///
+    /// Attributes of a repeating group or element resolve to the
+    /// first tag of the range.
+    fn parse_tag(&self, tag: &str) -> Option<Tag> {
+        tag.parse()
+            .ok()
+            .or_else(|| self.by_name(tag.trim()).map(|e| e.tag()))
+    }
+
+    /// Obtain a display adapter for the given tag, which writes the tag
+    /// followed by the alias of its attribute in this dictionary, if any
+    /// (e.g. `(0010,0010) PatientName`).
+    fn display_tag(&self, tag: Tag) -> TagDisplay<'_, Self>
+    where
+        Self: Sized,
+    {
+        TagDisplay { tag, dict: self }
+    }
+}
+
+/// A display adapter for a tag along with the alias of its attribute
+/// in a data dictionary, created with [`DataDictionary::display_tag`].
+///
+/// The tag is written alone if it is not in the dictionary.
+#[derive(Debug, Clone, Copy)]
+pub struct TagDisplay<'a, D: ?Sized> {
+    tag: Tag,
+    dict: &'a D,
+}
+
+impl<D: ?Sized> fmt::Display for TagDisplay<'_, D>
+where
+    D: DataDictionary,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.dict.by_tag(self.tag) {
+            Some(entry) => write!(f, "{} {}", self.tag, entry.alias()),
+            None => write!(f, "{}", self.tag),
+        }
+    }
 }
 
 /// The dictionary entry data type, representing a DICOM attribute.
@@ -132,6 +200,200 @@ pub trait DictionaryEntry {
     /// The _typical_ value representation of the attribute.
     /// In some edge cases, an element might not have this VR.
     fn vr(&self) -> VR;
+    /// The value representation of the attribute as declared in the
+    /// standard, which may comprise more than one alternative
+    /// (e.g. `US or SS`).
+    fn virtual_vr(&self) -> VirtualVr {
+        VirtualVr::Exact(self.vr())
+    }
+    /// The value multiplicity of the attribute, if known.
+    fn vm(&self) -> Option<ValueMultiplicity> {
+        None
+    }
+    /// The full name of the attribute as written in the standard
+    /// (e.g. "Patient's Name"), if known.
+    fn name(&self) -> Option<&str> {
+        None
+    }
+    /// Whether the attribute has been retired from the standard.
+    fn is_retired(&self) -> bool {
+        false
+    }
+}
+
+/// The value representation of an attribute as declared in a dictionary.
+///
+/// A few attributes admit more than one VR, in which case the actual VR
+/// of an element depends on the context of the data set it belongs to.
+/// This only matters when the VR is not encoded explicitly.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VirtualVr {
+    /// A single, well determined value representation
+    Exact(VR),
+    /// `US or SS`: the VR depends on _Pixel Representation_ (0028,0103)
+    Xs,
+    /// `OB or OW`: the VR depends on the number of bits allocated
+    Ox,
+    /// `OB or OW` for pixel data and overlay data,
+    /// which is always `OW` in Implicit VR Little Endian
+    Px,
+    /// `US or OW` (or `US or SS or OW`) for lookup table data,
+    /// which is always `OW` in Implicit VR Little Endian
+    Lt,
+}
+
+impl VirtualVr {
+    /// Retrieve the single VR to assume when no context is available:
+    /// the VR itself if exact, or the first of the alternatives otherwise.
+    pub fn relaxed(self) -> VR {
+        match self {
+            VirtualVr::Exact(vr) => vr,
+            VirtualVr::Xs | VirtualVr::Lt => VR::US,
+            VirtualVr::Ox | VirtualVr::Px => VR::OB,
+        }
+    }
+
+    /// Retrieve the exact VR, if the declaration is not ambiguous.
+    pub fn exact(self) -> Option<VR> {
+        match self {
+            VirtualVr::Exact(vr) => Some(vr),
+            _ => None,
+        }
+    }
+}
+
+impl From<VR> for VirtualVr {
+    fn from(vr: VR) -> Self {
+        VirtualVr::Exact(vr)
+    }
+}
+
+impl FromStr for VirtualVr {
+    type Err = VirtualVrParseError;
+
+    /// Parse a value representation as written in the standard,
+    /// such as `PN`, `US or SS` or `OB or OW`.
+    ///
+    /// `OB or OW` is always parsed as [`VirtualVr::Ox`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "US or SS" => Ok(VirtualVr::Xs),
+            "OB or OW" => Ok(VirtualVr::Ox),
+            "US or OW" | "US or SS or OW" => Ok(VirtualVr::Lt),
+            s => s
+                .parse::<VR>()
+                .map(VirtualVr::Exact)
+                .map_err(|_e| VirtualVrParseError("unknown value representation")),
+        }
+    }
+}
+
+/// An error returned when parsing an invalid value representation.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct VirtualVrParseError(&'static str);
+
+/// The value multiplicity (VM) of an attribute: the number of values
+/// that an element of this attribute may contain.
+///
+/// A multiplicity is described by a minimum number of values, an optional
+/// maximum, and a step. For example, `2-2n` is a minimum of 2 without
+/// a maximum, in steps of 2.
+///
+/// ```
+/// # use dicom_core::dictionary::ValueMultiplicity;
+/// let vm = ValueMultiplicity { min: 2, max: None, step: 2 };
+/// assert!(vm.contains(2));
+/// assert!(!vm.contains(3));
+/// assert!(vm.contains(4));
+/// assert_eq!(vm.to_string(), "2-2n");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ValueMultiplicity {
+    /// The minimum number of values.
+    pub min: u32,
+    /// The maximum number of values, or `None` if unbounded.
+    pub max: Option<u32>,
+    /// The step between allowed numbers of values.
+    pub step: u32,
+}
+
+impl ValueMultiplicity {
+    /// A multiplicity of exactly one value.
+    pub const ONE: Self = ValueMultiplicity {
+        min: 1,
+        max: Some(1),
+        step: 1,
+    };
+
+    /// Check whether the given number of values is allowed.
+    pub fn contains(self, n: u32) -> bool {
+        n >= self.min
+            && self.max.map(|max| n <= max).unwrap_or(true)
+            && (n - self.min).is_multiple_of(self.step.max(1))
+    }
+}
+
+/// An error returned when parsing an invalid value multiplicity.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ValueMultiplicityParseError(&'static str);
+
+impl FromStr for ValueMultiplicity {
+    type Err = ValueMultiplicityParseError;
+
+    /// Parse a value multiplicity in the notation of the standard,
+    /// such as `1`, `1-3`, `1-n` or `2-2n`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (min, max) = match s.find('-') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+        let min = min
+            .parse()
+            .map_err(|_e| ValueMultiplicityParseError("invalid minimum number of values"))?;
+        match max {
+            None => Ok(ValueMultiplicity {
+                min,
+                max: Some(min),
+                step: 1,
+            }),
+            Some(max) if max.ends_with('n') => {
+                let step = &max[..max.len() - 1];
+                let step = if step.is_empty() {
+                    1
+                } else {
+                    step.parse()
+                        .map_err(|_e| ValueMultiplicityParseError("invalid step"))?
+                };
+                Ok(ValueMultiplicity {
+                    min,
+                    max: None,
+                    step,
+                })
+            }
+            Some(max) => {
+                let max = max.parse().map_err(|_e| {
+                    ValueMultiplicityParseError("invalid maximum number of values")
+                })?;
+                Ok(ValueMultiplicity {
+                    min,
+                    max: Some(max),
+                    step: 1,
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValueMultiplicity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "{}", self.min),
+            Some(max) => write!(f, "{}-{}", self.min, max),
+            None if self.step > 1 => write!(f, "{}-{}n", self.min, self.step),
+            None => write!(f, "{}-n", self.min),
+        }
+    }
 }
 
 /// A data type for a dictionary entry with full ownership.
@@ -141,8 +403,14 @@ pub struct DictionaryEntryBuf {
     pub tag: TagRange,
     /// The alias of the attribute, with no spaces, usually InCapitalizedCamelCase
     pub alias: String,
-    /// The _typical_  value representation of the attribute
-    pub vr: VR,
+    /// The full name of the attribute, if known
+    pub name: Option<String>,
+    /// The value representation of the attribute
+    pub vr: VirtualVr,
+    /// The value multiplicity of the attribute, if known
+    pub vm: Option<ValueMultiplicity>,
+    /// Whether the attribute has been retired
+    pub retired: bool,
 }
 
 impl DictionaryEntry for DictionaryEntryBuf {
@@ -153,19 +421,38 @@ impl DictionaryEntry for DictionaryEntryBuf {
         self.alias.as_str()
     }
     fn vr(&self) -> VR {
+        self.vr.relaxed()
+    }
+    fn virtual_vr(&self) -> VirtualVr {
         self.vr
     }
+    fn vm(&self) -> Option<ValueMultiplicity> {
+        self.vm
+    }
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    fn is_retired(&self) -> bool {
+        self.retired
+    }
 }
 
-/// A data type for a dictionary entry with a string slice for its alias.
+/// A data type for a dictionary entry with string slices for its alias
+/// and name.
 #[derive(Debug, PartialEq, Clone)]
 pub struct DictionaryEntryRef<'a> {
     /// The attribute tag or tag range
     pub tag: TagRange,
     /// The alias of the attribute, with no spaces, usually InCapitalizedCamelCase
     pub alias: &'a str,
-    /// The _typical_  value representation of the attribute
-    pub vr: VR,
+    /// The full name of the attribute, if known
+    pub name: Option<&'a str>,
+    /// The value representation of the attribute
+    pub vr: VirtualVr,
+    /// The value multiplicity of the attribute, if known
+    pub vm: Option<ValueMultiplicity>,
+    /// Whether the attribute has been retired
+    pub retired: bool,
 }
 
 impl<'a> DictionaryEntry for DictionaryEntryRef<'a> {
@@ -176,8 +463,20 @@ impl<'a> DictionaryEntry for DictionaryEntryRef<'a> {
         self.alias
     }
     fn vr(&self) -> VR {
+        self.vr.relaxed()
+    }
+    fn virtual_vr(&self) -> VirtualVr {
         self.vr
     }
+    fn vm(&self) -> Option<ValueMultiplicity> {
+        self.vm
+    }
+    fn name(&self) -> Option<&str> {
+        self.name
+    }
+    fn is_retired(&self) -> bool {
+        self.retired
+    }
 }
 
 /// Utility data structure that resolves to a DICOM attribute tag
@@ -214,8 +513,35 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::TagRange;
-    use crate::header::Tag;
+    use super::{
+        DataDictionary, DictionaryEntryBuf, InMemDataDictionary, TagRange, ValueMultiplicity,
+        VirtualVr,
+    };
+    use crate::header::{Tag, VR};
+
+    #[test]
+    fn test_parse_and_display_tags() {
+        let dict = InMemDataDictionary::new().with_entry(DictionaryEntryBuf {
+            tag: TagRange::Single(Tag(0x0010, 0x0010)),
+            alias: "PatientName".to_string(),
+            name: None,
+            vr: VR::PN.into(),
+            vm: None,
+            retired: false,
+        });
+        assert_eq!(dict.parse_tag("PatientName"), Some(Tag(0x0010, 0x0010)));
+        assert_eq!(dict.parse_tag("(0008,0060)"), Some(Tag(0x0008, 0x0060)));
+        assert_eq!(dict.parse_tag("PatientID"), None);
+
+        assert_eq!(
+            dict.display_tag(Tag(0x0010, 0x0010)).to_string(),
+            "(0010,0010) PatientName"
+        );
+        assert_eq!(
+            dict.display_tag(Tag(0x0008, 0x0060)).to_string(),
+            "(0008,0060)"
+        );
+    }
 
     #[test]
     fn test_parse_tag_range() {
@@ -231,4 +557,57 @@ mod tests {
         let tag: TagRange = "1234,56xx".parse().unwrap();
         assert_eq!(tag, TagRange::Element100(Tag(0x1234, 0x5600)));
     }
+
+    #[test]
+    fn test_parse_value_multiplicity() {
+        let vm: ValueMultiplicity = "1".parse().unwrap();
+        assert_eq!(vm, ValueMultiplicity::ONE);
+
+        let vm: ValueMultiplicity = "1-3".parse().unwrap();
+        assert_eq!(
+            vm,
+            ValueMultiplicity {
+                min: 1,
+                max: Some(3),
+                step: 1
+            }
+        );
+
+        let vm: ValueMultiplicity = "1-n".parse().unwrap();
+        assert_eq!(
+            vm,
+            ValueMultiplicity {
+                min: 1,
+                max: None,
+                step: 1
+            }
+        );
+
+        let vm: ValueMultiplicity = "3-3n".parse().unwrap();
+        assert_eq!(
+            vm,
+            ValueMultiplicity {
+                min: 3,
+                max: None,
+                step: 3
+            }
+        );
+        assert_eq!(vm.to_string(), "3-3n");
+
+        assert!("".parse::<ValueMultiplicity>().is_err());
+        assert!("1-x".parse::<ValueMultiplicity>().is_err());
+    }
+
+    #[test]
+    fn test_parse_virtual_vr() {
+        assert_eq!("PN".parse(), Ok(VirtualVr::Exact(VR::PN)));
+        assert_eq!("US or SS".parse(), Ok(VirtualVr::Xs));
+        assert_eq!("OB or OW".parse(), Ok(VirtualVr::Ox));
+        assert_eq!("US or SS or OW".parse(), Ok(VirtualVr::Lt));
+        assert!("See Note".parse::<VirtualVr>().is_err());
+
+        assert_eq!(VirtualVr::Xs.relaxed(), VR::US);
+        assert_eq!(VirtualVr::Px.relaxed(), VR::OB);
+        assert_eq!(VirtualVr::Px.exact(), None);
+    }
 }
@@ -94,6 +94,21 @@ quick_error! {
             cause(err)
             display(self_) -> ("{}", err.description())
         }
+        /// A number does not fit in the requested type.
+        OutOfRange(value: String, requested: &'static str) {
+            description("Number out of range for the requested type")
+            display(self_) -> ("number {} out of range for {}", value, requested)
+        }
+        /// The date or time value is not precise enough for an exact conversion.
+        ImpreciseValue {
+            description("Date/time value is not precise enough for an exact conversion")
+            display(self_) -> ("{}", self_.description())
+        }
+        /// The value is not a valid range of dates or times.
+        InvalidRange {
+            description("Invalid date/time range")
+            display(self_) -> ("{}", self_.description())
+        }
         /// An attempt of reading more than the number of bytes in the length attribute was made.
         UnexpectedEndOfElement {
             description("Unexpected end of element")
@@ -129,3 +144,40 @@ impl ::std::error::Error for CastValueError {
         "bad value cast"
     }
 }
+
+/// An error type for a failed attempt at converting a value
+/// into another representation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvertValueError {
+    /// The value format requested
+    pub requested: &'static str,
+    /// The value's original representation
+    pub original: ValueType,
+    /// The reason why the conversion failed,
+    /// or `None` if the original representation cannot be converted
+    /// to the requested one
+    pub cause: Option<InvalidValueReadError>,
+}
+
+impl fmt::Display for ConvertValueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "could not convert {:?} value to {}",
+            self.original, self.requested
+        )?;
+        if let Some(cause) = &self.cause {
+            write!(f, ": {}", cause)?;
+        }
+        Ok(())
+    }
+}
+
+impl ::std::error::Error for ConvertValueError {
+    fn source(&self) -> Option<&(dyn BaseError + 'static)> {
+        match &self.cause {
+            Some(cause) => Some(cause),
+            None => None,
+        }
+    }
+}
@@ -2,11 +2,13 @@
 //! It comprises a variety of basic data types, such as the DICOM attribute tag, the
 //! element header, and element composite types.
 
-use crate::error::{Error, Result};
-use crate::value::{DicomValueType, PrimitiveValue, Value};
+use crate::error::{ConvertValueError, Error, Result};
+use crate::value::{DicomDate, DicomDateTime, DicomTime, DicomValueType, PrimitiveValue, Value};
+use num_traits::NumCast;
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
 use std::str::{from_utf8, FromStr};
 
 /// A trait for a data type containing a DICOM header.
@@ -176,6 +178,72 @@ where
     pub fn to_str(&self) -> Result<Cow<str>> {
         self.value.to_str().map_err(From::from)
     }
+
+    /// Retrieve the element's value as a single integer of type `T`,
+    /// regardless of whether it was read in binary or text form
+    /// (see [`PrimitiveValue::to_int`]).
+    pub fn to_int<T>(&self) -> std::result::Result<T, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseIntError>,
+    {
+        self.value.to_int()
+    }
+
+    /// Retrieve the element's value as a sequence of integers of type `T`.
+    pub fn to_multi_int<T>(&self) -> std::result::Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseIntError>,
+    {
+        self.value.to_multi_int()
+    }
+
+    /// Retrieve the element's value as a single floating point number
+    /// of type `T` (see [`PrimitiveValue::to_float`]).
+    pub fn to_float<T>(&self) -> std::result::Result<T, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseFloatError>,
+    {
+        self.value.to_float()
+    }
+
+    /// Retrieve the element's value as a sequence of floating point numbers
+    /// of type `T`.
+    pub fn to_multi_float<T>(&self) -> std::result::Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseFloatError>,
+    {
+        self.value.to_multi_float()
+    }
+
+    /// Retrieve the element's value as a single date.
+    pub fn to_date(&self) -> std::result::Result<DicomDate, ConvertValueError> {
+        self.value.to_date()
+    }
+
+    /// Retrieve the element's value as a sequence of dates.
+    pub fn to_multi_date(&self) -> std::result::Result<Vec<DicomDate>, ConvertValueError> {
+        self.value.to_multi_date()
+    }
+
+    /// Retrieve the element's value as a single time.
+    pub fn to_time(&self) -> std::result::Result<DicomTime, ConvertValueError> {
+        self.value.to_time()
+    }
+
+    /// Retrieve the element's value as a sequence of times.
+    pub fn to_multi_time(&self) -> std::result::Result<Vec<DicomTime>, ConvertValueError> {
+        self.value.to_multi_time()
+    }
+
+    /// Retrieve the element's value as a single date-time.
+    pub fn to_datetime(&self) -> std::result::Result<DicomDateTime, ConvertValueError> {
+        self.value.to_datetime()
+    }
+
+    /// Retrieve the element's value as a sequence of date-times.
+    pub fn to_multi_datetime(&self) -> std::result::Result<Vec<DicomDateTime>, ConvertValueError> {
+        self.value.to_multi_datetime()
+    }
 }
 
 impl<'v, I> DataElementRef<'v, I>
@@ -530,6 +598,36 @@ impl Tag {
     pub fn element(self) -> ElementNumber {
         self.1
     }
+
+    /// Check whether this tag belongs to a private group: an odd group
+    /// other than `0001`, `0003`, `0005`, `0007` and `FFFF`.
+    pub fn is_private(self) -> bool {
+        self.0 % 2 == 1 && !matches!(self.0, 0x0001 | 0x0003 | 0x0005 | 0x0007 | 0xFFFF)
+    }
+
+    /// Check whether this tag is of a private creator data element,
+    /// `(gggg,0010-00FF)` in a private group.
+    pub fn is_private_creator(self) -> bool {
+        self.is_private() && (0x0010..=0x00FF).contains(&self.1)
+    }
+
+    /// Retrieve the tag of the private creator data element which reserves
+    /// the block of this private data element, `(gggg,10xx-FFxx)`.
+    /// Returns `None` if this is not a private data element.
+    ///
+    /// ```
+    /// # use dicom_core::Tag;
+    /// assert_eq!(Tag(0x0029, 0x1010).private_creator(), Some(Tag(0x0029, 0x0010)));
+    /// assert_eq!(Tag(0x0029, 0x0010).private_creator(), None);
+    /// assert_eq!(Tag(0x0028, 0x1010).private_creator(), None);
+    /// ```
+    pub fn private_creator(self) -> Option<Tag> {
+        if self.is_private() && self.1 >= 0x1000 {
+            Some(Tag(self.0, self.1 >> 8))
+        } else {
+            None
+        }
+    }
 }
 
 impl fmt::Debug for Tag {
@@ -544,6 +642,63 @@ impl fmt::Display for Tag {
     }
 }
 
+/// An error returned when parsing an invalid tag.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct TagParseError(&'static str);
+
+impl fmt::Display for TagParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for TagParseError {}
+
+/// Parse a tag from text in one of the forms `(gggg,eeee)`, `gggg,eeee`
+/// or `ggggeeee`, where each component is made of 4 hexadecimal digits.
+///
+/// Attribute keywords are resolved with the help of a data dictionary
+/// instead, via [`DataDictionary::parse_tag`].
+///
+/// ```
+/// # use dicom_core::Tag;
+/// assert_eq!("(0010,0010)".parse(), Ok(Tag(0x0010, 0x0010)));
+/// assert_eq!("7fe0,0010".parse(), Ok(Tag(0x7FE0, 0x0010)));
+/// assert_eq!("00080060".parse(), Ok(Tag(0x0008, 0x0060)));
+/// assert!("PatientName".parse::<Tag>().is_err());
+/// ```
+///
+/// [`DataDictionary::parse_tag`]: crate::dictionary::DataDictionary::parse_tag
+impl FromStr for Tag {
+    type Err = TagParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut s = s.trim();
+        if s.starts_with('(') && s.ends_with(')') {
+            s = &s[1..s.len() - 1];
+        }
+        let (group, elem) = match s.find(',') {
+            Some(i) => (s[..i].trim(), s[i + 1..].trim()),
+            None if s.len() == 8 && s.is_char_boundary(4) => (&s[..4], &s[4..]),
+            None => {
+                return Err(TagParseError(
+                    "expected a tag of the form `(gggg,eeee)` or `ggggeeee`",
+                ))
+            }
+        };
+        let parse_component = |txt: &str, err| {
+            if txt.len() != 4 || !txt.bytes().all(|c| c.is_ascii_hexdigit()) {
+                return Err(TagParseError(err));
+            }
+            u16::from_str_radix(txt, 16).map_err(|_| TagParseError(err))
+        };
+        Ok(Tag(
+            parse_component(group, "invalid tag component `group`")?,
+            parse_component(elem, "invalid tag component `element`")?,
+        ))
+    }
+}
+
 impl PartialEq<(u16, u16)> for Tag {
     fn eq(&self, other: &(u16, u16)) -> bool {
         self.0 == other.0 && self.1 == other.1
@@ -792,4 +947,37 @@ mod tests {
         assert_eq!(0x0010u16, t.group());
         assert_eq!(0x0020u16, t.element());
     }
+
+    #[test]
+    fn tag_from_str() {
+        assert_eq!("(0010,0020)".parse(), Ok(Tag(0x0010, 0x0020)));
+        assert_eq!(" 0010,0020 ".parse(), Ok(Tag(0x0010, 0x0020)));
+        assert_eq!("(7fe0, 0010)".parse(), Ok(Tag(0x7FE0, 0x0010)));
+        assert_eq!("FFFEE000".parse(), Ok(Tag(0xFFFE, 0xE000)));
+        assert!("(0010,002)".parse::<Tag>().is_err());
+        assert!("(0010,+020)".parse::<Tag>().is_err());
+        assert!("(60xx,3000)".parse::<Tag>().is_err());
+        assert!("0010".parse::<Tag>().is_err());
+        assert!("PatientName".parse::<Tag>().is_err());
+    }
+
+    #[test]
+    fn private_tags() {
+        assert!(Tag(0x0009, 0x0010).is_private());
+        assert!(Tag(0x0009, 0x0010).is_private_creator());
+        assert!(!Tag(0x0009, 0x1010).is_private_creator());
+        assert!(!Tag(0x0008, 0x0010).is_private());
+        assert!(!Tag(0x0007, 0x0010).is_private());
+        assert!(!Tag(0xFFFF, 0x0010).is_private());
+        assert!(!Tag(0x0009, 0x0005).is_private_creator());
+        assert_eq!(
+            Tag(0x0009, 0x10FF).private_creator(),
+            Some(Tag(0x0009, 0x0010))
+        );
+        assert_eq!(
+            Tag(0x0009, 0xFF01).private_creator(),
+            Some(Tag(0x0009, 0x00FF))
+        );
+        assert_eq!(Tag(0x0009, 0x0011).private_creator(), None);
+    }
 }
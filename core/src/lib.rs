@@ -6,7 +6,7 @@
     unused_qualifications,
     unused_import_braces
 )]
-#![recursion_limit = "60"]
+#![recursion_limit = "128"]
 
 //! This is the core DICOM library, containing various concepts, data structures
 //! and traits specific to DICOM content.
@@ -1,11 +1,25 @@
 //! This module includes a high level abstraction over a DICOM data element's value.
 
-use crate::error::CastValueError;
+use crate::error::{CastValueError, ConvertValueError, InvalidValueReadError};
 use crate::header::{Length, Tag};
-use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, Timelike};
 use itertools::Itertools;
+use num_traits::{NumCast, ToPrimitive};
 use smallvec::SmallVec;
+use std::any::type_name;
 use std::borrow::Cow;
+use std::fmt::Display;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::FromStr;
+
+pub mod numeric;
+pub mod partial;
+pub mod person_name;
+
+pub use self::numeric::{DecimalString, IntegerString, NumericString};
+pub use self::partial::{
+    DateRange, DateTimeRange, DicomDate, DicomDateTime, DicomRange, DicomTime, TimeRange,
+};
+pub use self::person_name::{PersonName, PersonNameComponents};
 
 /// An aggregation of one or more elements in a value.
 pub type C<T> = SmallVec<[T; 2]>;
@@ -107,6 +121,90 @@ where
         }
     }
 
+    /// Retrieve a single integer of type `T`,
+    /// converted as in [`PrimitiveValue::to_int`].
+    pub fn to_int<T>(&self) -> Result<T, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseIntError>,
+    {
+        self.primitive_to_convert(type_name::<T>())?.to_int()
+    }
+
+    /// Retrieve all integers of type `T`,
+    /// converted as in [`PrimitiveValue::to_int`].
+    pub fn to_multi_int<T>(&self) -> Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseIntError>,
+    {
+        self.primitive_to_convert(type_name::<T>())?.to_multi_int()
+    }
+
+    /// Retrieve a single floating point number of type `T`,
+    /// converted as in [`PrimitiveValue::to_float`].
+    pub fn to_float<T>(&self) -> Result<T, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseFloatError>,
+    {
+        self.primitive_to_convert(type_name::<T>())?.to_float()
+    }
+
+    /// Retrieve all floating point numbers of type `T`,
+    /// converted as in [`PrimitiveValue::to_float`].
+    pub fn to_multi_float<T>(&self) -> Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseFloatError>,
+    {
+        self.primitive_to_convert(type_name::<T>())?
+            .to_multi_float()
+    }
+
+    /// Retrieve a single date,
+    /// converted as in [`PrimitiveValue::to_date`].
+    pub fn to_date(&self) -> Result<DicomDate, ConvertValueError> {
+        self.primitive_to_convert("date")?.to_date()
+    }
+
+    /// Retrieve all dates,
+    /// converted as in [`PrimitiveValue::to_date`].
+    pub fn to_multi_date(&self) -> Result<Vec<DicomDate>, ConvertValueError> {
+        self.primitive_to_convert("date")?.to_multi_date()
+    }
+
+    /// Retrieve a single time,
+    /// converted as in [`PrimitiveValue::to_time`].
+    pub fn to_time(&self) -> Result<DicomTime, ConvertValueError> {
+        self.primitive_to_convert("time")?.to_time()
+    }
+
+    /// Retrieve all times,
+    /// converted as in [`PrimitiveValue::to_time`].
+    pub fn to_multi_time(&self) -> Result<Vec<DicomTime>, ConvertValueError> {
+        self.primitive_to_convert("time")?.to_multi_time()
+    }
+
+    /// Retrieve a single date-time,
+    /// converted as in [`PrimitiveValue::to_datetime`].
+    pub fn to_datetime(&self) -> Result<DicomDateTime, ConvertValueError> {
+        self.primitive_to_convert("date-time")?.to_datetime()
+    }
+
+    /// Retrieve all date-times,
+    /// converted as in [`PrimitiveValue::to_datetime`].
+    pub fn to_multi_datetime(&self) -> Result<Vec<DicomDateTime>, ConvertValueError> {
+        self.primitive_to_convert("date-time")?.to_multi_datetime()
+    }
+
+    fn primitive_to_convert(
+        &self,
+        requested: &'static str,
+    ) -> Result<&PrimitiveValue, ConvertValueError> {
+        self.primitive().ok_or_else(|| ConvertValueError {
+            requested,
+            original: self.value_type(),
+            cause: None,
+        })
+    }
+
     /// Retrieves the primitive value as a DICOM tag.
     pub fn to_tag(&self) -> Result<Tag, CastValueError> {
         match self {
@@ -171,7 +269,7 @@ pub enum PrimitiveValue {
     U16(C<u16>),
 
     /// A sequence of signed 32-bit integers.
-    /// Used for SL and IS.
+    /// Used for SL.
     I32(C<i32>),
 
     /// A sequence of unsigned 32-bit integers.
@@ -191,20 +289,28 @@ pub enum PrimitiveValue {
     F32(C<f32>),
 
     /// The value is a sequence of 64-bit floating point numbers.
-    /// Used for OD and FD, DS.
+    /// Used for OD and FD.
     F64(C<f64>),
 
+    /// A sequence of decimal numbers in text form.
+    /// Used for the DS representation.
+    DecimalString(C<DecimalString>),
+
+    /// A sequence of integers in text form.
+    /// Used for the IS representation.
+    IntegerString(C<IntegerString>),
+
     /// A sequence of dates.
     /// Used for the DA representation.
-    Date(C<NaiveDate>),
+    Date(C<DicomDate>),
 
     /// A sequence of date-time values.
     /// Used for the DT representation.
-    DateTime(C<DateTime<FixedOffset>>),
+    DateTime(C<DicomDateTime>),
 
     /// A sequence of time values.
     /// Used for the TM representation.
-    Time(C<NaiveTime>),
+    Time(C<DicomTime>),
 }
 
 impl PrimitiveValue {
@@ -226,6 +332,8 @@ impl PrimitiveValue {
             U64(c) => c.len() as u32,
             F32(c) => c.len() as u32,
             F64(c) => c.len() as u32,
+            DecimalString(c) => c.len() as u32,
+            IntegerString(c) => c.len() as u32,
             Date(c) => c.len() as u32,
             DateTime(c) => c.len() as u32,
             Time(c) => c.len() as u32,
@@ -253,6 +361,17 @@ impl PrimitiveValue {
         }
     }
 
+    /// Get a single person name, parsed from the first string value.
+    pub fn person_name(&self) -> Option<PersonName> {
+        self.string().map(PersonName::parse)
+    }
+
+    /// Get a sequence of person names, parsed from the string values.
+    pub fn person_names(&self) -> Option<Vec<PersonName>> {
+        self.strings()
+            .map(|names| names.into_iter().map(PersonName::parse).collect())
+    }
+
     /// Get a single DICOM tag.
     pub fn tag(&self) -> Option<Tag> {
         use self::PrimitiveValue::*;
@@ -294,6 +413,7 @@ impl PrimitiveValue {
         use self::PrimitiveValue::*;
         match self {
             I32(c) => c.first().cloned(),
+            IntegerString(c) => c.first().map(NumericString::value),
             _ => None,
         }
     }
@@ -348,6 +468,7 @@ impl PrimitiveValue {
         use self::PrimitiveValue::*;
         match self {
             F64(c) => c.first().cloned(),
+            DecimalString(c) => c.first().map(NumericString::value),
             _ => None,
         }
     }
@@ -371,7 +492,6 @@ impl PrimitiveValue {
             F32(c) => c.len() * 4,
             F64(c) => c.len() * 8,
             Tags(c) => c.len() * 4,
-            Date(c) => c.len() * 8,
             Str(s) => s.as_bytes().len(),
             Strs(c) if c.is_empty() => 0,
             Strs(c) => {
@@ -380,59 +500,275 @@ impl PrimitiveValue {
                     .sum::<usize>()
                     - 1
             }
-            Time(c) if c.is_empty() => 0,
-            Time(c) => {
-                c.iter()
-                    .map(|t| ((PrimitiveValue::tm_byte_len(*t) + 1) & !1) + 1)
-                    .sum::<usize>()
-                    - 1
-            }
-            DateTime(c) if c.is_empty() => 0,
-            DateTime(c) => {
-                c.iter()
-                    .map(|dt| ((PrimitiveValue::dt_byte_len(*dt) + 1) & !1) + 1)
-                    .sum::<usize>()
-                    - 1
-            }
+            DecimalString(c) => PrimitiveValue::text_byte_len(c),
+            IntegerString(c) => PrimitiveValue::text_byte_len(c),
+            Date(c) => PrimitiveValue::text_byte_len(c),
+            Time(c) => PrimitiveValue::text_byte_len(c),
+            DateTime(c) => PrimitiveValue::text_byte_len(c),
         }
     }
 
-    fn tm_byte_len(time: NaiveTime) -> usize {
-        match (time.hour(), time.minute(), time.second(), time.nanosecond()) {
-            (_, 0, 0, 0) => 2,
-            (_, _, 0, 0) => 4,
-            (_, _, _, 0) => 6,
-            (_, _, _, nano) => {
-                let mut frac = nano / 1000; // nano to microseconds
-                let mut trailing_zeros = 0;
-                while frac % 10 == 0 {
-                    frac /= 10;
-                    trailing_zeros += 1;
-                }
-                7 + 6 - trailing_zeros
-            }
+    /// The byte length of values in their text form,
+    /// each padded to an even length and separated by a backslash.
+    fn text_byte_len<T: Display>(values: &[T]) -> usize {
+        if values.is_empty() {
+            return 0;
         }
+        values
+            .iter()
+            .map(|v| ((v.to_string().len() + 1) & !1) + 1)
+            .sum::<usize>()
+            - 1
+    }
+}
+
+/// Conversions to other representations, which, unlike the accessors above,
+/// also succeed when the value was read in another form.
+impl PrimitiveValue {
+    /// Retrieve a single integer of type `T` from this value.
+    ///
+    /// Integers of any width are converted to `T` if they fit in it,
+    /// and strings (such as IS values read in their text form) are parsed,
+    /// ignoring surrounding spaces.
+    /// If the value has multiple integers, only the first one is returned.
+    ///
+    /// ```
+    /// # use dicom_core::value::PrimitiveValue;
+    /// # use smallvec::smallvec;
+    /// let value = PrimitiveValue::Strs(smallvec![" 512".to_string()]);
+    /// assert_eq!(value.to_int::<u16>(), Ok(512));
+    /// assert!(value.to_int::<u8>().is_err());
+    /// ```
+    pub fn to_int<T>(&self) -> Result<T, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseIntError>,
+    {
+        first(self, self.to_ints(1))
     }
 
-    fn dt_byte_len(datetime: DateTime<FixedOffset>) -> usize {
-        // !!! the current local definition of datetime is inaccurate, because
-        // it cannot distinguish unspecified components from their defaults
-        // (e.g. 201812 should be different from 20181201). This will have to
-        // be changed at some point.
-        (match (datetime.month(), datetime.day()) {
-            (1, 1) => 0,
-            (_, 1) => 2,
-            _ => 4,
-        }) + 8
-            + PrimitiveValue::tm_byte_len(datetime.time())
-            + if datetime.offset() == &FixedOffset::east(0) {
-                0
-            } else {
-                5
+    /// Retrieve all integers of type `T` from this value,
+    /// converted as in [`to_int`](PrimitiveValue::to_int).
+    pub fn to_multi_int<T>(&self) -> Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseIntError>,
+    {
+        self.to_ints(usize::MAX)
+    }
+
+    /// Retrieve a single floating point number of type `T` from this value.
+    ///
+    /// Integers and floating point numbers of any width are converted to `T`,
+    /// and strings (such as DS values read in their text form) are parsed,
+    /// ignoring surrounding spaces.
+    /// If the value has multiple numbers, only the first one is returned.
+    pub fn to_float<T>(&self) -> Result<T, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseFloatError>,
+    {
+        first(self, self.to_floats(1))
+    }
+
+    /// Retrieve all floating point numbers of type `T` from this value,
+    /// converted as in [`to_float`](PrimitiveValue::to_float).
+    pub fn to_multi_float<T>(&self) -> Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseFloatError>,
+    {
+        self.to_floats(usize::MAX)
+    }
+
+    /// Retrieve a single date from this value,
+    /// parsing it if the value is in text form.
+    pub fn to_date(&self) -> Result<DicomDate, ConvertValueError> {
+        first(self, self.to_dates(1))
+    }
+
+    /// Retrieve all dates from this value,
+    /// parsing them if the value is in text form.
+    pub fn to_multi_date(&self) -> Result<Vec<DicomDate>, ConvertValueError> {
+        self.to_dates(usize::MAX)
+    }
+
+    /// Retrieve a single time from this value,
+    /// parsing it if the value is in text form.
+    pub fn to_time(&self) -> Result<DicomTime, ConvertValueError> {
+        first(self, self.to_times(1))
+    }
+
+    /// Retrieve all times from this value,
+    /// parsing them if the value is in text form.
+    pub fn to_multi_time(&self) -> Result<Vec<DicomTime>, ConvertValueError> {
+        self.to_times(usize::MAX)
+    }
+
+    /// Retrieve a single date-time from this value,
+    /// parsing it if the value is in text form.
+    /// Dates are also accepted, as date-times without a time.
+    pub fn to_datetime(&self) -> Result<DicomDateTime, ConvertValueError> {
+        first(self, self.to_datetimes(1))
+    }
+
+    /// Retrieve all date-times from this value,
+    /// converted as in [`to_datetime`](PrimitiveValue::to_datetime).
+    pub fn to_multi_datetime(&self) -> Result<Vec<DicomDateTime>, ConvertValueError> {
+        self.to_datetimes(usize::MAX)
+    }
+
+    fn to_ints<T>(&self, max: usize) -> Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseIntError>,
+    {
+        use self::PrimitiveValue::*;
+        let requested = type_name::<T>();
+        let result = match self {
+            Str(s) => parse_texts(std::iter::once(s.as_str()), max),
+            Strs(c) => parse_texts(c.iter().map(String::as_str), max),
+            U8(c) => cast_numbers(c, max, requested),
+            I16(c) => cast_numbers(c, max, requested),
+            U16(c) => cast_numbers(c, max, requested),
+            I32(c) => cast_numbers(c, max, requested),
+            U32(c) => cast_numbers(c, max, requested),
+            I64(c) => cast_numbers(c, max, requested),
+            U64(c) => cast_numbers(c, max, requested),
+            IntegerString(c) => cast_numbers(&numbers(c), max, requested),
+            // the text of a decimal string may still hold an integer
+            DecimalString(c) => {
+                let texts: Vec<_> = c.iter().map(ToString::to_string).collect();
+                parse_texts(texts.iter().map(String::as_str), max)
             }
+            _ => return Err(self.convert_error(requested, None)),
+        };
+        result.map_err(|e| self.convert_error(requested, Some(e)))
+    }
+
+    fn to_floats<T>(&self, max: usize) -> Result<Vec<T>, ConvertValueError>
+    where
+        T: NumCast + FromStr<Err = ParseFloatError>,
+    {
+        use self::PrimitiveValue::*;
+        let requested = type_name::<T>();
+        let result = match self {
+            Str(s) => parse_texts(std::iter::once(s.as_str()), max),
+            Strs(c) => parse_texts(c.iter().map(String::as_str), max),
+            U8(c) => cast_numbers(c, max, requested),
+            I16(c) => cast_numbers(c, max, requested),
+            U16(c) => cast_numbers(c, max, requested),
+            I32(c) => cast_numbers(c, max, requested),
+            U32(c) => cast_numbers(c, max, requested),
+            I64(c) => cast_numbers(c, max, requested),
+            U64(c) => cast_numbers(c, max, requested),
+            F32(c) => cast_numbers(c, max, requested),
+            F64(c) => cast_numbers(c, max, requested),
+            DecimalString(c) => cast_numbers(&numbers(c), max, requested),
+            IntegerString(c) => cast_numbers(&numbers(c), max, requested),
+            _ => return Err(self.convert_error(requested, None)),
+        };
+        result.map_err(|e| self.convert_error(requested, Some(e)))
+    }
+
+    fn to_dates(&self, max: usize) -> Result<Vec<DicomDate>, ConvertValueError> {
+        use self::PrimitiveValue::*;
+        let result = match self {
+            Date(c) => Ok(c.iter().take(max).copied().collect()),
+            Str(s) => parse_texts(std::iter::once(s.as_str()), max),
+            Strs(c) => parse_texts(c.iter().map(String::as_str), max),
+            _ => return Err(self.convert_error("date", None)),
+        };
+        result.map_err(|e| self.convert_error("date", Some(e)))
+    }
+
+    fn to_times(&self, max: usize) -> Result<Vec<DicomTime>, ConvertValueError> {
+        use self::PrimitiveValue::*;
+        let result = match self {
+            Time(c) => Ok(c.iter().take(max).copied().collect()),
+            Str(s) => parse_texts(std::iter::once(s.as_str()), max),
+            Strs(c) => parse_texts(c.iter().map(String::as_str), max),
+            _ => return Err(self.convert_error("time", None)),
+        };
+        result.map_err(|e| self.convert_error("time", Some(e)))
+    }
+
+    fn to_datetimes(&self, max: usize) -> Result<Vec<DicomDateTime>, ConvertValueError> {
+        use self::PrimitiveValue::*;
+        let result = match self {
+            DateTime(c) => Ok(c.iter().take(max).copied().collect()),
+            Date(c) => Ok(c
+                .iter()
+                .take(max)
+                .map(|d| DicomDateTime::from_date(*d))
+                .collect()),
+            Str(s) => parse_texts(std::iter::once(s.as_str()), max),
+            Strs(c) => parse_texts(c.iter().map(String::as_str), max),
+            _ => return Err(self.convert_error("date-time", None)),
+        };
+        result.map_err(|e| self.convert_error("date-time", Some(e)))
+    }
+
+    fn convert_error(
+        &self,
+        requested: &'static str,
+        cause: Option<InvalidValueReadError>,
+    ) -> ConvertValueError {
+        ConvertValueError {
+            requested,
+            original: self.value_type(),
+            cause,
+        }
     }
 }
 
+/// Take the first of the converted values,
+/// failing if there are none.
+fn first<T>(
+    value: &PrimitiveValue,
+    values: Result<Vec<T>, ConvertValueError>,
+) -> Result<T, ConvertValueError> {
+    values?
+        .into_iter()
+        .next()
+        .ok_or_else(|| value.convert_error(type_name::<T>(), None))
+}
+
+/// Parse up to `max` values in text form, ignoring surrounding spaces
+/// and trailing null characters.
+fn parse_texts<'a, T, E>(
+    texts: impl Iterator<Item = &'a str>,
+    max: usize,
+) -> Result<Vec<T>, InvalidValueReadError>
+where
+    T: FromStr<Err = E>,
+    E: Into<InvalidValueReadError>,
+{
+    texts
+        .take(max)
+        .map(|s| s.trim_end_matches('\0').trim().parse().map_err(Into::into))
+        .collect()
+}
+
+/// Collect the numbers of numeric string values.
+fn numbers<T: Copy>(values: &[NumericString<T>]) -> C<T> {
+    values.iter().map(NumericString::value).collect()
+}
+
+/// Convert up to `max` numbers to `T`, failing if any does not fit in it.
+fn cast_numbers<T, V>(
+    values: &[V],
+    max: usize,
+    requested: &'static str,
+) -> Result<Vec<T>, InvalidValueReadError>
+where
+    T: NumCast,
+    V: ToPrimitive + Display + Copy,
+{
+    values
+        .iter()
+        .take(max)
+        .map(|&v| {
+            T::from(v).ok_or_else(|| InvalidValueReadError::OutOfRange(v.to_string(), requested))
+        })
+        .collect()
+}
+
 /// An enum representing an abstraction of a DICOM element's data value type.
 /// This should be the equivalent of `PrimitiveValue` without the content,
 /// plus the `Item` entry.
@@ -471,7 +807,7 @@ pub enum ValueType {
     U16,
 
     /// A sequence of signed 32-bit integers.
-    /// Used for SL and IS.
+    /// Used for SL.
     I32,
 
     /// A sequence of unsigned 32-bit integers.
@@ -491,9 +827,17 @@ pub enum ValueType {
     F32,
 
     /// The value is a sequence of 64-bit floating point numbers.
-    /// Used for OD, FD and DS.
+    /// Used for OD and FD.
     F64,
 
+    /// A sequence of decimal numbers in text form.
+    /// Used for the DS representation.
+    DecimalString,
+
+    /// A sequence of integers in text form.
+    /// Used for the IS representation.
+    IntegerString,
+
     /// A sequence of dates.
     /// Used for the DA representation.
     Date,
@@ -530,6 +874,8 @@ impl DicomValueType for PrimitiveValue {
             DateTime(_) => ValueType::DateTime,
             F32(_) => ValueType::F32,
             F64(_) => ValueType::F64,
+            DecimalString(_) => ValueType::DecimalString,
+            IntegerString(_) => ValueType::IntegerString,
             I16(_) => ValueType::I16,
             I32(_) => ValueType::I32,
             I64(_) => ValueType::I64,
@@ -553,6 +899,8 @@ impl DicomValueType for PrimitiveValue {
             DateTime(b) => b.len() as u32,
             F32(b) => b.len() as u32,
             F64(b) => b.len() as u32,
+            DecimalString(b) => b.len() as u32,
+            IntegerString(b) => b.len() as u32,
             I16(b) => b.len() as u32,
             I32(b) => b.len() as u32,
             I64(b) => b.len() as u32,
@@ -585,3 +933,98 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::{DataElement, VR};
+    use smallvec::smallvec;
+
+    #[test]
+    fn convert_numbers() {
+        let value = PrimitiveValue::Strs(smallvec!["512 ".to_string(), " -3".to_string()]);
+        assert_eq!(value.to_int::<u16>(), Ok(512));
+        assert_eq!(value.to_multi_int::<i32>(), Ok(vec![512, -3]));
+        assert_eq!(value.to_float::<f64>(), Ok(512.));
+        let err = value.to_multi_int::<u16>().unwrap_err();
+        assert_eq!(err.requested, "u16");
+        assert_eq!(err.original, ValueType::Strs);
+        assert!(matches!(
+            err.cause,
+            Some(InvalidValueReadError::ParseInteger(_))
+        ));
+
+        // widening and narrowing with overflow checks
+        let value = PrimitiveValue::U16(smallvec![300, 2]);
+        assert_eq!(value.to_int::<i64>(), Ok(300));
+        assert_eq!(value.to_multi_float::<f32>(), Ok(vec![300., 2.]));
+        let err = value.to_int::<u8>().unwrap_err();
+        assert_eq!(
+            err.cause,
+            Some(InvalidValueReadError::OutOfRange("300".to_string(), "u8"))
+        );
+        assert_eq!(
+            err.to_string(),
+            "could not convert U16 value to u8: number 300 out of range for u8"
+        );
+        assert!(PrimitiveValue::I32(smallvec![-1]).to_int::<u32>().is_err());
+
+        let value = PrimitiveValue::Str("1.5e2".to_string());
+        assert_eq!(value.to_float::<f32>(), Ok(150.));
+        assert!(value.to_int::<i32>().is_err());
+
+        // floats are not truncated into integers
+        let err = PrimitiveValue::F64(smallvec![1.0])
+            .to_int::<i32>()
+            .unwrap_err();
+        assert_eq!(err.cause, None);
+        assert!(PrimitiveValue::Empty.to_int::<i32>().is_err());
+        assert_eq!(PrimitiveValue::Empty.to_multi_int::<i32>().ok(), None);
+    }
+
+    #[test]
+    fn convert_dates_and_times() {
+        let value = PrimitiveValue::Strs(smallvec!["201903".to_string()]);
+        assert_eq!(value.to_date(), Ok(DicomDate::from_ym(2019, 3).unwrap()));
+        assert_eq!(
+            value.to_datetime(),
+            Ok(DicomDateTime::from_date(
+                DicomDate::from_ym(2019, 3).unwrap()
+            ))
+        );
+        assert!(value.to_time().is_ok());
+
+        let date = DicomDate::from_ymd(2019, 3, 15).unwrap();
+        let value = PrimitiveValue::Date(smallvec![date]);
+        assert_eq!(value.to_date(), Ok(date));
+        assert_eq!(value.to_datetime(), Ok(DicomDateTime::from_date(date)));
+        assert!(value.to_time().is_err());
+
+        let value = PrimitiveValue::Str("1030\\1130".to_string());
+        let err = value.to_time().unwrap_err();
+        assert_eq!(err.requested, "time");
+        assert!(err.cause.is_some());
+    }
+
+    #[test]
+    fn convert_elements() {
+        let elem: DataElement<PrimitiveValue> = DataElement::new(
+            Tag(0x0028, 0x0010),
+            VR::IS,
+            Value::Primitive(PrimitiveValue::Strs(smallvec!["512".to_string()])),
+        );
+        assert_eq!(elem.to_int::<u16>(), Ok(512));
+        assert_eq!(elem.to_multi_float::<f64>(), Ok(vec![512.]));
+
+        let elem: DataElement<PrimitiveValue> = DataElement::new(
+            Tag(0x0008, 0x1111),
+            VR::SQ,
+            Value::Sequence {
+                items: smallvec![],
+                size: Length::UNDEFINED,
+            },
+        );
+        let err = elem.to_int::<u16>().unwrap_err();
+        assert_eq!(err.original, ValueType::Item);
+    }
+}
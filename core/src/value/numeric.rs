@@ -0,0 +1,139 @@
+//! This module contains the number types of the numeric string
+//! representations, decimal string (DS) and integer string (IS).
+//!
+//! The same number can be written in more than one way
+//! (`0.5`, `0.50` and `+.5` are all the same decimal string),
+//! so a value read from a data set keeps its original text next to
+//! the parsed number. Formatting an unmodified value yields that text,
+//! which makes re-encoded data sets equal to their source.
+//! Once the number is replaced, the value is formatted from the number.
+//!
+//! ```
+//! # use dicom_core::value::numeric::DecimalString;
+//! let mut value: DecimalString = "0.50".parse()?;
+//! assert_eq!(value.value(), 0.5);
+//! assert_eq!(value.to_string(), "0.50");
+//!
+//! value.set_value(0.25);
+//! assert_eq!(value.text(), None);
+//! assert_eq!(value.to_string(), "0.25");
+//! # Ok::<(), dicom_core::error::InvalidValueReadError>(())
+//! ```
+
+use crate::error::InvalidValueReadError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A number in text form which remembers the text it was parsed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericString<T> {
+    value: T,
+    text: Option<String>,
+}
+
+/// A single decimal string (DS) value.
+pub type DecimalString = NumericString<f64>;
+
+/// A single integer string (IS) value.
+pub type IntegerString = NumericString<i32>;
+
+impl<T> NumericString<T> {
+    /// Create a value from a number alone.
+    /// It is formatted in the canonical text form of the number.
+    pub fn new(value: T) -> Self {
+        NumericString { value, text: None }
+    }
+
+    /// Retrieve the number.
+    pub fn value(&self) -> T
+    where
+        T: Copy,
+    {
+        self.value
+    }
+
+    /// Retrieve the original text of the value,
+    /// including any leading or trailing spaces,
+    /// if the number was not modified since it was parsed.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Replace the number, discarding the original text.
+    pub fn set_value(&mut self, value: T) {
+        self.value = value;
+        self.text = None;
+    }
+
+    /// Discard the original text,
+    /// so that the value is formatted in its canonical form.
+    pub fn into_canonical(self) -> Self {
+        NumericString::new(self.value)
+    }
+
+    /// Retrieve the number, discarding the original text.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<T> for NumericString<T> {
+    fn from(value: T) -> Self {
+        NumericString::new(value)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for NumericString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.text {
+            Some(text) => f.write_str(text),
+            None => self.value.fmt(f),
+        }
+    }
+}
+
+/// Parses the number, ignoring leading and trailing whitespace,
+/// and keeps the full text.
+impl<T> FromStr for NumericString<T>
+where
+    T: FromStr,
+    InvalidValueReadError: From<T::Err>,
+{
+    type Err = InvalidValueReadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().parse()?;
+        Ok(NumericString {
+            value,
+            text: Some(s.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_original_text() {
+        let value: IntegerString = " +1".parse().unwrap();
+        assert_eq!(value.value(), 1);
+        assert_eq!(value.text(), Some(" +1"));
+        assert_eq!(value.to_string(), " +1");
+        assert_eq!(value.clone().into_canonical().to_string(), "1");
+        assert_ne!(value, IntegerString::new(1));
+
+        let value: DecimalString = "1.250E2 ".parse().unwrap();
+        assert_eq!(value.value(), 125.);
+        assert_eq!(value.to_string(), "1.250E2 ");
+
+        let mut value = DecimalString::from(0.5);
+        assert_eq!(value.text(), None);
+        assert_eq!(value.to_string(), "0.5");
+        value.set_value(-3.);
+        assert_eq!(value.to_string(), "-3");
+
+        assert!("1.5".parse::<IntegerString>().is_err());
+        assert!("".parse::<DecimalString>().is_err());
+    }
+}
@@ -0,0 +1,1160 @@
+//! This module contains date and time types which keep the precision
+//! of the original DICOM value.
+//!
+//! DICOM allows dates and times to omit their trailing components:
+//! a date may be given as `2019`, `201903` or `20190315`,
+//! and a time as `10`, `1030`, `103015` or `103015.25`.
+//! The types in this module remember which components were present,
+//! so that formatting a value yields the same text that was parsed.
+//! They can be converted to the earliest or latest [`chrono`] value
+//! within the period they describe.
+//!
+//! ```
+//! # use dicom_core::value::partial::{DateComponent, DicomDate};
+//! # use chrono::NaiveDate;
+//! let date: DicomDate = "201903".parse()?;
+//! assert_eq!(date.precision(), DateComponent::Month);
+//! assert_eq!(date.earliest()?, NaiveDate::from_ymd_opt(2019, 3, 1).unwrap());
+//! assert_eq!(date.latest()?, NaiveDate::from_ymd_opt(2019, 3, 31).unwrap());
+//! assert_eq!(date.to_string(), "201903");
+//! # Ok::<(), dicom_core::error::InvalidValueReadError>(())
+//! ```
+//!
+//! Ranges in the form used by queries (e.g. `20190101-20190131`)
+//! are represented by [`DicomRange`].
+
+use crate::error::InvalidValueReadError;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+type Result<T, E = InvalidValueReadError> = std::result::Result<T, E>;
+
+/// A component of a date or time value,
+/// used to describe the precision of a partial value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DateComponent {
+    /// the year
+    Year,
+    /// the month of the year
+    Month,
+    /// the day of the month
+    Day,
+    /// the hour of the day
+    Hour,
+    /// the minute of the hour
+    Minute,
+    /// the second of the minute
+    Second,
+    /// the fraction of a second
+    Fraction,
+}
+
+/// A DICOM date (DA), with a precision of a year, a month or a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DicomDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl DicomDate {
+    /// Create a date with only a year.
+    pub fn from_y(year: u16) -> Result<Self> {
+        if year > 9999 {
+            return Err(InvalidValueReadError::ParseDateTime(
+                u32::from(year),
+                "year (in 0..=9999)",
+            ));
+        }
+        Ok(DicomDate {
+            year,
+            month: None,
+            day: None,
+        })
+    }
+
+    /// Create a date with a year and a month.
+    pub fn from_ym(year: u16, month: u8) -> Result<Self> {
+        if !(1..=12).contains(&month) {
+            return Err(InvalidValueReadError::ParseDateTime(
+                u32::from(month),
+                "month (in 1..=12)",
+            ));
+        }
+        Ok(DicomDate {
+            month: Some(month),
+            ..DicomDate::from_y(year)?
+        })
+    }
+
+    /// Create a date with a year, a month and a day.
+    pub fn from_ymd(year: u16, month: u8, day: u8) -> Result<Self> {
+        let date = DicomDate::from_ym(year, month)?;
+        if NaiveDate::from_ymd_opt(i32::from(year), u32::from(month), u32::from(day)).is_none() {
+            return Err(InvalidValueReadError::ParseDateTime(
+                u32::from(day),
+                "day (valid in the given month)",
+            ));
+        }
+        Ok(DicomDate {
+            day: Some(day),
+            ..date
+        })
+    }
+
+    /// Retrieve the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Retrieve the month, if present.
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    /// Retrieve the day of the month, if present.
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// Retrieve the last component present in the date.
+    pub fn precision(&self) -> DateComponent {
+        match (self.month, self.day) {
+            (_, Some(_)) => DateComponent::Day,
+            (Some(_), None) => DateComponent::Month,
+            (None, _) => DateComponent::Year,
+        }
+    }
+
+    /// Check whether the date has all of its components.
+    pub fn is_precise(&self) -> bool {
+        self.day.is_some()
+    }
+
+    /// Retrieve the first day within this date.
+    pub fn earliest(&self) -> Result<NaiveDate> {
+        let year = i32::from(self.year);
+        match (self.month, self.day) {
+            (Some(month), Some(day)) => {
+                NaiveDate::from_ymd_opt(year, u32::from(month), u32::from(day))
+            }
+            (Some(month), None) => NaiveDate::from_ymd_opt(year, u32::from(month), 1),
+            (None, _) => NaiveDate::from_ymd_opt(year, 1, 1),
+        }
+        .ok_or(InvalidValueReadError::DateTimeZone)
+    }
+
+    /// Retrieve the last day within this date.
+    pub fn latest(&self) -> Result<NaiveDate> {
+        let year = i32::from(self.year);
+        match (self.month, self.day) {
+            (Some(month), Some(day)) => {
+                NaiveDate::from_ymd_opt(year, u32::from(month), u32::from(day))
+            }
+            (Some(12), None) | (None, _) => NaiveDate::from_ymd_opt(year, 12, 31),
+            (Some(month), None) => {
+                NaiveDate::from_ymd_opt(year, u32::from(month) + 1, 1).and_then(|d| d.pred_opt())
+            }
+        }
+        .ok_or(InvalidValueReadError::DateTimeZone)
+    }
+
+    /// Convert the date to a `chrono` date,
+    /// failing if the date is not precise down to the day.
+    pub fn to_naive_date(&self) -> Result<NaiveDate> {
+        if !self.is_precise() {
+            return Err(InvalidValueReadError::ImpreciseValue);
+        }
+        self.earliest()
+    }
+}
+
+impl TryFrom<NaiveDate> for DicomDate {
+    type Error = InvalidValueReadError;
+
+    fn try_from(date: NaiveDate) -> Result<Self> {
+        let year = u16::try_from(date.year()).map_err(|_| {
+            InvalidValueReadError::ParseDateTime(date.year() as u32, "year (in 0..=9999)")
+        })?;
+        DicomDate::from_ymd(year, date.month() as u8, date.day() as u8)
+    }
+}
+
+/// Formats the date in its DICOM representation.
+impl fmt::Display for DicomDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DicomDate {
+    type Err = InvalidValueReadError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_complete(s.as_bytes(), parse_date_prefix)
+    }
+}
+
+/// A DICOM time (TM), with a precision of an hour, a minute,
+/// a second or a fraction of a second of up to 6 digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DicomTime {
+    hour: u8,
+    minute: Option<u8>,
+    second: Option<u8>,
+    /// the fraction of a second and its number of digits
+    fraction: Option<(u32, u8)>,
+}
+
+impl DicomTime {
+    /// Create a time with only an hour.
+    pub fn from_h(hour: u8) -> Result<Self> {
+        if hour >= 24 {
+            return Err(InvalidValueReadError::ParseDateTime(
+                u32::from(hour),
+                "hour (in 0..24)",
+            ));
+        }
+        Ok(DicomTime {
+            hour,
+            minute: None,
+            second: None,
+            fraction: None,
+        })
+    }
+
+    /// Create a time with an hour and a minute.
+    pub fn from_hm(hour: u8, minute: u8) -> Result<Self> {
+        if minute >= 60 {
+            return Err(InvalidValueReadError::ParseDateTime(
+                u32::from(minute),
+                "minute (in 0..60)",
+            ));
+        }
+        Ok(DicomTime {
+            minute: Some(minute),
+            ..DicomTime::from_h(hour)?
+        })
+    }
+
+    /// Create a time with an hour, a minute and a second.
+    /// A second of 60 is accepted for leap seconds.
+    pub fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Self> {
+        if second > 60 {
+            return Err(InvalidValueReadError::ParseDateTime(
+                u32::from(second),
+                "second (in 0..=60)",
+            ));
+        }
+        Ok(DicomTime {
+            second: Some(second),
+            ..DicomTime::from_hm(hour, minute)?
+        })
+    }
+
+    /// Create a time with an hour, a minute, a second
+    /// and a fraction of a second in microseconds (6 digits).
+    pub fn from_hms_micro(hour: u8, minute: u8, second: u8, micro: u32) -> Result<Self> {
+        DicomTime::from_hms_fraction(hour, minute, second, micro, 6)
+    }
+
+    /// Create a time with an hour, a minute, a second
+    /// and a fraction of a second with the given number of digits (1 to 6).
+    ///
+    /// For instance, `10:30:15.25` has the fraction 25 with 2 digits.
+    pub fn from_hms_fraction(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        fraction: u32,
+        digits: u8,
+    ) -> Result<Self> {
+        if !(1..=6).contains(&digits) {
+            return Err(InvalidValueReadError::InvalidLength(
+                usize::from(digits),
+                "between 1 and 6 fraction digits",
+            ));
+        }
+        if fraction >= 10_u32.pow(u32::from(digits)) {
+            return Err(InvalidValueReadError::ParseDateTime(
+                fraction,
+                "fraction within the number of digits",
+            ));
+        }
+        Ok(DicomTime {
+            fraction: Some((fraction, digits)),
+            ..DicomTime::from_hms(hour, minute, second)?
+        })
+    }
+
+    /// Retrieve the hour.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Retrieve the minute, if present.
+    pub fn minute(&self) -> Option<u8> {
+        self.minute
+    }
+
+    /// Retrieve the second, if present.
+    pub fn second(&self) -> Option<u8> {
+        self.second
+    }
+
+    /// Retrieve the fraction of a second as written,
+    /// along with its number of digits, if present.
+    pub fn fraction(&self) -> Option<(u32, u8)> {
+        self.fraction
+    }
+
+    /// Retrieve the last component present in the time.
+    pub fn precision(&self) -> DateComponent {
+        match (self.minute, self.second, self.fraction) {
+            (_, _, Some(_)) => DateComponent::Fraction,
+            (_, Some(_), None) => DateComponent::Second,
+            (Some(_), None, None) => DateComponent::Minute,
+            (None, _, None) => DateComponent::Hour,
+        }
+    }
+
+    /// Retrieve the first instant within this time.
+    pub fn earliest(&self) -> Result<NaiveTime> {
+        self.bound(false)
+    }
+
+    /// Retrieve the last instant within this time,
+    /// to the microsecond.
+    pub fn latest(&self) -> Result<NaiveTime> {
+        self.bound(true)
+    }
+
+    /// Convert the time to a `chrono` time,
+    /// failing if the time is not precise down to the second.
+    pub fn to_naive_time(&self) -> Result<NaiveTime> {
+        if self.second.is_none() {
+            return Err(InvalidValueReadError::ImpreciseValue);
+        }
+        self.earliest()
+    }
+
+    fn bound(&self, latest: bool) -> Result<NaiveTime> {
+        let fill = |max: u8| if latest { max } else { 0 };
+        let minute = self.minute.unwrap_or_else(|| fill(59));
+        let second = self.second.unwrap_or_else(|| fill(59));
+        let micro = match self.fraction {
+            Some((fraction, digits)) => {
+                let scale = 10_u32.pow(6 - u32::from(digits));
+                fraction * scale + if latest { scale - 1 } else { 0 }
+            }
+            None if latest => 999_999,
+            None => 0,
+        };
+        // leap seconds are represented as a fraction past the 59th second
+        let (second, micro) = if second == 60 {
+            (59, micro + 1_000_000)
+        } else {
+            (second, micro)
+        };
+        NaiveTime::from_hms_micro_opt(
+            u32::from(self.hour),
+            u32::from(minute),
+            u32::from(second),
+            micro,
+        )
+        .ok_or(InvalidValueReadError::DateTimeZone)
+    }
+}
+
+impl From<NaiveTime> for DicomTime {
+    fn from(time: NaiveTime) -> Self {
+        let (second, micro) = match time.nanosecond() / 1000 {
+            micro if micro >= 1_000_000 => (60, micro - 1_000_000),
+            micro => (time.second() as u8, micro),
+        };
+        DicomTime {
+            hour: time.hour() as u8,
+            minute: Some(time.minute() as u8),
+            second: Some(second),
+            fraction: if micro == 0 { None } else { Some((micro, 6)) },
+        }
+    }
+}
+
+/// Formats the time in its DICOM representation.
+impl fmt::Display for DicomTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", self.hour)?;
+        if let Some(minute) = self.minute {
+            write!(f, "{:02}", minute)?;
+        }
+        if let Some(second) = self.second {
+            write!(f, "{:02}", second)?;
+        }
+        if let Some((fraction, digits)) = self.fraction {
+            write!(f, ".{:0width$}", fraction, width = usize::from(digits))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DicomTime {
+    type Err = InvalidValueReadError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_complete(s.as_bytes(), parse_time_prefix)
+    }
+}
+
+/// A DICOM date-time (DT), made of a partial date, an optional partial
+/// time, and an optional offset from UTC.
+///
+/// A value without an explicit offset is interpreted in a default offset,
+/// which is UTC unless specified otherwise (see [`with_default_offset`]).
+/// The default offset is never written back to the DICOM representation.
+///
+/// [`with_default_offset`]: DicomDateTime::with_default_offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DicomDateTime {
+    date: DicomDate,
+    time: Option<DicomTime>,
+    offset: Option<FixedOffset>,
+    default_offset: FixedOffset,
+}
+
+impl DicomDateTime {
+    /// Create a date-time with only a date.
+    pub fn from_date(date: DicomDate) -> Self {
+        DicomDateTime {
+            date,
+            time: None,
+            offset: None,
+            default_offset: utc(),
+        }
+    }
+
+    /// Create a date-time with a date and a time.
+    /// The date must be precise down to the day.
+    pub fn from_date_and_time(date: DicomDate, time: DicomTime) -> Result<Self> {
+        if !date.is_precise() {
+            return Err(InvalidValueReadError::ImpreciseValue);
+        }
+        Ok(DicomDateTime {
+            time: Some(time),
+            ..DicomDateTime::from_date(date)
+        })
+    }
+
+    /// Set an explicit offset from UTC, which is part of the value.
+    pub fn with_offset(mut self, offset: FixedOffset) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the offset from UTC to assume when the value
+    /// does not have an explicit one.
+    pub fn with_default_offset(mut self, offset: FixedOffset) -> Self {
+        self.default_offset = offset;
+        self
+    }
+
+    /// Retrieve the date.
+    pub fn date(&self) -> &DicomDate {
+        &self.date
+    }
+
+    /// Retrieve the time, if present.
+    pub fn time(&self) -> Option<&DicomTime> {
+        self.time.as_ref()
+    }
+
+    /// Retrieve the explicit offset from UTC, if present.
+    pub fn offset(&self) -> Option<FixedOffset> {
+        self.offset
+    }
+
+    /// Retrieve the offset from UTC in effect for this value,
+    /// which is either the explicit offset or the default one.
+    pub fn utc_offset(&self) -> FixedOffset {
+        self.offset.unwrap_or(self.default_offset)
+    }
+
+    /// Retrieve the last date or time component present in the value.
+    pub fn precision(&self) -> DateComponent {
+        match &self.time {
+            Some(time) => time.precision(),
+            None => self.date.precision(),
+        }
+    }
+
+    /// Retrieve the first instant within this date-time.
+    pub fn earliest(&self) -> Result<DateTime<FixedOffset>> {
+        let date = self.date.earliest()?;
+        let time = match &self.time {
+            Some(time) => time.earliest()?,
+            None => NaiveTime::MIN,
+        };
+        self.with_utc_offset(date, time)
+    }
+
+    /// Retrieve the last instant within this date-time,
+    /// to the microsecond.
+    pub fn latest(&self) -> Result<DateTime<FixedOffset>> {
+        let date = self.date.latest()?;
+        let time = match &self.time {
+            Some(time) => time.latest()?,
+            None => NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999)
+                .ok_or(InvalidValueReadError::DateTimeZone)?,
+        };
+        self.with_utc_offset(date, time)
+    }
+
+    /// Convert the value to a `chrono` date-time,
+    /// failing if the value is not precise down to the second.
+    pub fn to_chrono_datetime(&self) -> Result<DateTime<FixedOffset>> {
+        match &self.time {
+            Some(time) if time.second().is_some() => self.earliest(),
+            _ => Err(InvalidValueReadError::ImpreciseValue),
+        }
+    }
+
+    fn with_utc_offset(&self, date: NaiveDate, time: NaiveTime) -> Result<DateTime<FixedOffset>> {
+        self.utc_offset()
+            .from_local_datetime(&date.and_time(time))
+            .single()
+            .ok_or(InvalidValueReadError::DateTimeZone)
+    }
+}
+
+impl TryFrom<DateTime<FixedOffset>> for DicomDateTime {
+    type Error = InvalidValueReadError;
+
+    fn try_from(datetime: DateTime<FixedOffset>) -> Result<Self> {
+        let date = DicomDate::try_from(datetime.date_naive())?;
+        let time = DicomTime::from(datetime.time());
+        Ok(DicomDateTime::from_date_and_time(date, time)?.with_offset(*datetime.offset()))
+    }
+}
+
+/// Formats the date-time in its DICOM representation.
+impl fmt::Display for DicomDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.date)?;
+        if let Some(time) = &self.time {
+            write!(f, "{}", time)?;
+        }
+        if let Some(offset) = self.offset {
+            let secs = offset.local_minus_utc();
+            let sign = if secs < 0 { '-' } else { '+' };
+            let secs = secs.abs();
+            write!(f, "{}{:02}{:02}", sign, secs / 3600, secs % 3600 / 60)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DicomDateTime {
+    type Err = InvalidValueReadError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_complete(s.as_bytes(), parse_datetime_prefix)
+    }
+}
+
+/// A range of partial date or time values, in the form used by queries
+/// (e.g. `20190101-20190131`). Either bound may be open, but not both.
+///
+/// ```
+/// # use dicom_core::value::partial::DateRange;
+/// # use chrono::NaiveDate;
+/// let range: DateRange = "201901-".parse()?;
+/// assert_eq!(range.earliest()?, NaiveDate::from_ymd_opt(2019, 1, 1));
+/// assert_eq!(range.latest()?, None);
+/// assert_eq!(range.to_string(), "201901-");
+/// # Ok::<(), dicom_core::error::InvalidValueReadError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DicomRange<T> {
+    start: Option<T>,
+    end: Option<T>,
+}
+
+/// A range of dates (DA).
+pub type DateRange = DicomRange<DicomDate>;
+
+/// A range of times (TM).
+pub type TimeRange = DicomRange<DicomTime>;
+
+/// A range of date-times (DT).
+pub type DateTimeRange = DicomRange<DicomDateTime>;
+
+impl<T> DicomRange<T> {
+    /// Create a range from its bounds,
+    /// failing if both are open.
+    pub fn new(start: Option<T>, end: Option<T>) -> Result<Self> {
+        if start.is_none() && end.is_none() {
+            return Err(InvalidValueReadError::InvalidRange);
+        }
+        Ok(DicomRange { start, end })
+    }
+
+    /// Create a range with both bounds.
+    pub fn between(start: T, end: T) -> Self {
+        DicomRange {
+            start: Some(start),
+            end: Some(end),
+        }
+    }
+
+    /// Create a range without an upper bound.
+    pub fn from_start(start: T) -> Self {
+        DicomRange {
+            start: Some(start),
+            end: None,
+        }
+    }
+
+    /// Create a range without a lower bound.
+    pub fn until(end: T) -> Self {
+        DicomRange {
+            start: None,
+            end: Some(end),
+        }
+    }
+
+    /// Retrieve the lower bound of the range, if any.
+    pub fn start(&self) -> Option<&T> {
+        self.start.as_ref()
+    }
+
+    /// Retrieve the upper bound of the range, if any.
+    pub fn end(&self) -> Option<&T> {
+        self.end.as_ref()
+    }
+}
+
+macro_rules! impl_range_conversions {
+    ($t: ty, $instant: ty) => {
+        impl DicomRange<$t> {
+            /// Retrieve the earliest instant of the range,
+            /// or `None` if it has no lower bound.
+            pub fn earliest(&self) -> Result<Option<$instant>> {
+                self.start.as_ref().map(|v| v.earliest()).transpose()
+            }
+
+            /// Retrieve the latest instant of the range,
+            /// or `None` if it has no upper bound.
+            pub fn latest(&self) -> Result<Option<$instant>> {
+                self.end.as_ref().map(|v| v.latest()).transpose()
+            }
+
+            /// Check whether the given instant is within the range.
+            pub fn contains(&self, instant: &$instant) -> Result<bool> {
+                Ok(self.earliest()?.map_or(true, |start| start <= *instant)
+                    && self.latest()?.map_or(true, |end| *instant <= end))
+            }
+        }
+    };
+}
+
+impl_range_conversions!(DicomDate, NaiveDate);
+impl_range_conversions!(DicomTime, NaiveTime);
+impl_range_conversions!(DicomDateTime, DateTime<FixedOffset>);
+
+/// Formats the range in its DICOM representation.
+impl<T: fmt::Display> fmt::Display for DicomRange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(start) = &self.start {
+            write!(f, "{}", start)?;
+        }
+        f.write_str("-")?;
+        if let Some(end) = &self.end {
+            write!(f, "{}", end)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> FromStr for DicomRange<T>
+where
+    T: FromStr<Err = InvalidValueReadError>,
+{
+    type Err = InvalidValueReadError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parse_bound = |s: &str| {
+            if s.is_empty() {
+                Ok(None)
+            } else {
+                s.parse().map(Some)
+            }
+        };
+        // the separator is ambiguous with negative offsets in date-times,
+        // so try every position until both bounds are valid
+        let mut error = InvalidValueReadError::InvalidRange;
+        for (i, _) in s.match_indices('-') {
+            match (parse_bound(&s[..i]), parse_bound(&s[i + 1..])) {
+                (Ok(start), Ok(end)) => return DicomRange::new(start, end),
+                (Err(e), _) | (_, Err(e)) => error = e,
+            }
+        }
+        Err(error)
+    }
+}
+
+/// Parse an offset from UTC in the form `&ZZXX` (e.g. `+0100` or `-0500`),
+/// as found in the attribute _Timezone Offset From UTC_ (0008,0201).
+/// Surrounding spaces are ignored.
+pub fn parse_utc_offset(s: &str) -> Result<FixedOffset> {
+    parse_complete(s.trim().as_bytes(), parse_offset_prefix)
+}
+
+fn utc() -> FixedOffset {
+    FixedOffset::east_opt(0).unwrap()
+}
+
+/// A function parsing a value from the start of a byte slice,
+/// returning the value and the remaining bytes.
+type ParsePrefix<T> = fn(&[u8]) -> Result<(T, &[u8])>;
+
+/// Parse a value from the full byte slice, failing if any bytes remain.
+fn parse_complete<T>(buf: &[u8], parse: ParsePrefix<T>) -> Result<T> {
+    let (value, rest) = parse(buf)?;
+    match rest.first() {
+        None => Ok(value),
+        Some(&c) => Err(InvalidValueReadError::InvalidToken(c, "end of value")),
+    }
+}
+
+fn starts_with_digit(buf: &[u8]) -> bool {
+    buf.first().is_some_and(u8::is_ascii_digit)
+}
+
+/// Read exactly `n` decimal digits from the start of the buffer.
+fn read_digits(buf: &[u8], n: usize) -> Result<(u32, &[u8])> {
+    if buf.len() < n {
+        return Err(InvalidValueReadError::UnexpectedEndOfElement);
+    }
+    let (digits, rest) = buf.split_at(n);
+    let mut value = 0;
+    for &c in digits {
+        if !c.is_ascii_digit() {
+            return Err(InvalidValueReadError::InvalidToken(c, "digit in 0..9"));
+        }
+        value = value * 10 + u32::from(c - b'0');
+    }
+    Ok((value, rest))
+}
+
+/// Parse a date in the form `YYYY(MM(DD)?)?`.
+fn parse_date_prefix(buf: &[u8]) -> Result<(DicomDate, &[u8])> {
+    let (year, rest) = read_digits(buf, 4)?;
+    if !starts_with_digit(rest) {
+        return Ok((DicomDate::from_y(year as u16)?, rest));
+    }
+    let (month, rest) = read_digits(rest, 2)?;
+    if !starts_with_digit(rest) {
+        return Ok((DicomDate::from_ym(year as u16, month as u8)?, rest));
+    }
+    let (day, rest) = read_digits(rest, 2)?;
+    Ok((
+        DicomDate::from_ymd(year as u16, month as u8, day as u8)?,
+        rest,
+    ))
+}
+
+/// Parse a time in the form `HH(MM(SS(.F{1,6})?)?)?`.
+fn parse_time_prefix(buf: &[u8]) -> Result<(DicomTime, &[u8])> {
+    let (hour, rest) = read_digits(buf, 2)?;
+    if !starts_with_digit(rest) {
+        return Ok((DicomTime::from_h(hour as u8)?, rest));
+    }
+    let (minute, rest) = read_digits(rest, 2)?;
+    if !starts_with_digit(rest) {
+        return Ok((DicomTime::from_hm(hour as u8, minute as u8)?, rest));
+    }
+    let (second, rest) = read_digits(rest, 2)?;
+    let rest = match rest.split_first() {
+        Some((b'.', rest)) => rest,
+        _ => {
+            return Ok((
+                DicomTime::from_hms(hour as u8, minute as u8, second as u8)?,
+                rest,
+            ))
+        }
+    };
+    let digits = rest
+        .iter()
+        .take(6)
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    if digits == 0 {
+        return Err(match rest.first() {
+            Some(&c) => InvalidValueReadError::InvalidToken(c, "digit in 0..9"),
+            None => InvalidValueReadError::UnexpectedEndOfElement,
+        });
+    }
+    let (fraction, rest) = read_digits(rest, digits)?;
+    Ok((
+        DicomTime::from_hms_fraction(
+            hour as u8,
+            minute as u8,
+            second as u8,
+            fraction,
+            digits as u8,
+        )?,
+        rest,
+    ))
+}
+
+/// Parse a date-time in the form `YYYY(MM(DD(HH(MM(SS(.F{1,6})?)?)?)?)?)?(&ZZXX)?`.
+fn parse_datetime_prefix(buf: &[u8]) -> Result<(DicomDateTime, &[u8])> {
+    let (date, rest) = parse_date_prefix(buf)?;
+    let (mut datetime, rest) = if starts_with_digit(rest) {
+        let (time, rest) = parse_time_prefix(rest)?;
+        (DicomDateTime::from_date_and_time(date, time)?, rest)
+    } else {
+        (DicomDateTime::from_date(date), rest)
+    };
+    let rest = match rest.first() {
+        Some(b'+' | b'-') => {
+            let (offset, rest) = parse_offset_prefix(rest)?;
+            datetime = datetime.with_offset(offset);
+            rest
+        }
+        _ => rest,
+    };
+    Ok((datetime, rest))
+}
+
+/// Parse an offset from UTC in the form `&ZZXX`.
+fn parse_offset_prefix(buf: &[u8]) -> Result<(FixedOffset, &[u8])> {
+    let (sign, rest) = match buf.split_first() {
+        Some((&sign @ (b'+' | b'-'), rest)) => (sign, rest),
+        Some((&c, _)) => return Err(InvalidValueReadError::InvalidToken(c, "'+' or '-'")),
+        None => return Err(InvalidValueReadError::UnexpectedEndOfElement),
+    };
+    let (hhmm, rest) = read_digits(rest, 4)?;
+    let (hours, minutes) = (hhmm / 100, hhmm % 100);
+    if minutes >= 60 {
+        return Err(InvalidValueReadError::ParseDateTime(
+            minutes,
+            "offset minutes (in 0..60)",
+        ));
+    }
+    let secs = ((hours * 60 + minutes) * 60) as i32;
+    let offset = if sign == b'+' {
+        FixedOffset::east_opt(secs)
+    } else {
+        FixedOffset::west_opt(secs)
+    };
+    Ok((offset.ok_or(InvalidValueReadError::DateTimeZone)?, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn time(h: u32, m: u32, s: u32, micro: u32) -> NaiveTime {
+        NaiveTime::from_hms_micro_opt(h, m, s, micro).unwrap()
+    }
+
+    #[test]
+    fn parse_dates() {
+        let d: DicomDate = "20180101".parse().unwrap();
+        assert_eq!(d, DicomDate::from_ymd(2018, 1, 1).unwrap());
+        assert_eq!(d.to_naive_date().unwrap(), date(2018, 1, 1));
+        let d: DicomDate = "19711231".parse().unwrap();
+        assert_eq!(d.to_naive_date().unwrap(), date(1971, 12, 31));
+
+        let d: DicomDate = "2019".parse().unwrap();
+        assert_eq!(d.precision(), DateComponent::Year);
+        assert_eq!(d.earliest().unwrap(), date(2019, 1, 1));
+        assert_eq!(d.latest().unwrap(), date(2019, 12, 31));
+        assert_eq!(
+            d.to_naive_date(),
+            Err(InvalidValueReadError::ImpreciseValue)
+        );
+
+        let d: DicomDate = "202002".parse().unwrap();
+        assert_eq!(d.precision(), DateComponent::Month);
+        assert_eq!(d.earliest().unwrap(), date(2020, 2, 1));
+        assert_eq!(d.latest().unwrap(), date(2020, 2, 29));
+        let d: DicomDate = "201912".parse().unwrap();
+        assert_eq!(d.latest().unwrap(), date(2019, 12, 31));
+
+        for text in &["2019", "201903", "20190315"] {
+            assert_eq!(text.parse::<DicomDate>().unwrap().to_string(), *text);
+        }
+
+        assert!("".parse::<DicomDate>().is_err());
+        assert!("        ".parse::<DicomDate>().is_err());
+        assert!("--------".parse::<DicomDate>().is_err());
+        assert!("00000000".parse::<DicomDate>().is_err());
+        assert!("19991313".parse::<DicomDate>().is_err());
+        assert!("20180229".parse::<DicomDate>().is_err());
+        assert!("nothing!".parse::<DicomDate>().is_err());
+        assert!("2012dec".parse::<DicomDate>().is_err());
+        assert!("20180101xxxx".parse::<DicomDate>().is_err());
+        assert!("2019031".parse::<DicomDate>().is_err());
+    }
+
+    #[test]
+    fn parse_times() {
+        let cases = [
+            ("10", time(10, 0, 0, 0), time(10, 59, 59, 999_999)),
+            ("0755", time(7, 55, 0, 0), time(7, 55, 59, 999_999)),
+            ("075500", time(7, 55, 0, 0), time(7, 55, 0, 999_999)),
+            ("065003", time(6, 50, 3, 0), time(6, 50, 3, 999_999)),
+            ("075501.5", time(7, 55, 1, 500_000), time(7, 55, 1, 599_999)),
+            (
+                "075501.58",
+                time(7, 55, 1, 580_000),
+                time(7, 55, 1, 589_999),
+            ),
+            (
+                "101010.204",
+                time(10, 10, 10, 204_000),
+                time(10, 10, 10, 204_999),
+            ),
+            (
+                "235959.99999",
+                time(23, 59, 59, 999_990),
+                time(23, 59, 59, 999_999),
+            ),
+            (
+                "075501.123456",
+                time(7, 55, 1, 123_456),
+                time(7, 55, 1, 123_456),
+            ),
+            (
+                "075501.012300",
+                time(7, 55, 1, 12_300),
+                time(7, 55, 1, 12_300),
+            ),
+        ];
+        for (text, earliest, latest) in &cases {
+            let t: DicomTime = text.parse().unwrap();
+            assert_eq!(t.earliest().unwrap(), *earliest, "earliest of {}", text);
+            assert_eq!(t.latest().unwrap(), *latest, "latest of {}", text);
+            assert_eq!(t.to_string(), *text);
+        }
+
+        let t: DicomTime = "0755".parse().unwrap();
+        assert_eq!(t.precision(), DateComponent::Minute);
+        assert_eq!(
+            t.to_naive_time(),
+            Err(InvalidValueReadError::ImpreciseValue)
+        );
+        let t: DicomTime = "235960".parse().unwrap();
+        assert_eq!(t.to_naive_time().unwrap(), time(23, 59, 59, 1_000_000));
+        assert_eq!(DicomTime::from(time(23, 59, 59, 1_000_000)), t);
+        assert_eq!(
+            DicomTime::from(time(7, 55, 1, 500_000)).to_string(),
+            "075501.500000"
+        );
+
+        assert!("".parse::<DicomTime>().is_err());
+        assert!("      ".parse::<DicomTime>().is_err());
+        assert!("------.----".parse::<DicomTime>().is_err());
+        assert!("075501.".parse::<DicomTime>().is_err());
+        assert!("075501.----".parse::<DicomTime>().is_err());
+        assert!("075501.1234567".parse::<DicomTime>().is_err());
+        assert!("235800.0a".parse::<DicomTime>().is_err());
+        assert!("246000".parse::<DicomTime>().is_err());
+        assert!("nope".parse::<DicomTime>().is_err());
+        assert!("235959.123456max precision".parse::<DicomTime>().is_err());
+    }
+
+    #[test]
+    fn parse_datetimes() {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let dt: DicomDateTime = "201801010930".parse().unwrap();
+        assert_eq!(dt.precision(), DateComponent::Minute);
+        assert_eq!(dt.offset(), None);
+        assert_eq!(
+            dt.earliest().unwrap(),
+            utc.from_local_datetime(&date(2018, 1, 1).and_time(time(9, 30, 0, 0)))
+                .unwrap()
+        );
+        assert_eq!(
+            dt.to_chrono_datetime(),
+            Err(InvalidValueReadError::ImpreciseValue)
+        );
+
+        let dt: DicomDateTime = "20171130101010.204+0535".parse().unwrap();
+        let offset = FixedOffset::east_opt(5 * 3600 + 35 * 60).unwrap();
+        assert_eq!(dt.offset(), Some(offset));
+        assert_eq!(
+            format!("{:?}", dt.to_chrono_datetime().unwrap()),
+            "2017-11-30T10:10:10.204+05:35"
+        );
+
+        let dt: DicomDateTime = "20171130101010.204-1000".parse().unwrap();
+        assert_eq!(dt.utc_offset(), FixedOffset::west_opt(10 * 3600).unwrap());
+
+        // the default offset applies only without an explicit offset
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        let dt: DicomDateTime = "20140426".parse().unwrap();
+        let dt = dt.with_default_offset(cet);
+        assert_eq!(
+            format!("{:?}", dt.earliest().unwrap()),
+            "2014-04-26T00:00:00+01:00"
+        );
+        assert_eq!(
+            format!("{:?}", dt.latest().unwrap()),
+            "2014-04-26T23:59:59.999999+01:00"
+        );
+        assert_eq!(dt.to_string(), "20140426");
+        let dt: DicomDateTime = "2014-0300".parse().unwrap();
+        assert_eq!(
+            dt.with_default_offset(cet).utc_offset(),
+            FixedOffset::west_opt(3 * 3600).unwrap()
+        );
+
+        for text in &[
+            "2019",
+            "201903+0100",
+            "20190315",
+            "2019031510",
+            "20190315103015.25-0300",
+            "20190315103015.000000+0000",
+        ] {
+            assert_eq!(text.parse::<DicomDateTime>().unwrap().to_string(), *text);
+        }
+
+        let chrono_dt = cet
+            .from_local_datetime(&date(2017, 11, 30).and_time(time(10, 10, 10, 0)))
+            .unwrap();
+        let dt = DicomDateTime::try_from(chrono_dt).unwrap();
+        assert_eq!(dt.to_string(), "20171130101010+0100");
+        assert_eq!(dt.to_chrono_datetime().unwrap(), chrono_dt);
+
+        assert!("".parse::<DicomDateTime>().is_err());
+        assert!("nope".parse::<DicomDateTime>().is_err());
+        assert!("2015dec".parse::<DicomDateTime>().is_err());
+        assert!("20151231162945.".parse::<DicomDateTime>().is_err());
+        assert!("20151130161445+".parse::<DicomDateTime>().is_err());
+        assert!("20151130161445+01".parse::<DicomDateTime>().is_err());
+        assert!("20151130161445+0160".parse::<DicomDateTime>().is_err());
+        assert!("        ".parse::<DicomDateTime>().is_err());
+        assert!("20151130161445+----".parse::<DicomDateTime>().is_err());
+        assert!("20151130161445. ".parse::<DicomDateTime>().is_err());
+        assert!("20151130161445. +0000".parse::<DicomDateTime>().is_err());
+        assert!("20100423164000.001+3".parse::<DicomDateTime>().is_err());
+        assert!("200809112945*1000".parse::<DicomDateTime>().is_err());
+    }
+
+    /// The cases of the date and time parsers formerly in `dicom-parser`.
+    #[test]
+    fn parse_cases_of_former_parsers() {
+        // trailing data used to be left for the caller,
+        // and is now only accepted by the prefix parsers
+        let (d, rest) = parse_date_prefix(b"20180101xxxx").unwrap();
+        assert_eq!(d.to_naive_date().unwrap(), date(2018, 1, 1));
+        assert_eq!(rest, b"xxxx");
+        let (t, rest) = parse_time_prefix(b"235959.123456max precision").unwrap();
+        assert_eq!(t.to_naive_time().unwrap(), time(23, 59, 59, 123_456));
+        assert_eq!(rest, b"max precision");
+        let (t, rest) = parse_time_prefix(b"100003.123456...").unwrap();
+        assert_eq!(t.to_naive_time().unwrap(), time(10, 0, 3, 123_456));
+        assert_eq!(rest, b"...");
+        let (t, rest) = parse_time_prefix(b"075501.123456-05:00").unwrap();
+        assert_eq!(t.to_naive_time().unwrap(), time(7, 55, 1, 123_456));
+        assert_eq!(rest, b"-05:00");
+
+        for input in &[&[0x00_u8; 8][..], &[0xFF_u8; 8], &[b'0'; 8]] {
+            assert!(parse_complete(input, parse_date_prefix).is_err());
+            assert!(parse_complete(input, parse_datetime_prefix).is_err());
+        }
+        for input in &[&[0x00_u8; 6][..], &[0xFF_u8; 6], b"------"] {
+            assert!(parse_complete(input, parse_time_prefix).is_err());
+        }
+        assert!("075501.123......".parse::<DicomTime>().is_err());
+        assert!("235959.9999+0100".parse::<DicomTime>().is_err());
+        // a fraction of less than 6 digits is a valid precision
+        let t: DicomTime = "235959.9999".parse().unwrap();
+        assert_eq!(t.earliest().unwrap(), time(23, 59, 59, 999_900));
+
+        let dt: DicomDateTime = "19711231065003".parse().unwrap();
+        assert_eq!(
+            dt.to_chrono_datetime().unwrap(),
+            utc()
+                .from_local_datetime(&date(1971, 12, 31).and_time(time(6, 50, 3, 0)))
+                .unwrap()
+        );
+        let dt: DicomDateTime = "20180314000000.25".parse().unwrap();
+        assert_eq!(
+            dt.earliest().unwrap(),
+            utc()
+                .from_local_datetime(&date(2018, 3, 14).and_time(time(0, 0, 0, 250_000)))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_ranges() {
+        let range: DateRange = "20190101-20190131".parse().unwrap();
+        assert_eq!(range.earliest().unwrap(), Some(date(2019, 1, 1)));
+        assert_eq!(range.latest().unwrap(), Some(date(2019, 1, 31)));
+        assert!(range.contains(&date(2019, 1, 15)).unwrap());
+        assert!(!range.contains(&date(2019, 2, 1)).unwrap());
+        assert_eq!(range.to_string(), "20190101-20190131");
+
+        let range: DateRange = "-2018".parse().unwrap();
+        assert_eq!(range.start(), None);
+        assert_eq!(range.latest().unwrap(), Some(date(2018, 12, 31)));
+        assert!(range.contains(&date(1900, 1, 1)).unwrap());
+
+        let range: TimeRange = "10-1130".parse().unwrap();
+        assert!(range.contains(&time(11, 30, 59, 0)).unwrap());
+        assert!(!range.contains(&time(9, 59, 59, 0)).unwrap());
+
+        // negative offsets are not mistaken for the range separator
+        let range: DateTimeRange = "20190101-0500-20190102".parse().unwrap();
+        assert_eq!(range.start().unwrap().to_string(), "20190101-0500");
+        assert_eq!(range.end().unwrap().to_string(), "20190102");
+        let range: DateTimeRange = "201901011200-".parse().unwrap();
+        assert_eq!(range.end(), None);
+        assert_eq!(range.to_string(), "201901011200-");
+
+        assert_eq!(
+            "-".parse::<DateRange>(),
+            Err(InvalidValueReadError::InvalidRange)
+        );
+        assert_eq!(
+            "20190101".parse::<DateRange>(),
+            Err(InvalidValueReadError::InvalidRange)
+        );
+        assert!("2019x-2020".parse::<DateRange>().is_err());
+    }
+
+    #[test]
+    fn parse_utc_offsets() {
+        assert_eq!(
+            parse_utc_offset("+0100").unwrap(),
+            FixedOffset::east_opt(3600).unwrap()
+        );
+        assert_eq!(
+            parse_utc_offset("-0530 ").unwrap(),
+            FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap()
+        );
+        assert!(parse_utc_offset("0100").is_err());
+        assert!(parse_utc_offset("+01").is_err());
+        assert!(parse_utc_offset("+0160").is_err());
+        assert!(parse_utc_offset("").is_err());
+    }
+}
@@ -0,0 +1,244 @@
+//! This module contains a structured representation of person names
+//! (value representation PN).
+//!
+//! A person name is made of up to three component groups, separated by
+//! `=`: an alphabetic representation, an ideographic representation and
+//! a phonetic representation. Each group is made of up to five
+//! components, separated by `^`: family name, given name, middle name,
+//! prefix and suffix.
+//!
+//! ```
+//! # use dicom_core::value::PersonName;
+//! let name = PersonName::parse("Yamada^Tarou=山田^太郎=やまだ^たろう");
+//! assert_eq!(name.alphabetic.family, "Yamada");
+//! assert_eq!(name.ideographic.given, "太郎");
+//! assert_eq!(name.phonetic.family, "やまだ");
+//! assert_eq!(name.to_string(), "Yamada^Tarou=山田^太郎=やまだ^たろう");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The separator of component groups.
+const GROUP_SEPARATOR: char = '=';
+
+/// The separator of components within a group.
+const COMPONENT_SEPARATOR: char = '^';
+
+/// A component group of a person name,
+/// with its five components.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PersonNameComponents {
+    /// family name complex
+    pub family: String,
+    /// given name complex
+    pub given: String,
+    /// middle name
+    pub middle: String,
+    /// name prefix (e.g. "Dr.")
+    pub prefix: String,
+    /// name suffix (e.g. "Jr.")
+    pub suffix: String,
+}
+
+impl PersonNameComponents {
+    /// Parse a single component group.
+    ///
+    /// Component separators beyond the fifth component are kept
+    /// in the suffix, so that no content is lost.
+    pub fn parse(group: &str) -> Self {
+        let mut parts = group.splitn(5, COMPONENT_SEPARATOR);
+        let mut next = || parts.next().unwrap_or_default().to_string();
+        PersonNameComponents {
+            family: next(),
+            given: next(),
+            middle: next(),
+            prefix: next(),
+            suffix: next(),
+        }
+    }
+
+    /// Check whether all components are empty.
+    pub fn is_empty(&self) -> bool {
+        self.components().iter().all(|c| c.is_empty())
+    }
+
+    /// Retrieve the components in their standard order:
+    /// family, given, middle, prefix and suffix.
+    pub fn components(&self) -> [&str; 5] {
+        [
+            &self.family,
+            &self.given,
+            &self.middle,
+            &self.prefix,
+            &self.suffix,
+        ]
+    }
+}
+
+/// Formats the component group in its DICOM representation,
+/// without trailing component separators.
+impl fmt::Display for PersonNameComponents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let components = self.components();
+        let len = components
+            .iter()
+            .rposition(|c| !c.is_empty())
+            .map_or(0, |i| i + 1);
+        for (i, c) in components[..len].iter().enumerate() {
+            if i > 0 {
+                f.write_str("^")?;
+            }
+            f.write_str(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// A person name (value representation PN), with its alphabetic,
+/// ideographic and phonetic component groups.
+///
+/// Missing groups and components are empty.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PersonName {
+    /// the alphabetic representation
+    pub alphabetic: PersonNameComponents,
+    /// the ideographic representation
+    pub ideographic: PersonNameComponents,
+    /// the phonetic representation
+    pub phonetic: PersonNameComponents,
+}
+
+impl PersonName {
+    /// Create a person name with only an alphabetic representation.
+    pub fn new(alphabetic: PersonNameComponents) -> Self {
+        PersonName {
+            alphabetic,
+            ..Default::default()
+        }
+    }
+
+    /// Parse a single person name value in its DICOM representation.
+    ///
+    /// Trailing spaces are ignored. Parsing never fails: group separators
+    /// beyond the third group are kept in the phonetic group, so that the
+    /// value is preserved when formatted back.
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim_end_matches([' ', '\0']);
+        let mut groups = value.splitn(3, GROUP_SEPARATOR);
+        let mut next = || PersonNameComponents::parse(groups.next().unwrap_or_default());
+        PersonName {
+            alphabetic: next(),
+            ideographic: next(),
+            phonetic: next(),
+        }
+    }
+
+    /// Retrieve the component groups in their standard order:
+    /// alphabetic, ideographic and phonetic.
+    pub fn groups(&self) -> [&PersonNameComponents; 3] {
+        [&self.alphabetic, &self.ideographic, &self.phonetic]
+    }
+
+    /// Check whether all component groups are empty.
+    pub fn is_empty(&self) -> bool {
+        self.groups().iter().all(|g| g.is_empty())
+    }
+
+    /// The family name, from the alphabetic representation.
+    pub fn family_name(&self) -> &str {
+        &self.alphabetic.family
+    }
+
+    /// The given name, from the alphabetic representation.
+    pub fn given_name(&self) -> &str {
+        &self.alphabetic.given
+    }
+
+    /// The middle name, from the alphabetic representation.
+    pub fn middle_name(&self) -> &str {
+        &self.alphabetic.middle
+    }
+
+    /// The name prefix, from the alphabetic representation.
+    pub fn prefix(&self) -> &str {
+        &self.alphabetic.prefix
+    }
+
+    /// The name suffix, from the alphabetic representation.
+    pub fn suffix(&self) -> &str {
+        &self.alphabetic.suffix
+    }
+}
+
+impl From<&str> for PersonName {
+    fn from(value: &str) -> Self {
+        PersonName::parse(value)
+    }
+}
+
+impl FromStr for PersonName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(PersonName::parse(s))
+    }
+}
+
+/// Formats the person name in its DICOM representation,
+/// without trailing separators.
+impl fmt::Display for PersonName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let groups = self.groups();
+        let len = groups
+            .iter()
+            .rposition(|g| !g.is_empty())
+            .map_or(0, |i| i + 1);
+        for (i, g) in groups[..len].iter().enumerate() {
+            if i > 0 {
+                f.write_str("=")?;
+            }
+            write!(f, "{}", g)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format_person_names() {
+        let name = PersonName::parse("Adams^John Robert Quincy^^Rev.^B.A. M.Div. ");
+        assert_eq!(name.family_name(), "Adams");
+        assert_eq!(name.given_name(), "John Robert Quincy");
+        assert_eq!(name.middle_name(), "");
+        assert_eq!(name.prefix(), "Rev.");
+        assert_eq!(name.suffix(), "B.A. M.Div.");
+        assert!(name.ideographic.is_empty());
+        assert_eq!(
+            name.to_string(),
+            "Adams^John Robert Quincy^^Rev.^B.A. M.Div."
+        );
+
+        // only an ideographic representation
+        let name = PersonName::parse("=山田^太郎");
+        assert!(name.alphabetic.is_empty());
+        assert_eq!(name.ideographic.family, "山田");
+        assert_eq!(name.to_string(), "=山田^太郎");
+
+        // trailing separators are dropped
+        assert_eq!(PersonName::parse("Doe^John^^^==").to_string(), "Doe^John");
+
+        // excess separators are preserved
+        let name = PersonName::parse("a^b^c^d^e^f=g=h=i");
+        assert_eq!(name.alphabetic.suffix, "e^f");
+        assert_eq!(name.phonetic.suffix, "");
+        assert_eq!(name.phonetic.family, "h=i");
+        assert_eq!(name.to_string(), "a^b^c^d^e^f=g=h=i");
+
+        assert!(PersonName::parse("").is_empty());
+        assert_eq!(PersonName::default().to_string(), "");
+    }
+}
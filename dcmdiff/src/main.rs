@@ -0,0 +1,111 @@
+//! A CLI tool for comparing the contents of two DICOM files.
+//!
+//! Differences are printed one per line: `+` for elements only in the
+//! second file, `-` for elements only in the first file, and `~` for
+//! elements with a different VR or value. Nested elements are prefixed
+//! with the path of sequence items leading to them.
+//! The process exits with status 1 if any difference was found,
+//! and with status 2 if the files could not be compared.
+//!
+//! Usage:
+//!
+//! ```none
+//! dcmdiff [--ignore-tag <TAG>]... [--ignore-padding] [--pixel-hash] <file1.dcm> <file2.dcm>
+//! ```
+//!
+//! Tags to ignore can be given as `(gggg,eeee)`, `gggg,eeee`, `ggggeeee` or by keyword
+//! (e.g. `SOPInstanceUID`).
+use clap::{App, Arg};
+use dicom::core::dictionary::DataDictionary;
+use dicom::core::Tag;
+use dicom::object::diff::{diff, DiffOptions};
+use dicom::object::{open_file, StandardDataDictionary};
+
+use std::io::{stdout, ErrorKind, Write};
+
+type DynResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+fn main() {
+    match run() {
+        Ok(false) => {}
+        Ok(true) => std::process::exit(1),
+        Err(e) => {
+            eprintln!("[ERROR] {}", e);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Compare the files given in the command line,
+/// returning whether any difference was found.
+fn run() -> DynResult<bool> {
+    let matches = App::new("dcmdiff")
+        .version("0.1.0")
+        .about("Compare two DICOM files")
+        .arg(
+            Arg::with_name("FILE1")
+                .required(true)
+                .help("The original DICOM file"),
+        )
+        .arg(
+            Arg::with_name("FILE2")
+                .required(true)
+                .help("The DICOM file to compare against"),
+        )
+        .arg(
+            Arg::with_name("ignore-tag")
+                .long("ignore-tag")
+                .short("i")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .help("A tag or keyword of an attribute to ignore"),
+        )
+        .arg(
+            Arg::with_name("ignore-padding")
+                .long("ignore-padding")
+                .help("Ignore differences in trailing padding of text values"),
+        )
+        .arg(
+            Arg::with_name("pixel-hash")
+                .long("pixel-hash")
+                .help("Compare pixel data by hash only"),
+        )
+        .get_matches();
+
+    let ignored_tags = matches
+        .values_of("ignore-tag")
+        .into_iter()
+        .flatten()
+        .map(parse_tag)
+        .collect::<DynResult<Vec<_>>>()?;
+
+    let options = DiffOptions::new()
+        .ignore_tags(ignored_tags)
+        .ignore_padding(matches.is_present("ignore-padding"))
+        .pixel_data_by_hash(matches.is_present("pixel-hash"));
+
+    let left = open_file(matches.value_of("FILE1").unwrap())?;
+    let right = open_file(matches.value_of("FILE2").unwrap())?;
+
+    let differences = diff(&left, &right, &options);
+
+    let mut to = stdout();
+    for difference in &differences {
+        match writeln!(to, "{}", difference) {
+            Err(ref e) if e.kind() == ErrorKind::BrokenPipe => break,
+            Err(e) => return Err(e.into()),
+            Ok(_) => {}
+        }
+    }
+
+    Ok(!differences.is_empty())
+}
+
+/// Parse a tag from its `(gggg,eeee)`, `gggg,eeee` or `ggggeeee` form,
+/// or from the attribute keyword.
+fn parse_tag(txt: &str) -> DynResult<Tag> {
+    StandardDataDictionary
+        .parse_tag(txt)
+        .ok_or_else(|| format!("Unknown tag or keyword: {}", txt).into())
+}
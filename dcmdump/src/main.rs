@@ -12,6 +12,7 @@ use dicom::core::dictionary::{DataDictionary, DictionaryEntry};
 use dicom::core::header::Header;
 use dicom::core::value::{PrimitiveValue, Value as DicomValue};
 use dicom::core::VR;
+use dicom::dictionary_std::uids;
 use dicom::object::mem::{InMemDicomObject, InMemElement};
 use dicom::object::{open_file, DefaultDicomObject, FileMetaTable, StandardDataDictionary};
 
@@ -58,15 +59,23 @@ where
     writeln!(
         to,
         "Media Storage SOP Class UID: {}",
-        meta.media_storage_sop_class_uid
+        uid_with_name(&meta.media_storage_sop_class_uid)
     )?;
     writeln!(
         to,
         "Media Storage SOP Instance UID: {}",
         meta.media_storage_sop_instance_uid
     )?;
-    writeln!(to, "Transfer Syntax: {}", meta.transfer_syntax)?;
-    writeln!(to, "Implementation Class UID: {}", meta.transfer_syntax)?;
+    writeln!(
+        to,
+        "Transfer Syntax: {}",
+        uid_with_name(&meta.transfer_syntax)
+    )?;
+    writeln!(
+        to,
+        "Implementation Class UID: {}",
+        meta.implementation_class_uid
+    )?;
 
     if let Some(v) = meta.implementation_version_name.as_ref() {
         writeln!(to, "Implementation version name: {}", v)?;
@@ -102,24 +111,31 @@ where
 fn dump<W, D>(to: &mut W, obj: &InMemDicomObject<D>, width: u32, depth: u32) -> IoResult<()>
 where
     W: ?Sized + Write,
-    D: DataDictionary,
+    D: DataDictionary + Clone,
 {
     for elem in obj {
-        dump_element(&mut *to, &elem, width, depth)?;
+        dump_element(&mut *to, obj, elem, width, depth)?;
     }
 
     Ok(())
 }
 
-fn dump_element<W, D>(to: &mut W, elem: &InMemElement<D>, width: u32, depth: u32) -> IoResult<()>
+fn dump_element<W, D>(
+    to: &mut W,
+    obj: &InMemDicomObject<D>,
+    elem: &InMemElement<D>,
+    width: u32,
+    depth: u32,
+) -> IoResult<()>
 where
     W: ?Sized + Write,
-    D: DataDictionary,
+    D: DataDictionary + Clone,
 {
     let indent = vec![b' '; (depth * 2) as usize];
     let tag_alias = StandardDataDictionary
         .by_tag(elem.tag())
         .map(DictionaryEntry::alias)
+        .or_else(|| obj.private_entry(elem.tag()).map(DictionaryEntry::alias))
         .unwrap_or("«Unknown Attribute»");
     to.write_all(&indent)?;
     let vm = match elem.vr() {
@@ -161,7 +177,7 @@ where
 fn dump_item<W, D>(to: &mut W, item: &InMemDicomObject<D>, width: u32, depth: u32) -> IoResult<()>
 where
     W: ?Sized + Write,
-    D: DataDictionary,
+    D: DataDictionary + Clone,
 {
     let indent: String = std::iter::repeat(' ').take((depth * 2) as usize).collect();
     let trail: String = std::iter::repeat(' ')
@@ -181,6 +197,8 @@ fn value_summary(value: &PrimitiveValue, vr: VR, max_characters: u32) -> Cow<str
     match (value, vr) {
         (F32(values), _) => format_value_list(values, max_characters).into(),
         (F64(values), _) => format_value_list(values, max_characters).into(),
+        (DecimalString(values), _) => format_value_list(values, max_characters).into(),
+        (IntegerString(values), _) => format_value_list(values, max_characters).into(),
         (I32(values), _) => format_value_list(values, max_characters).into(),
         (I64(values), _) => format_value_list(values, max_characters).into(),
         (U32(values), _) => format_value_list(values, max_characters).into(),
@@ -199,6 +217,11 @@ fn value_summary(value: &PrimitiveValue, vr: VR, max_characters: u32) -> Cow<str
         .into(),
         (U8(values), _) => format_value_list(values, max_characters).into(),
         (Tags(values), _) => format_value_list(values, max_characters).into(),
+        (Strs(values), VR::UI) => format_value_list(
+            values.iter().map(|uid| uid_name(uid).unwrap_or(uid)),
+            max_characters,
+        )
+        .into(),
         (Strs(values), _) => format_value_list(values, max_characters).into(),
         (Date(values), _) => format_value_list(values, max_characters).into(),
         (Time(values), _) => format_value_list(values, max_characters).into(),
@@ -210,6 +233,20 @@ fn value_summary(value: &PrimitiveValue, vr: VR, max_characters: u32) -> Cow<str
     }
 }
 
+/// Retrieve the name of a well-known UID, such as "CT Image Storage".
+fn uid_name(uid: &str) -> Option<&'static str> {
+    uids::uid_entry(uid).map(|e| e.name)
+}
+
+/// Format a UID followed by its name, if it is a well-known UID.
+fn uid_with_name(uid: &str) -> Cow<'_, str> {
+    let uid = uid.trim_end_matches('\0');
+    match uid_name(uid) {
+        Some(name) => format!("{} ({})", uid, name).into(),
+        None => uid.into(),
+    }
+}
+
 fn format_value_list<I>(values: I, max_characters: u32) -> String
 where
     I: IntoIterator,
@@ -0,0 +1,94 @@
+//! A CLI tool for creating a DICOM file-set from a collection of DICOM files.
+//!
+//! The given files, and all files inside the given directories, are copied
+//! into the output directory under ISO 9660 compatible file IDs, and a
+//! DICOMDIR describing them is written alongside.
+//!
+//! Usage:
+//!
+//! ```none
+//! dcmfileset [--id <FILE_SET_ID>] --output <DIR> <INPUT>...
+//! ```
+use clap::{App, Arg};
+use dicom::object::dicomdir::{DirectoryRecord, FileSetBuilder};
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+type DynResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+fn main() -> DynResult<()> {
+    let matches = App::new("dcmfileset")
+        .version("0.1.0")
+        .about("Create a DICOM file-set with a DICOMDIR")
+        .arg(
+            Arg::with_name("INPUT")
+                .required(true)
+                .multiple(true)
+                .help("The DICOM files or directories of DICOM files to include"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .short("o")
+                .takes_value(true)
+                .required(true)
+                .help("The directory in which to create the file-set"),
+        )
+        .arg(
+            Arg::with_name("id")
+                .long("id")
+                .takes_value(true)
+                .default_value("")
+                .help(
+                    "The File-set ID (up to 16 upper case letters, digits, spaces or underscores)",
+                ),
+        )
+        .get_matches();
+
+    let mut files = Vec::new();
+    for input in matches.values_of("INPUT").unwrap() {
+        collect_files(Path::new(input), &mut files)?;
+    }
+    if files.is_empty() {
+        return Err("No input files found".into());
+    }
+
+    let out_dir = matches.value_of("output").unwrap();
+    let dicomdir = FileSetBuilder::new(matches.value_of("id").unwrap())
+        .add_files(files)
+        .build(out_dir)?;
+
+    println!(
+        "Created file-set in {} with {} patient(s) and {} file(s)",
+        out_dir,
+        dicomdir.records().len(),
+        count_files(dicomdir.records())
+    );
+    Ok(())
+}
+
+/// Count the records which reference a file, at any level.
+fn count_files(records: &[DirectoryRecord]) -> usize {
+    records
+        .iter()
+        .map(|r| r.referenced_file_id().map(|_| 1).unwrap_or(0) + count_files(r.children()))
+        .sum()
+}
+
+/// Collect the files at the given path, descending into directories.
+/// Existing DICOMDIR files are skipped.
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> DynResult<()> {
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path)?
+            .map(|e| e.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+        for entry in entries {
+            collect_files(&entry, files)?;
+        }
+    } else if path.file_name().map(|n| n != "DICOMDIR").unwrap_or(true) {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
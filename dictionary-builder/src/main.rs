@@ -8,6 +8,20 @@
 //! from the official DICOM website and store the result in "entries.rs".
 //! Future versions will enable different kinds of outputs.
 //!
+//! The registry of unique identifiers (tables A-1 and A-2 of PS3.6),
+//! which includes SOP classes, transfer syntaxes, well-known frames of
+//! reference and coding schemes, can also be written to a separate
+//! module with the `--uids` option.
+//!
+//! The command elements of group `0000` used in DIMSE messages are
+//! defined in PS3.7 instead (tables E.1-1 and E.2-1). These are fetched
+//! and written to a separate file with the `--commands` option.
+//!
+//! Constants for the tags of all attributes, such as
+//! `PATIENT_NAME: Tag = Tag(0x0010, 0x0010)`, can be written with the
+//! `--tags` option, and likewise for command elements with the
+//! `--command-tags` option.
+//!
 //! Please use the `--help` flag for the full usage information.
 
 use clap::{App, Arg};
@@ -36,6 +50,10 @@ use std::str::FromStr;
 const DEFAULT_LOCATION: &str =
     "http://dicom.nema.org/medical/dicom/current/source/docbook/part06/part06.xml";
 
+/// url to PS3.7 XML file
+const DEFAULT_COMMANDS_LOCATION: &str =
+    "http://dicom.nema.org/medical/dicom/current/source/docbook/part07/part07.xml";
+
 fn main() {
     let matches = App::new("DICOM Dictionary Builder")
         .version("0.1.0")
@@ -61,6 +79,44 @@ fn main() {
                 .possible_value("rs")
                 .possible_value("json"),
         )
+        .arg(
+            Arg::with_name("UIDS")
+                .short("u")
+                .long("uids")
+                .help("The path to the output file of the UID dictionary")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("TAGS")
+                .short("t")
+                .long("tags")
+                .help("The path to the output file of the tag constants")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("COMMAND_TAGS")
+                .long("command-tags")
+                .help("The path to the output file of the command element tag constants")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("COMMANDS")
+                .short("c")
+                .long("commands")
+                .help("The path to the output file of the command dictionary")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("COMMANDS_FROM")
+                .long("commands-from")
+                .help("Where to fetch the command dictionary (PS3.7) from")
+                .default_value(DEFAULT_COMMANDS_LOCATION)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("no-retired")
                 .help("Whether to ignore retired tags")
@@ -77,6 +133,10 @@ fn main() {
         _ => "entries",
     });
     let dst = Path::new(out_file);
+    let uids_dst = matches.value_of("UIDS").map(Path::new);
+    let tags_dst = matches.value_of("TAGS").map(Path::new);
+    let commands_dst = matches.value_of("COMMANDS").map(Path::new);
+    let command_tags_dst = matches.value_of("COMMAND_TAGS").map(Path::new);
 
     let mut core = Core::new().unwrap();
 
@@ -94,6 +154,16 @@ fn main() {
                     _ => unreachable!(),
                 }
                 .expect("Failed to write file");
+                if let Some(uids_dst) = uids_dst {
+                    let xml_uids = XmlUidIterator::new(&*body).map(|item| item.unwrap());
+                    to_uid_code_file(uids_dst, xml_uids, !ignore_retired)
+                        .expect("Failed to write UID file");
+                }
+                if let Some(tags_dst) = tags_dst {
+                    let xml_entries = XmlEntryIterator::new(&*body).map(|item| item.unwrap());
+                    to_tags_code_file(tags_dst, xml_entries, !ignore_retired)
+                        .expect("Failed to write tags file");
+                }
                 Ok(())
             })
         });
@@ -110,6 +180,55 @@ fn main() {
             _ => unreachable!(),
         }
         .expect("Failed to write file");
+
+        if let Some(uids_dst) = uids_dst {
+            let file = BufReader::new(File::open(src).unwrap());
+            let xml_uids = XmlUidIterator::new(file).map(|item| item.unwrap());
+            to_uid_code_file(uids_dst, xml_uids, !ignore_retired)
+                .expect("Failed to write UID file");
+        }
+
+        if let Some(tags_dst) = tags_dst {
+            let file = BufReader::new(File::open(src).unwrap());
+            let xml_entries = XmlEntryIterator::new(file).map(|item| item.unwrap());
+            to_tags_code_file(tags_dst, xml_entries, !ignore_retired)
+                .expect("Failed to write tags file");
+        }
+    }
+
+    if commands_dst.is_some() || command_tags_dst.is_some() {
+        let src = matches.value_of("COMMANDS_FROM").unwrap();
+        let command_entries: Vec<Entry> = if src.starts_with("http:") || src.starts_with("https:") {
+            let src = Uri::from_str(src).unwrap();
+            println!("Downloading DICOM command dictionary ...");
+            let req = xml_from_site(src).and_then(|resp| {
+                resp.into_body().concat2().map(|body: Chunk| {
+                    XmlCommandIterator::new(&*body)
+                        .map(|item| item.unwrap())
+                        .collect()
+                })
+            });
+            core.run(req).unwrap()
+        } else {
+            let file = BufReader::new(File::open(src).unwrap());
+            XmlCommandIterator::new(file)
+                .map(|item| item.unwrap())
+                .collect()
+        };
+
+        if let Some(commands_dst) = commands_dst {
+            let entries = command_entries.iter().cloned();
+            match format {
+                "rs" => to_code_file(commands_dst, entries, !ignore_retired),
+                "json" => to_json_file(commands_dst, entries),
+                _ => unreachable!(),
+            }
+            .expect("Failed to write command dictionary file");
+        }
+        if let Some(command_tags_dst) = command_tags_dst {
+            to_tags_code_file(command_tags_dst, command_entries, !ignore_retired)
+                .expect("Failed to write command tags file");
+        }
     }
 }
 
@@ -338,6 +457,263 @@ impl<R: BufRead> Iterator for XmlEntryIterator<R> {
     }
 }
 
+/// The identifiers of the tables with command elements in PS3.7,
+/// and whether their elements are retired.
+const COMMAND_TABLES: &[(&[u8], bool)] = &[(b"table_E.1-1", false), (b"table_E.2-1", true)];
+
+/// An iterator over the command elements in tables E.1-1 and E.2-1
+/// of PS3.7.
+///
+/// Unlike the data dictionary of PS3.6, each row starts with the name
+/// of the element, followed by the tag, keyword, VR and VM. Retired
+/// command elements are listed in a separate table, so their entries
+/// are marked as retired here.
+struct XmlCommandIterator<R: BufRead> {
+    parser: Reader<R>,
+    buf: Vec<u8>,
+    /// whether the elements of the current table are retired, if in one
+    table_retired: Option<bool>,
+    in_body: bool,
+    cells: Vec<String>,
+}
+
+impl<R: BufRead> XmlCommandIterator<R> {
+    pub fn new(xml: R) -> XmlCommandIterator<R> {
+        let mut reader = Reader::from_reader(xml);
+        reader.expand_empty_elements(true).trim_text(true);
+        XmlCommandIterator {
+            parser: reader,
+            buf: Vec::new(),
+            table_retired: None,
+            in_body: false,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Build an entry out of the cells of the row just read.
+    fn take_entry(&mut self, retired: bool) -> Option<Entry> {
+        let mut cells = std::mem::take(&mut self.cells)
+            .into_iter()
+            .map(|cell| Some(cell).filter(|c| !c.is_empty()));
+        let name = cells.next()?;
+        let tag = cells.next()??;
+        Some(Entry {
+            tag,
+            name,
+            alias: cells.next()?,
+            vr: cells.next()?,
+            vm: cells.next()?,
+            obs: if retired {
+                Some("RET".to_string())
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for XmlCommandIterator<R> {
+    type Item = XmlResult<Entry>;
+    fn next(&mut self) -> Option<XmlResult<Entry>> {
+        loop {
+            self.buf.clear();
+            match self.parser.read_event(&mut self.buf) {
+                Ok(Event::Start(ref e)) => match (self.table_retired, e.local_name()) {
+                    (None, b"table") => {
+                        for attr in e.attributes() {
+                            let attr = match attr {
+                                Ok(attr) => attr,
+                                Err(err) => return Some(Err(err)),
+                            };
+                            if attr.key != b"xml:id" {
+                                continue;
+                            }
+                            if let Some((_, retired)) =
+                                COMMAND_TABLES.iter().find(|(id, _)| **id == *attr.value)
+                            {
+                                self.table_retired = Some(*retired);
+                            }
+                        }
+                    }
+                    (Some(_), b"tbody") => {
+                        self.in_body = true;
+                    }
+                    (Some(_), b"td") if self.in_body => {
+                        self.cells.push(String::new());
+                    }
+                    _ => {}
+                },
+                Ok(Event::End(ref e)) => match (self.table_retired, e.local_name()) {
+                    (Some(retired), b"tr") if self.in_body => {
+                        if let Some(entry) = self.take_entry(retired) {
+                            return Some(Ok(entry));
+                        }
+                    }
+                    (Some(_), b"tbody") => {
+                        // the table ended, look for the next one
+                        self.in_body = false;
+                        self.table_retired = None;
+                    }
+                    _ => {}
+                },
+                Ok(Event::Text(data)) if self.in_body => {
+                    let data = data
+                        .unescape_and_decode(&self.parser)
+                        .unwrap()
+                        .replace("\u{200b}", "");
+                    if let Some(cell) = self.cells.last_mut() {
+                        if !cell.is_empty() {
+                            cell.push(' ');
+                        }
+                        cell.push_str(data.trim());
+                    }
+                }
+                Ok(Event::Eof { .. }) => {
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// An entry of the registry of unique identifiers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct UidEntry {
+    uid: String,
+    name: String,
+    keyword: Option<String>,
+    uid_type: String,
+}
+
+/// The identifiers of the tables with unique identifiers,
+/// and the UID type to use when a table has no such column.
+const UID_TABLES: &[(&[u8], Option<&str>)] = &[
+    (b"table_A-1", None),
+    (b"table_A-2", Some("Well-known frame of reference")),
+];
+
+/// An iterator over the unique identifiers in tables A-1 and A-2 of PS3.6.
+///
+/// Each row is read as a list of cells, where the first ones are the UID
+/// value, name and keyword. The UID type is the fourth cell in table A-1,
+/// whereas all UIDs in table A-2 are well-known frames of reference.
+struct XmlUidIterator<R: BufRead> {
+    parser: Reader<R>,
+    buf: Vec<u8>,
+    /// the UID type of all entries in the current table, if fixed
+    table_type: Option<Option<&'static str>>,
+    in_body: bool,
+    cells: Vec<String>,
+}
+
+impl<R: BufRead> XmlUidIterator<R> {
+    pub fn new(xml: R) -> XmlUidIterator<R> {
+        let mut reader = Reader::from_reader(xml);
+        reader.expand_empty_elements(true).trim_text(true);
+        XmlUidIterator {
+            parser: reader,
+            buf: Vec::new(),
+            table_type: None,
+            in_body: false,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Build an entry out of the cells of the row just read.
+    fn take_entry(&mut self, table_type: Option<&str>) -> Option<UidEntry> {
+        let mut cells = std::mem::take(&mut self.cells).into_iter();
+        let uid = cells.next().filter(|uid| !uid.is_empty())?;
+        let name = cells.next().unwrap_or_default();
+        let keyword = cells.next().filter(|k| !k.is_empty());
+        let uid_type = match table_type {
+            Some(t) => t.to_string(),
+            None => cells.next().unwrap_or_default(),
+        };
+        Some(UidEntry {
+            uid,
+            name,
+            keyword,
+            uid_type,
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for XmlUidIterator<R> {
+    type Item = XmlResult<UidEntry>;
+    fn next(&mut self) -> Option<XmlResult<UidEntry>> {
+        loop {
+            self.buf.clear();
+            match self.parser.read_event(&mut self.buf) {
+                Ok(Event::Start(ref e)) => match (self.table_type, e.local_name()) {
+                    (None, b"table") => {
+                        for attr in e.attributes() {
+                            let attr = match attr {
+                                Ok(attr) => attr,
+                                Err(err) => return Some(Err(err)),
+                            };
+                            if attr.key != b"xml:id" {
+                                continue;
+                            }
+                            if let Some((_, t)) =
+                                UID_TABLES.iter().find(|(id, _)| **id == *attr.value)
+                            {
+                                self.table_type = Some(*t);
+                            }
+                        }
+                    }
+                    (Some(_), b"tbody") => {
+                        self.in_body = true;
+                    }
+                    (Some(_), b"td") if self.in_body => {
+                        self.cells.push(String::new());
+                    }
+                    _ => {}
+                },
+                Ok(Event::End(ref e)) => match (self.table_type, e.local_name()) {
+                    (Some(table_type), b"tr") if self.in_body => {
+                        if let Some(entry) = self.take_entry(table_type) {
+                            return Some(Ok(entry));
+                        }
+                    }
+                    (Some(_), b"tbody") => {
+                        // the table ended, look for the next one
+                        self.in_body = false;
+                        self.table_type = None;
+                    }
+                    _ => {}
+                },
+                Ok(Event::Text(data)) if self.in_body => {
+                    let data = data
+                        .unescape_and_decode(&self.parser)
+                        .unwrap()
+                        .replace("\u{200b}", "");
+                    if let Some(cell) = self.cells.last_mut() {
+                        if !cell.is_empty() {
+                            cell.push(' ');
+                        }
+                        cell.push_str(data.trim());
+                    }
+                }
+                Ok(Event::Eof { .. }) => {
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        None
+    }
+}
+
 fn to_code_file<P: AsRef<Path>, I>(dest_path: P, entries: I, include_retired: bool) -> DynResult<()>
 where
     I: IntoIterator<Item = Entry>,
@@ -349,7 +725,9 @@ where
 
     f.write_all(
         b"//! Automatically generated. Edit at your own risk.\n\n\
-    use dicom_core::dictionary::{DictionaryEntryRef, TagRange::*};\n\
+    use dicom_core::dictionary::{\n    \
+        DictionaryEntryRef, TagRange::*, ValueMultiplicity as VM, VirtualVr::*,\n\
+    };\n\
     use dicom_core::Tag;\n\
     use dicom_core::VR::*;\n\n\
     type E = DictionaryEntryRef<'static>;\n\n\
@@ -364,10 +742,11 @@ where
     for e in entries {
         let Entry {
             tag,
+            name,
             alias,
             vr,
+            vm,
             obs,
-            ..
         } = e;
 
         // sanitize components
@@ -378,11 +757,11 @@ where
             continue;
         };
 
-        if let Some(ref s) = obs {
-            if s == "RET" && !include_retired {
-                // don't include retired attributes
-                continue;
-            }
+        // retired attributes are marked as "RET", sometimes followed by the year
+        let retired = obs.as_ref().is_some_and(|s| s.starts_with("RET"));
+        if retired && !include_retired {
+            // don't include retired attributes
+            continue;
         }
         let cap = regex_tag.captures(tag.as_str());
         let tag_txt = if let Some(cap) = cap {
@@ -407,34 +786,88 @@ where
             continue;
         };
 
-        let mut vr = vr.unwrap_or_else(|| "".into());
-        if vr == "See Note" {
-            vr = "UN See Note".to_string();
-        }
+        let vr = vr_code(vr.as_deref().unwrap_or(""), &tag_txt);
 
-        let (vr1, vr2) = vr.split_at(2);
+        let name = match name {
+            Some(name) if !name.is_empty() => format!("Some({:?})", name),
+            _ => "None".to_string(),
+        };
 
-        let second_vr = if vr2 != "" {
-            format!(" /*{} */", vr2)
-        } else {
-            vr2.to_string()
+        let vm = match vm.as_ref().and_then(|vm| vm_code(vm)) {
+            Some(vm) => format!("Some({})", vm),
+            None => "None".to_string(),
         };
 
-        let mut obs = obs.unwrap_or_else(String::new);
-        if obs != "" {
-            obs = format!(" // {}", obs.as_str());
-        }
+        // keep other remarks (e.g. DICONDE) as a comment
+        let obs = match obs {
+            Some(ref obs) if !retired && !obs.is_empty() => format!(" // {}", obs),
+            _ => String::new(),
+        };
 
         writeln!(
             f,
-            "    E {{ tag: {}, alias: \"{}\", vr: {}{} }},{}",
-            tag_txt, alias, vr1, second_vr, obs
+            "    E {{ tag: {}, alias: \"{}\", name: {}, vr: {}, vm: {}, retired: {} }},{}",
+            tag_txt, alias, name, vr, vm, retired, obs
         )?;
     }
     f.write_all(b"];\n")?;
     Ok(())
 }
 
+/// Convert a value representation as written in the standard
+/// (e.g. `US or SS`) into a `VirtualVr` expression. `tag_txt` is the
+/// tag range expression of the attribute, which tells pixel data and
+/// overlay data apart from other `OB or OW` attributes.
+fn vr_code(vr: &str, tag_txt: &str) -> String {
+    match vr {
+        "US or SS" => "Xs".to_string(),
+        "OB or OW" => match tag_txt {
+            "Single(Tag(0x7FE0, 0x0010))"
+            | "Group100(Tag(0x6000, 0x3000))"
+            | "Group100(Tag(0x7F00, 0x0010))" => "Px".to_string(),
+            _ => "Ox".to_string(),
+        },
+        "US or OW" | "US or SS or OW" => "Lt".to_string(),
+        "See Note" => "Exact(UN) /* See Note */".to_string(),
+        "" => "Exact(UN)".to_string(),
+        vr => {
+            let (vr1, vr2) = vr.split_at(2);
+            if vr2.is_empty() {
+                format!("Exact({})", vr1)
+            } else {
+                format!("Exact({}) /*{} */", vr1, vr2)
+            }
+        }
+    }
+}
+
+/// Convert a value multiplicity as written in the standard (e.g. `1-n`)
+/// into a `ValueMultiplicity` expression. When alternatives are given
+/// (e.g. `1-n or 1`), the first one is used.
+fn vm_code(vm: &str) -> Option<String> {
+    let vm = vm.split(" or ").next()?.trim();
+    let (min, max) = match vm.find('-') {
+        Some(i) => (&vm[..i], Some(&vm[i + 1..])),
+        None => (vm, None),
+    };
+    let min: u32 = min.parse().ok()?;
+    let (max, step) = match max {
+        None => (format!("Some({})", min), 1),
+        Some(max) if max.ends_with('n') => {
+            let step = match &max[..max.len() - 1] {
+                "" => 1,
+                step => step.parse().ok()?,
+            };
+            ("None".to_string(), step)
+        }
+        Some(max) => (format!("Some({})", max.parse::<u32>().ok()?), 1),
+    };
+    Some(format!(
+        "VM {{ min: {}, max: {}, step: {} }}",
+        min, max, step
+    ))
+}
+
 fn to_json_file<P: AsRef<Path>, I>(dest_path: P, entries: I) -> DynResult<()>
 where
     I: IntoIterator<Item = Entry>,
@@ -450,3 +883,314 @@ where
     to_writer(f, &entries)?;
     Ok(())
 }
+
+/// Convert a UID keyword in upper camel case (e.g. `CTImageStorage`)
+/// into the name of a constant (e.g. `CT_IMAGE_STORAGE`).
+fn to_constant_name(keyword: &str) -> String {
+    let chars: Vec<char> = keyword.chars().collect();
+    let mut out = String::with_capacity(keyword.len() + 8);
+    for (i, c) in chars.iter().enumerate() {
+        if i > 0 && c.is_ascii_uppercase() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || ((prev.is_ascii_uppercase() || prev.is_ascii_digit()) && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
+}
+
+/// Map the UID type of the registry to the variant of `UidType`.
+fn to_uid_type_variant(uid_type: &str) -> &'static str {
+    match uid_type.to_lowercase().as_str() {
+        "sop class" => "SopClass",
+        "meta sop class" => "MetaSopClass",
+        "transfer syntax" => "TransferSyntax",
+        "well-known sop instance" => "WellKnownSopInstance",
+        "well-known frame of reference" | "synchronization frame of reference" => {
+            "WellKnownFrameOfReference"
+        }
+        "coding scheme" => "CodingScheme",
+        "application context name" => "ApplicationContextName",
+        "service class" => "ServiceClass",
+        _ => "Other",
+    }
+}
+
+fn to_uid_code_file<P: AsRef<Path>, I>(
+    dest_path: P,
+    entries: I,
+    include_retired: bool,
+) -> DynResult<()>
+where
+    I: IntoIterator<Item = UidEntry>,
+{
+    if let Some(p_dir) = dest_path.as_ref().parent() {
+        create_dir_all(p_dir)?;
+    }
+    let mut f = File::create(&dest_path)?;
+
+    f.write_all(
+        b"//! Automatically generated. Edit at your own risk.\n\n\
+    use super::{UidEntry, UidType::*};\n\n\
+    type U = UidEntry;\n\n",
+    )?;
+
+    let mut rows = Vec::new();
+    for e in entries {
+        let UidEntry {
+            uid,
+            name,
+            keyword,
+            uid_type,
+        } = e;
+
+        let keyword = if let Some(v) = keyword {
+            v
+        } else {
+            continue;
+        };
+
+        let (name, retired) = match name.trim_end().strip_suffix("(Retired)") {
+            Some(name) => (name.trim_end().to_string(), true),
+            None => (name, false),
+        };
+        if retired && !include_retired {
+            continue;
+        }
+
+        let constant = to_constant_name(&keyword);
+        writeln!(
+            f,
+            "/// {}{}\npub const {}: &str = \"{}\";",
+            name,
+            if retired { " (Retired)" } else { "" },
+            constant,
+            uid
+        )?;
+        rows.push(format!(
+            "    U {{ uid: {}, name: {:?}, alias: \"{}\", uid_type: {}, retired: {} }},",
+            constant,
+            name,
+            keyword,
+            to_uid_type_variant(&uid_type),
+            retired
+        ));
+    }
+
+    f.write_all(b"\n#[rustfmt::skip]\npub(crate) const UIDS: &[U] = &[\n")?;
+    for row in rows {
+        writeln!(f, "{}", row)?;
+    }
+    f.write_all(b"];\n")?;
+    Ok(())
+}
+
+fn to_tags_code_file<P: AsRef<Path>, I>(
+    dest_path: P,
+    entries: I,
+    include_retired: bool,
+) -> DynResult<()>
+where
+    I: IntoIterator<Item = Entry>,
+{
+    if let Some(p_dir) = dest_path.as_ref().parent() {
+        create_dir_all(p_dir)?;
+    }
+    let mut f = File::create(&dest_path)?;
+
+    f.write_all(
+        b"//! Automatically generated. Edit at your own risk.\n\n\
+    use dicom_core::Tag;\n",
+    )?;
+
+    let regex_tag = Regex::new(r"^\(([0-9A-F]{4}),([0-9A-F]{4})\)$")?;
+    let regex_tag_group100 = Regex::new(r"^\(([0-9A-F]{2})xx,([0-9A-F]{4})\)$")?;
+    let regex_tag_element100 = Regex::new(r"^\(([0-9A-F]{4}),([0-9A-F]{2})xx\)$")?;
+
+    for e in entries {
+        let alias = match e.alias {
+            Some(ref alias) if !alias.is_empty() => alias,
+            _ => continue,
+        };
+        let retired = e.obs.as_ref().is_some_and(|s| s.starts_with("RET"));
+        if retired && !include_retired {
+            continue;
+        }
+
+        // repeating groups and elements are given
+        // with the open portion of the tag zeroed
+        let tag_txt = if let Some(cap) = regex_tag.captures(&e.tag) {
+            format!("Tag(0x{}, 0x{})", &cap[1], &cap[2])
+        } else if let Some(cap) = regex_tag_group100.captures(&e.tag) {
+            format!("Tag(0x{}00, 0x{})", &cap[1], &cap[2])
+        } else if let Some(cap) = regex_tag_element100.captures(&e.tag) {
+            format!("Tag(0x{}, 0x{}00)", &cap[1], &cap[2])
+        } else {
+            continue;
+        };
+
+        let mut doc = format!("{} {}", alias, e.tag);
+        for part in [&e.vr, &e.vm].iter().filter_map(|p| p.as_ref()) {
+            doc.push(' ');
+            doc.push_str(part);
+        }
+        if retired {
+            doc.push_str(" (Retired)");
+        }
+
+        writeln!(
+            f,
+            "\n/// {}\npub const {}: Tag = {};",
+            doc,
+            to_constant_name(alias),
+            tag_txt
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vr_expressions() {
+        let tag = "Single(Tag(0x0028, 0x0106))";
+        assert_eq!(vr_code("US", tag), "Exact(US)");
+        assert_eq!(vr_code("US or SS", tag), "Xs");
+        assert_eq!(vr_code("OB or OW", "Single(Tag(0x0028, 0x1201))"), "Ox");
+        assert_eq!(vr_code("OB or OW", "Single(Tag(0x7FE0, 0x0010))"), "Px");
+        assert_eq!(vr_code("OB or OW", "Group100(Tag(0x6000, 0x3000))"), "Px");
+        assert_eq!(vr_code("US or SS or OW", tag), "Lt");
+        assert_eq!(vr_code("See Note", tag), "Exact(UN) /* See Note */");
+    }
+
+    #[test]
+    fn vm_expressions() {
+        assert_eq!(
+            vm_code("1").as_deref(),
+            Some("VM { min: 1, max: Some(1), step: 1 }")
+        );
+        assert_eq!(
+            vm_code("1-3").as_deref(),
+            Some("VM { min: 1, max: Some(3), step: 1 }")
+        );
+        assert_eq!(
+            vm_code("2-2n").as_deref(),
+            Some("VM { min: 2, max: None, step: 2 }")
+        );
+        assert_eq!(
+            vm_code("1-n or 1").as_deref(),
+            Some("VM { min: 1, max: None, step: 1 }")
+        );
+        assert_eq!(vm_code(""), None);
+    }
+
+    #[test]
+    fn uid_constant_names() {
+        assert_eq!(to_constant_name("Verification"), "VERIFICATION");
+        assert_eq!(to_constant_name("CTImageStorage"), "CT_IMAGE_STORAGE");
+        assert_eq!(
+            to_constant_name("ExplicitVRLittleEndian"),
+            "EXPLICIT_VR_LITTLE_ENDIAN"
+        );
+        assert_eq!(to_constant_name("JPEG2000Lossless"), "JPEG2000_LOSSLESS");
+        assert_eq!(
+            to_constant_name("XRay3DAngiographicImageStorage"),
+            "X_RAY3D_ANGIOGRAPHIC_IMAGE_STORAGE"
+        );
+    }
+
+    #[test]
+    fn read_command_tables() {
+        let xml = r#"<book>
+<table xml:id="table_E.1-1">
+<thead><tr><td><para>Message Field</para></td><td><para>Tag</para></td></tr></thead>
+<tbody>
+<tr><td><para>Command Field</para></td><td><para>(0000,0100)</para></td>
+<td><para>CommandField</para></td><td><para>US</para></td><td><para>1</para></td>
+<td><para>This field distinguishes the DIMSE operation conveyed by this Message.</para></td></tr>
+</tbody></table>
+<table xml:id="table_E.2-1"><tbody>
+<tr><td><para>Initiator</para></td><td><para>(0000,0200)</para></td>
+<td><para>Initiator</para></td><td><para>AE</para></td><td><para>1</para></td>
+<td><para>Retired.</para></td></tr>
+</tbody></table>
+</book>"#;
+        let entries: Vec<_> = XmlCommandIterator::new(xml.as_bytes())
+            .collect::<XmlResult<_>>()
+            .unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                Entry {
+                    tag: "(0000,0100)".to_string(),
+                    name: Some("Command Field".to_string()),
+                    alias: Some("CommandField".to_string()),
+                    vr: Some("US".to_string()),
+                    vm: Some("1".to_string()),
+                    obs: None,
+                },
+                Entry {
+                    tag: "(0000,0200)".to_string(),
+                    name: Some("Initiator".to_string()),
+                    alias: Some("Initiator".to_string()),
+                    vr: Some("AE".to_string()),
+                    vm: Some("1".to_string()),
+                    obs: Some("RET".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn read_uid_tables() {
+        let xml = r#"<book>
+<table xml:id="table_6-1"><tbody><tr><td><para>(0008,0016)</para></td></tr></tbody></table>
+<table xml:id="table_A-1">
+<thead><tr><td><para>UID Value</para></td><td><para>UID Name</para></td></tr></thead>
+<tbody>
+<tr><td><para>1.2.840.10008.1.1</para></td><td><para>Verification SOP Class</para></td>
+<td><para>Verification</para></td><td><para>SOP Class</para></td><td><para>PS3.4</para></td></tr>
+<tr><td><para>1.2.840.10008.1.2.4.52</para></td>
+<td><para>JPEG Extended (Process 3 &amp; 5) (Retired)</para></td>
+<td><para>JPEGExtended35</para></td><td><para>Transfer Syntax</para></td><td><para>PS3.5</para></td></tr>
+</tbody></table>
+<table xml:id="table_A-2"><tbody>
+<tr><td><para>1.2.840.10008.1.4.1.1</para></td><td><para>Talairach Brain Atlas Frame of Reference</para></td>
+<td><para>TalairachBrainAtlas</para></td><td><para>PS3.16</para></td></tr>
+</tbody></table>
+</book>"#;
+        let entries: Vec<_> = XmlUidIterator::new(xml.as_bytes())
+            .collect::<XmlResult<_>>()
+            .unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                UidEntry {
+                    uid: "1.2.840.10008.1.1".to_string(),
+                    name: "Verification SOP Class".to_string(),
+                    keyword: Some("Verification".to_string()),
+                    uid_type: "SOP Class".to_string(),
+                },
+                UidEntry {
+                    uid: "1.2.840.10008.1.2.4.52".to_string(),
+                    name: "JPEG Extended (Process 3 & 5) (Retired)".to_string(),
+                    keyword: Some("JPEGExtended35".to_string()),
+                    uid_type: "Transfer Syntax".to_string(),
+                },
+                UidEntry {
+                    uid: "1.2.840.10008.1.4.1.1".to_string(),
+                    name: "Talairach Brain Atlas Frame of Reference".to_string(),
+                    keyword: Some("TalairachBrainAtlas".to_string()),
+                    uid_type: "Well-known frame of reference".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Automatically generated. Edit at your own risk.
+
+use dicom_core::dictionary::{
+    DictionaryEntryRef, TagRange::*, ValueMultiplicity as VM, VirtualVr::*,
+};
+use dicom_core::Tag;
+use dicom_core::VR::*;
+
+type E = DictionaryEntryRef<'static>;
+
+#[rustfmt::skip]
+pub const ENTRIES: &[E] = &[
+    E { tag: Single(Tag(0x0000, 0x0000)), alias: "CommandGroupLength", name: Some("Command Group Length"), vr: Exact(UL), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0002)), alias: "AffectedSOPClassUID", name: Some("Affected SOP Class UID"), vr: Exact(UI), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0003)), alias: "RequestedSOPClassUID", name: Some("Requested SOP Class UID"), vr: Exact(UI), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0100)), alias: "CommandField", name: Some("Command Field"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0110)), alias: "MessageID", name: Some("Message ID"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0120)), alias: "MessageIDBeingRespondedTo", name: Some("Message ID Being Responded To"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0600)), alias: "MoveDestination", name: Some("Move Destination"), vr: Exact(AE), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0700)), alias: "Priority", name: Some("Priority"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0800)), alias: "CommandDataSetType", name: Some("Command Data Set Type"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0900)), alias: "Status", name: Some("Status"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0901)), alias: "OffendingElement", name: Some("Offending Element"), vr: Exact(AT), vm: Some(VM { min: 1, max: None, step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0902)), alias: "ErrorComment", name: Some("Error Comment"), vr: Exact(LO), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0903)), alias: "ErrorID", name: Some("Error ID"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1000)), alias: "AffectedSOPInstanceUID", name: Some("Affected SOP Instance UID"), vr: Exact(UI), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1001)), alias: "RequestedSOPInstanceUID", name: Some("Requested SOP Instance UID"), vr: Exact(UI), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1002)), alias: "EventTypeID", name: Some("Event Type ID"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1005)), alias: "AttributeIdentifierList", name: Some("Attribute Identifier List"), vr: Exact(AT), vm: Some(VM { min: 1, max: None, step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1008)), alias: "ActionTypeID", name: Some("Action Type ID"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1020)), alias: "NumberOfRemainingSuboperations", name: Some("Number of Remaining Sub-operations"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1021)), alias: "NumberOfCompletedSuboperations", name: Some("Number of Completed Sub-operations"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1022)), alias: "NumberOfFailedSuboperations", name: Some("Number of Failed Sub-operations"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1023)), alias: "NumberOfWarningSuboperations", name: Some("Number of Warning Sub-operations"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1030)), alias: "MoveOriginatorApplicationEntityTitle", name: Some("Move Originator Application Entity Title"), vr: Exact(AE), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x1031)), alias: "MoveOriginatorMessageID", name: Some("Move Originator Message ID"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: false },
+    E { tag: Single(Tag(0x0000, 0x0001)), alias: "CommandLengthToEnd", name: Some("Command Length to End"), vr: Exact(UL), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x0010)), alias: "CommandRecognitionCode", name: Some("Command Recognition Code"), vr: Exact(SH), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x0200)), alias: "Initiator", name: Some("Initiator"), vr: Exact(AE), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x0300)), alias: "Receiver", name: Some("Receiver"), vr: Exact(AE), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x0400)), alias: "FindLocation", name: Some("Find Location"), vr: Exact(AE), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x0850)), alias: "NumberOfMatches", name: Some("Number of Matches"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x0860)), alias: "ResponseSequenceNumber", name: Some("Response Sequence Number"), vr: Exact(US), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x4000)), alias: "DialogReceiver", name: Some("Dialog Receiver"), vr: Exact(LT), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x4010)), alias: "TerminalType", name: Some("Terminal Type"), vr: Exact(LT), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5010)), alias: "MessageSetID", name: Some("Message Set ID"), vr: Exact(SH), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5020)), alias: "EndMessageID", name: Some("End Message ID"), vr: Exact(SH), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5110)), alias: "DisplayFormat", name: Some("Display Format"), vr: Exact(LT), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5120)), alias: "PagePositionID", name: Some("Page Position ID"), vr: Exact(LT), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5130)), alias: "TextFormatID", name: Some("Text Format ID"), vr: Exact(CS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5140)), alias: "NormalReverse", name: Some("Normal/Reverse"), vr: Exact(CS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5150)), alias: "AddGrayScale", name: Some("Add Gray Scale"), vr: Exact(CS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5160)), alias: "Borders", name: Some("Borders"), vr: Exact(CS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5170)), alias: "Copies", name: Some("Copies"), vr: Exact(IS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5180)), alias: "CommandMagnificationType", name: Some("Command Magnification Type"), vr: Exact(CS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x5190)), alias: "Erase", name: Some("Erase"), vr: Exact(CS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x51A0)), alias: "Print", name: Some("Print"), vr: Exact(CS), vm: Some(VM { min: 1, max: Some(1), step: 1 }), retired: true },
+    E { tag: Single(Tag(0x0000, 0x51B0)), alias: "Overlays", name: Some("Overlays"), vr: Exact(US), vm: Some(VM { min: 1, max: None, step: 1 }), retired: true },
+];
@@ -4,12 +4,31 @@
 //! DICOM attributes specified in the standard according to DICOM PS3.6 2019c,
 //! and it will be used by default in most other abstractions available.
 //!
-//! When not using private tags, this dictionary should suffice.
+//! A selection of well-known private attributes from the major vendors is
+//! also available through [`DataDictionary::by_private_tag`], keyed by
+//! private creator, group and the low byte of the element number.
+//!
+//! The command elements of group `0000` used in DIMSE messages,
+//! as defined in PS3.7, are part of the dictionary as well.
+//!
+//! A subset of the registry of unique identifiers, such as SOP classes
+//! and transfer syntaxes, is available in the [`uids`] module.
+//!
+//! Constants for the tag of each attribute, such as
+//! [`tags::PATIENT_NAME`], are available in the [`tags`] module.
 
+mod commands;
 mod entries;
+mod private;
+pub mod tags;
+pub mod uids;
 
+use crate::commands::ENTRIES as COMMAND_ENTRIES;
 use crate::entries::ENTRIES;
-use dicom_core::dictionary::{DataDictionary, DictionaryEntryRef, TagRange::*};
+use crate::private::PRIVATE_ENTRIES;
+use dicom_core::dictionary::{
+    DataDictionary, DictionaryEntryRef, TagRange::*, ValueMultiplicity, VirtualVr,
+};
 use dicom_core::header::{Tag, VR};
 use lazy_static::lazy_static;
 use std::collections::{HashMap, HashSet};
@@ -36,6 +55,8 @@ pub struct StandardDictionaryRegistry {
     repeating_ggxx: HashSet<Tag>,
     /// repeating elements of the form (gggg, eexx). The `xx` portion is zeroed.
     repeating_eexx: HashSet<Tag>,
+    /// mapping: private creator → (group, element low byte) → entry
+    by_private: HashMap<&'static str, HashMap<(u16, u8), &'static DictionaryEntryRef<'static>>>,
 }
 
 impl StandardDictionaryRegistry {
@@ -45,6 +66,7 @@ impl StandardDictionaryRegistry {
             by_tag: HashMap::with_capacity(5000),
             repeating_ggxx: HashSet::with_capacity(75),
             repeating_eexx: HashSet::new(),
+            by_private: HashMap::new(),
         }
     }
 
@@ -63,6 +85,20 @@ impl StandardDictionaryRegistry {
         }
         self
     }
+
+    /// record the given private dictionary entry reference
+    fn index_private(
+        &mut self,
+        creator: &'static str,
+        entry: &'static DictionaryEntryRef<'static>,
+    ) -> &mut Self {
+        let tag = entry.tag.inner();
+        self.by_private
+            .entry(creator)
+            .or_default()
+            .insert((tag.group(), tag.element() as u8), entry);
+        self
+    }
 }
 
 /// A data dictionary which consults the library's global DICOM attribute registry.
@@ -91,6 +127,18 @@ impl StandardDataDictionary {
             })
             .cloned()
     }
+
+    fn indexed_private_tag(
+        creator: &str,
+        group: u16,
+        element: u8,
+    ) -> Option<&'static DictionaryEntryRef<'static>> {
+        registry()
+            .by_private
+            .get(creator)
+            .and_then(|entries| entries.get(&(group, element)))
+            .cloned()
+    }
 }
 
 impl DataDictionary for StandardDataDictionary {
@@ -103,6 +151,10 @@ impl DataDictionary for StandardDataDictionary {
     fn by_tag(&self, tag: Tag) -> Option<&Self::Entry> {
         StandardDataDictionary::indexed_tag(tag)
     }
+
+    fn by_private_tag(&self, creator: &str, group: u16, element: u8) -> Option<&Self::Entry> {
+        StandardDataDictionary::indexed_private_tag(creator, group, element)
+    }
 }
 
 impl<'a> DataDictionary for &'a StandardDataDictionary {
@@ -115,6 +167,15 @@ impl<'a> DataDictionary for &'a StandardDataDictionary {
     fn by_tag(&self, tag: Tag) -> Option<&'static DictionaryEntryRef<'static>> {
         StandardDataDictionary::indexed_tag(tag)
     }
+
+    fn by_private_tag(
+        &self,
+        creator: &str,
+        group: u16,
+        element: u8,
+    ) -> Option<&'static DictionaryEntryRef<'static>> {
+        StandardDataDictionary::indexed_private_tag(creator, group, element)
+    }
 }
 
 impl Display for StandardDataDictionary {
@@ -131,6 +192,12 @@ fn init_dictionary() -> StandardDictionaryRegistry {
     for entry in META_ENTRIES {
         d.index(&entry);
     }
+    for entry in COMMAND_ENTRIES {
+        d.index(entry);
+    }
+    for (creator, entry) in PRIVATE_ENTRIES {
+        d.index_private(creator, entry);
+    }
     d
 }
 
@@ -140,69 +207,108 @@ const META_ENTRIES: &[E<'static>] = &[
     E {
         tag: Single(Tag(0x0002, 0x0000)),
         alias: "FileMetaInformationGroupLength",
-        vr: VR::UL,
+        name: Some("File Meta Information Group Length"),
+        vr: VirtualVr::Exact(VR::UL),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0001)),
         alias: "FileMetaInformationVersion",
-        vr: VR::OB,
+        name: Some("File Meta Information Version"),
+        vr: VirtualVr::Exact(VR::OB),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0002)),
         alias: "MediaStorageSOPClassUID",
-        vr: VR::UI,
+        name: Some("Media Storage SOP Class UID"),
+        vr: VirtualVr::Exact(VR::UI),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0003)),
         alias: "MediaStorageSOPInstanceUID",
-        vr: VR::UI,
+        name: Some("Media Storage SOP Instance UID"),
+        vr: VirtualVr::Exact(VR::UI),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0010)),
         alias: "TransferSyntaxUID",
-        vr: VR::UI,
+        name: Some("Transfer Syntax UID"),
+        vr: VirtualVr::Exact(VR::UI),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0012)),
         alias: "ImplementationClassUID",
-        vr: VR::UI,
+        name: Some("Implementation Class UID"),
+        vr: VirtualVr::Exact(VR::UI),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0013)),
         alias: "ImplentationVersionName",
-        vr: VR::SH,
+        name: Some("Implementation Version Name"),
+        vr: VirtualVr::Exact(VR::SH),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0016)),
         alias: "SourceApplicationEntityTitle",
-        vr: VR::AE,
+        name: Some("Source Application Entity Title"),
+        vr: VirtualVr::Exact(VR::AE),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0017)),
         alias: "SendingApplicationEntityTitle",
-        vr: VR::AE,
+        name: Some("Sending Application Entity Title"),
+        vr: VirtualVr::Exact(VR::AE),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0018)),
         alias: "ReceivingApplicationEntityTitle",
-        vr: VR::AE,
+        name: Some("Receiving Application Entity Title"),
+        vr: VirtualVr::Exact(VR::AE),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0100)),
         alias: "PrivateInformationCreatorUID",
-        vr: VR::UI,
+        name: Some("Private Information Creator UID"),
+        vr: VirtualVr::Exact(VR::UI),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0102)),
         alias: "PrivateInformation",
-        vr: VR::OB,
+        name: Some("Private Information"),
+        vr: VirtualVr::Exact(VR::OB),
+        vm: Some(ValueMultiplicity::ONE),
+        retired: false,
     },
 ];
 
 #[cfg(test)]
 mod tests {
     use super::StandardDataDictionary;
-    use dicom_core::dictionary::{DataDictionary, DictionaryEntryRef, TagRange::*};
+    use dicom_core::dictionary::{
+        DataDictionary, DictionaryEntry, DictionaryEntryRef, TagRange::*, ValueMultiplicity,
+        VirtualVr,
+    };
     use dicom_core::header::{Tag, VR};
 
     // tests for just a few attributes to make sure that the entries
@@ -216,7 +322,10 @@ mod tests {
             Some(&DictionaryEntryRef {
                 tag: Single(Tag(0x0010, 0x0010)),
                 alias: "PatientName",
-                vr: VR::PN,
+                name: Some("Patient's Name"),
+                vr: VirtualVr::Exact(VR::PN),
+                vm: Some(ValueMultiplicity::ONE),
+                retired: false,
             })
         );
 
@@ -225,7 +334,10 @@ mod tests {
             Some(&DictionaryEntryRef {
                 tag: Single(Tag(0x0008, 0x0060)),
                 alias: "Modality",
-                vr: VR::CS,
+                name: Some("Modality"),
+                vr: VirtualVr::Exact(VR::CS),
+                vm: Some(ValueMultiplicity::ONE),
+                retired: false,
             })
         );
 
@@ -235,14 +347,14 @@ mod tests {
         eprintln!("{:X?}", pixel_data.tag);
         assert_eq!(pixel_data.tag, Single(Tag(0x7FE0, 0x0010)));
         assert_eq!(pixel_data.alias, "PixelData");
-        assert!(pixel_data.vr == VR::OB || pixel_data.vr == VR::OW);
+        assert!(pixel_data.vr() == VR::OB || pixel_data.vr() == VR::OW);
 
         let overlay_data = dict
             .by_tag(Tag(0x6000, 0x3000))
             .expect("Overlay Data attribute should exist");
         assert_eq!(overlay_data.tag, Group100(Tag(0x6000, 0x3000)));
         assert_eq!(overlay_data.alias, "OverlayData");
-        assert!(overlay_data.vr == VR::OB || overlay_data.vr == VR::OW);
+        assert!(overlay_data.vr() == VR::OB || overlay_data.vr() == VR::OW);
 
         // repeated overlay data
         let overlay_data = dict
@@ -250,6 +362,96 @@ mod tests {
             .expect("Repeated Overlay Data attribute should exist");
         assert_eq!(overlay_data.tag, Group100(Tag(0x6000, 0x3000)));
         assert_eq!(overlay_data.alias, "OverlayData");
-        assert!(overlay_data.vr == VR::OB || overlay_data.vr == VR::OW);
+        assert!(overlay_data.vr() == VR::OB || overlay_data.vr() == VR::OW);
+    }
+
+    #[test]
+    fn entry_metadata() {
+        let dict = StandardDataDictionary;
+
+        let entry = dict
+            .by_name("TransferSyntaxUID")
+            .expect("Transfer Syntax UID should exist");
+        assert_eq!(entry.name(), Some("Transfer Syntax UID"));
+        assert_eq!(entry.vm(), Some(ValueMultiplicity::ONE));
+        assert!(!entry.is_retired());
+
+        let entry = StandardDataDictionary
+            .by_tag(crate::tags::PATIENT_NAME)
+            .expect("Patient's Name should exist");
+        assert_eq!(entry.name(), Some("Patient's Name"));
+        assert_eq!(entry.vm(), Some(ValueMultiplicity::ONE));
+
+        let entry = dict
+            .by_name("ImagePositionPatient")
+            .expect("Image Position (Patient) should exist");
+        assert_eq!(entry.name(), Some("Image Position (Patient)"));
+        assert_eq!(entry.vm().map(|vm| vm.to_string()).as_deref(), Some("3"));
+        assert_eq!(
+            dict.by_name("ImageType").and_then(|e| e.vm()),
+            Some(ValueMultiplicity {
+                min: 2,
+                max: None,
+                step: 1
+            })
+        );
+
+        let entry = dict
+            .by_tag(Tag(0x0028, 0x0104))
+            .expect("Smallest Valid Pixel Value should exist");
+        assert_eq!(entry.alias, "SmallestValidPixelValue");
+        assert!(entry.is_retired());
+    }
+
+    #[test]
+    fn command_entries() {
+        let dict = StandardDataDictionary;
+
+        let entry = dict
+            .by_name("AffectedSOPClassUID")
+            .expect("Affected SOP Class UID should exist");
+        assert_eq!(entry.tag, Single(Tag(0x0000, 0x0002)));
+        assert_eq!(entry.vr(), VR::UI);
+
+        let entry = dict
+            .by_tag(Tag(0x0000, 0x0100))
+            .expect("Command Field should exist");
+        assert_eq!(entry.alias, "CommandField");
+        assert_eq!(entry.name(), Some("Command Field"));
+        assert_eq!(entry.vr(), VR::US);
+
+        assert_eq!(
+            dict.by_tag(Tag(0x0000, 0x0900)).map(|e| e.alias),
+            Some("Status")
+        );
+        assert!(dict
+            .by_name("CommandRecognitionCode")
+            .expect("retired command elements should exist")
+            .is_retired());
+    }
+
+    #[test]
+    fn private_entries() {
+        let dict = StandardDataDictionary;
+
+        let entry = dict
+            .by_private_tag("SIEMENS CSA HEADER", 0x0029, 0x10)
+            .expect("CSA Image Header Info should exist");
+        assert_eq!(entry.alias, "CSAImageHeaderInfo");
+        assert_eq!(entry.vr(), VR::OB);
+
+        // same group and element, different creator
+        let entry = dict
+            .by_private_tag("SIEMENS MEDCOM HEADER", 0x0029, 0x10)
+            .expect("MedCom Header Info should exist");
+        assert_eq!(entry.alias, "MedComHeaderInfo");
+
+        assert_eq!(
+            dict.by_private_tag("SIEMENS CSA HEADER", 0x0031, 0x10),
+            None
+        );
+        assert_eq!(dict.by_private_tag("ACME 1.1", 0x0029, 0x10), None);
+        // private attributes are not reachable by name
+        assert_eq!(dict.by_name("CSAImageHeaderInfo"), None);
     }
 }
@@ -0,0 +1,108 @@
+//! A selection of well-known private attributes, as described in the
+//! DICOM conformance statements of the respective vendors.
+//!
+//! Each entry is paired with the private creator which reserves it.
+//! The tag of each entry holds the private group and the low byte of the
+//! element number, `(gggg,00ee)`.
+
+use dicom_core::dictionary::{DictionaryEntryRef, TagRange::*, VirtualVr::*};
+use dicom_core::Tag;
+use dicom_core::VR::*;
+
+type E = DictionaryEntryRef<'static>;
+
+#[rustfmt::skip]
+pub const PRIVATE_ENTRIES: &[(&str, E)] = &[
+    // GE Healthcare
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x0001)), alias: "FullFidelity", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x0002)), alias: "SuiteId", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x0004)), alias: "ProductId", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x0027)), alias: "ImageActualDate", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x0030)), alias: "ServiceId", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x0031)), alias: "MobileLocationNumber", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x00E3)), alias: "EquipmentUID", name: None, vr: Exact(UI), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x00E6)), alias: "GenesisVersionNow", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("GEMS_IDEN_01", E { tag: Single(Tag(0x0009, 0x00E7)), alias: "ExamRecordChecksum", name: None, vr: Exact(UL), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0002)), alias: "NumberOfCellsInDetector", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0003)), alias: "CellNumberAtTheta", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0004)), alias: "CellSpacing", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x000F)), alias: "HorizontalFrameOfReference", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0011)), alias: "SeriesContrast", name: None, vr: Exact(SS), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0018)), alias: "FirstScanRas", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x001A)), alias: "LastScanRas", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0023)), alias: "TableSpeed", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0024)), alias: "MidScanTime", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x0027)), alias: "GantryPeriod", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("GEMS_ACQU_01", E { tag: Single(Tag(0x0019, 0x009C)), alias: "PulseSequenceName", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("GEMS_RELA_01", E { tag: Single(Tag(0x0021, 0x0003)), alias: "SeriesFromWhichPrescribed", name: None, vr: Exact(SS), vm: None, retired: false }),
+    ("GEMS_RELA_01", E { tag: Single(Tag(0x0021, 0x0005)), alias: "GenesisVersionNow", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("GEMS_RELA_01", E { tag: Single(Tag(0x0021, 0x0007)), alias: "SeriesRecordChecksum", name: None, vr: Exact(UL), vm: None, retired: false }),
+    ("GEMS_SERS_01", E { tag: Single(Tag(0x0025, 0x0006)), alias: "LastPulseSequenceUsed", name: None, vr: Exact(SS), vm: None, retired: false }),
+    ("GEMS_SERS_01", E { tag: Single(Tag(0x0025, 0x0007)), alias: "ImagesInSeries", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("GEMS_SERS_01", E { tag: Single(Tag(0x0025, 0x0010)), alias: "LandmarkCounter", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("GEMS_SERS_01", E { tag: Single(Tag(0x0025, 0x0011)), alias: "NumberOfAcquisitions", name: None, vr: Exact(SS), vm: None, retired: false }),
+    ("GEMS_SERS_01", E { tag: Single(Tag(0x0025, 0x0017)), alias: "SeriesCompleteFlag", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("GEMS_SERS_01", E { tag: Single(Tag(0x0025, 0x0018)), alias: "NumberOfImagesArchived", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("GEMS_SERS_01", E { tag: Single(Tag(0x0025, 0x0019)), alias: "LastImageNumberUsed", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("GEMS_PARM_01", E { tag: Single(Tag(0x0043, 0x0039)), alias: "SlopInteger6To9", name: None, vr: Exact(IS), vm: None, retired: false }),
+    // Siemens Healthineers
+    ("SIEMENS CSA HEADER", E { tag: Single(Tag(0x0029, 0x0008)), alias: "CSAImageHeaderType", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("SIEMENS CSA HEADER", E { tag: Single(Tag(0x0029, 0x0009)), alias: "CSAImageHeaderVersion", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("SIEMENS CSA HEADER", E { tag: Single(Tag(0x0029, 0x0010)), alias: "CSAImageHeaderInfo", name: None, vr: Exact(OB), vm: None, retired: false }),
+    ("SIEMENS CSA HEADER", E { tag: Single(Tag(0x0029, 0x0018)), alias: "CSASeriesHeaderType", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("SIEMENS CSA HEADER", E { tag: Single(Tag(0x0029, 0x0019)), alias: "CSASeriesHeaderVersion", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("SIEMENS CSA HEADER", E { tag: Single(Tag(0x0029, 0x0020)), alias: "CSASeriesHeaderInfo", name: None, vr: Exact(OB), vm: None, retired: false }),
+    ("SIEMENS MEDCOM HEADER", E { tag: Single(Tag(0x0029, 0x0008)), alias: "MedComHeaderType", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("SIEMENS MEDCOM HEADER", E { tag: Single(Tag(0x0029, 0x0009)), alias: "MedComHeaderVersion", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("SIEMENS MEDCOM HEADER", E { tag: Single(Tag(0x0029, 0x0010)), alias: "MedComHeaderInfo", name: None, vr: Exact(OB), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0008)), alias: "ImagingMode", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0009)), alias: "SequenceInfo", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x000A)), alias: "NumberOfImagesInMosaic", name: None, vr: Exact(US), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x000B)), alias: "SliceMeasurementDuration", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x000C)), alias: "BValue", name: None, vr: Exact(IS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x000D)), alias: "DiffusionDirectionality", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x000E)), alias: "DiffusionGradientDirection", name: None, vr: Exact(FD), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x000F)), alias: "GradientMode", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0011)), alias: "FlowCompensation", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0012)), alias: "TablePositionOrigin", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0013)), alias: "ImaAbsTablePosition", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0014)), alias: "ImaRelTablePosition", name: None, vr: Exact(IS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0015)), alias: "SlicePositionPCS", name: None, vr: Exact(FD), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0016)), alias: "TimeAfterStart", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0017)), alias: "SliceResolution", name: None, vr: Exact(DS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0018)), alias: "RealDwellTime", name: None, vr: Exact(IS), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0027)), alias: "BMatrix", name: None, vr: Exact(FD), vm: None, retired: false }),
+    ("SIEMENS MR HEADER", E { tag: Single(Tag(0x0019, 0x0028)), alias: "BandwidthPerPixelPhaseEncode", name: None, vr: Exact(FD), vm: None, retired: false }),
+    // Philips Healthcare
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0001)), alias: "ChemicalShift", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0002)), alias: "ChemicalShiftNumberMR", name: None, vr: Exact(IS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0003)), alias: "DiffusionBFactor", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0004)), alias: "DiffusionDirection", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0006)), alias: "ImageEnhanced", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0007)), alias: "ImageTypeEDES", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0008)), alias: "PhaseNumber", name: None, vr: Exact(IS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0009)), alias: "ImagePrepulseDelay", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x000A)), alias: "SliceNumberMR", name: None, vr: Exact(IS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x000B)), alias: "SliceOrientation", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0011)), alias: "DiffusionEchoTime", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0012)), alias: "DynamicSeries", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0013)), alias: "EPIFactor", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0014)), alias: "NumberOfEchoes", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0015)), alias: "NumberOfLocations", name: None, vr: Exact(SS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0017)), alias: "NumberOfPhasesMR", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0018)), alias: "NumberOfSlicesMR", name: None, vr: Exact(SL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x001A)), alias: "PCVelocity", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x001B)), alias: "PrepulseDelay", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x001C)), alias: "PrepulseType", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x001D)), alias: "ReconstructionNumberMR", name: None, vr: Exact(IS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x001F)), alias: "RespirationSync", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0020)), alias: "ScanningTechnique", name: None, vr: Exact(LO), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0021)), alias: "SPIR", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0022)), alias: "WaterFatShift", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0024)), alias: "SeriesIsInteractive", name: None, vr: Exact(CS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x0025)), alias: "EchoTimeDisplayMR", name: None, vr: Exact(SH), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x002D)), alias: "NumberOfStackSlices", name: None, vr: Exact(SS), vm: None, retired: false }),
+    ("Philips Imaging DD 001", E { tag: Single(Tag(0x2001, 0x005F)), alias: "StackSequence", name: None, vr: Exact(SQ), vm: None, retired: false }),
+    ("Philips MR Imaging DD 001", E { tag: Single(Tag(0x2005, 0x000D)), alias: "ScaleIntercept", name: None, vr: Exact(FL), vm: None, retired: false }),
+    ("Philips MR Imaging DD 001", E { tag: Single(Tag(0x2005, 0x000E)), alias: "ScaleSlope", name: None, vr: Exact(FL), vm: None, retired: false }),
+];
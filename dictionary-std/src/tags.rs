@@ -0,0 +1,63 @@
+//! This module contains a constant for the tag of each attribute
+//! in the standard dictionary, named after its keyword.
+//!
+//! Unlike looking up elements by name, using these constants
+//! is checked at compile time.
+//!
+//! ```
+//! use dicom_core::Tag;
+//! use dicom_dictionary_std::tags;
+//!
+//! assert_eq!(tags::PATIENT_NAME, Tag(0x0010, 0x0010));
+//! assert_eq!(tags::TRANSFER_SYNTAX_UID, Tag(0x0002, 0x0010));
+//! assert_eq!(tags::COMMAND_FIELD, Tag(0x0000, 0x0100));
+//! ```
+//!
+//! The tags of repeating groups and elements, such as Overlay Data
+//! (60xx,3000), are given with the open portion zeroed.
+
+#[rustfmt::skip]
+mod commands;
+#[rustfmt::skip]
+mod entries;
+
+pub use self::commands::*;
+pub use self::entries::*;
+
+use dicom_core::Tag;
+
+/// FileMetaInformationGroupLength (0002,0000) UL 1
+pub const FILE_META_INFORMATION_GROUP_LENGTH: Tag = Tag(0x0002, 0x0000);
+
+/// FileMetaInformationVersion (0002,0001) OB 1
+pub const FILE_META_INFORMATION_VERSION: Tag = Tag(0x0002, 0x0001);
+
+/// MediaStorageSOPClassUID (0002,0002) UI 1
+pub const MEDIA_STORAGE_SOP_CLASS_UID: Tag = Tag(0x0002, 0x0002);
+
+/// MediaStorageSOPInstanceUID (0002,0003) UI 1
+pub const MEDIA_STORAGE_SOP_INSTANCE_UID: Tag = Tag(0x0002, 0x0003);
+
+/// TransferSyntaxUID (0002,0010) UI 1
+pub const TRANSFER_SYNTAX_UID: Tag = Tag(0x0002, 0x0010);
+
+/// ImplementationClassUID (0002,0012) UI 1
+pub const IMPLEMENTATION_CLASS_UID: Tag = Tag(0x0002, 0x0012);
+
+/// ImplementationVersionName (0002,0013) SH 1
+pub const IMPLEMENTATION_VERSION_NAME: Tag = Tag(0x0002, 0x0013);
+
+/// SourceApplicationEntityTitle (0002,0016) AE 1
+pub const SOURCE_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0002, 0x0016);
+
+/// SendingApplicationEntityTitle (0002,0017) AE 1
+pub const SENDING_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0002, 0x0017);
+
+/// ReceivingApplicationEntityTitle (0002,0018) AE 1
+pub const RECEIVING_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0002, 0x0018);
+
+/// PrivateInformationCreatorUID (0002,0100) UI 1
+pub const PRIVATE_INFORMATION_CREATOR_UID: Tag = Tag(0x0002, 0x0100);
+
+/// PrivateInformation (0002,0102) OB 1
+pub const PRIVATE_INFORMATION: Tag = Tag(0x0002, 0x0102);
@@ -0,0 +1,141 @@
+//! Automatically generated. Edit at your own risk.
+
+use dicom_core::Tag;
+
+/// CommandGroupLength (0000,0000) UL 1
+pub const COMMAND_GROUP_LENGTH: Tag = Tag(0x0000, 0x0000);
+
+/// AffectedSOPClassUID (0000,0002) UI 1
+pub const AFFECTED_SOP_CLASS_UID: Tag = Tag(0x0000, 0x0002);
+
+/// RequestedSOPClassUID (0000,0003) UI 1
+pub const REQUESTED_SOP_CLASS_UID: Tag = Tag(0x0000, 0x0003);
+
+/// CommandField (0000,0100) US 1
+pub const COMMAND_FIELD: Tag = Tag(0x0000, 0x0100);
+
+/// MessageID (0000,0110) US 1
+pub const MESSAGE_ID: Tag = Tag(0x0000, 0x0110);
+
+/// MessageIDBeingRespondedTo (0000,0120) US 1
+pub const MESSAGE_ID_BEING_RESPONDED_TO: Tag = Tag(0x0000, 0x0120);
+
+/// MoveDestination (0000,0600) AE 1
+pub const MOVE_DESTINATION: Tag = Tag(0x0000, 0x0600);
+
+/// Priority (0000,0700) US 1
+pub const PRIORITY: Tag = Tag(0x0000, 0x0700);
+
+/// CommandDataSetType (0000,0800) US 1
+pub const COMMAND_DATA_SET_TYPE: Tag = Tag(0x0000, 0x0800);
+
+/// Status (0000,0900) US 1
+pub const STATUS: Tag = Tag(0x0000, 0x0900);
+
+/// OffendingElement (0000,0901) AT 1-n
+pub const OFFENDING_ELEMENT: Tag = Tag(0x0000, 0x0901);
+
+/// ErrorComment (0000,0902) LO 1
+pub const ERROR_COMMENT: Tag = Tag(0x0000, 0x0902);
+
+/// ErrorID (0000,0903) US 1
+pub const ERROR_ID: Tag = Tag(0x0000, 0x0903);
+
+/// AffectedSOPInstanceUID (0000,1000) UI 1
+pub const AFFECTED_SOP_INSTANCE_UID: Tag = Tag(0x0000, 0x1000);
+
+/// RequestedSOPInstanceUID (0000,1001) UI 1
+pub const REQUESTED_SOP_INSTANCE_UID: Tag = Tag(0x0000, 0x1001);
+
+/// EventTypeID (0000,1002) US 1
+pub const EVENT_TYPE_ID: Tag = Tag(0x0000, 0x1002);
+
+/// AttributeIdentifierList (0000,1005) AT 1-n
+pub const ATTRIBUTE_IDENTIFIER_LIST: Tag = Tag(0x0000, 0x1005);
+
+/// ActionTypeID (0000,1008) US 1
+pub const ACTION_TYPE_ID: Tag = Tag(0x0000, 0x1008);
+
+/// NumberOfRemainingSuboperations (0000,1020) US 1
+pub const NUMBER_OF_REMAINING_SUBOPERATIONS: Tag = Tag(0x0000, 0x1020);
+
+/// NumberOfCompletedSuboperations (0000,1021) US 1
+pub const NUMBER_OF_COMPLETED_SUBOPERATIONS: Tag = Tag(0x0000, 0x1021);
+
+/// NumberOfFailedSuboperations (0000,1022) US 1
+pub const NUMBER_OF_FAILED_SUBOPERATIONS: Tag = Tag(0x0000, 0x1022);
+
+/// NumberOfWarningSuboperations (0000,1023) US 1
+pub const NUMBER_OF_WARNING_SUBOPERATIONS: Tag = Tag(0x0000, 0x1023);
+
+/// MoveOriginatorApplicationEntityTitle (0000,1030) AE 1
+pub const MOVE_ORIGINATOR_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0000, 0x1030);
+
+/// MoveOriginatorMessageID (0000,1031) US 1
+pub const MOVE_ORIGINATOR_MESSAGE_ID: Tag = Tag(0x0000, 0x1031);
+
+/// CommandLengthToEnd (0000,0001) UL 1 (Retired)
+pub const COMMAND_LENGTH_TO_END: Tag = Tag(0x0000, 0x0001);
+
+/// CommandRecognitionCode (0000,0010) SH 1 (Retired)
+pub const COMMAND_RECOGNITION_CODE: Tag = Tag(0x0000, 0x0010);
+
+/// Initiator (0000,0200) AE 1 (Retired)
+pub const INITIATOR: Tag = Tag(0x0000, 0x0200);
+
+/// Receiver (0000,0300) AE 1 (Retired)
+pub const RECEIVER: Tag = Tag(0x0000, 0x0300);
+
+/// FindLocation (0000,0400) AE 1 (Retired)
+pub const FIND_LOCATION: Tag = Tag(0x0000, 0x0400);
+
+/// NumberOfMatches (0000,0850) US 1 (Retired)
+pub const NUMBER_OF_MATCHES: Tag = Tag(0x0000, 0x0850);
+
+/// ResponseSequenceNumber (0000,0860) US 1 (Retired)
+pub const RESPONSE_SEQUENCE_NUMBER: Tag = Tag(0x0000, 0x0860);
+
+/// DialogReceiver (0000,4000) LT 1 (Retired)
+pub const DIALOG_RECEIVER: Tag = Tag(0x0000, 0x4000);
+
+/// TerminalType (0000,4010) LT 1 (Retired)
+pub const TERMINAL_TYPE: Tag = Tag(0x0000, 0x4010);
+
+/// MessageSetID (0000,5010) SH 1 (Retired)
+pub const MESSAGE_SET_ID: Tag = Tag(0x0000, 0x5010);
+
+/// EndMessageID (0000,5020) SH 1 (Retired)
+pub const END_MESSAGE_ID: Tag = Tag(0x0000, 0x5020);
+
+/// DisplayFormat (0000,5110) LT 1 (Retired)
+pub const DISPLAY_FORMAT: Tag = Tag(0x0000, 0x5110);
+
+/// PagePositionID (0000,5120) LT 1 (Retired)
+pub const PAGE_POSITION_ID: Tag = Tag(0x0000, 0x5120);
+
+/// TextFormatID (0000,5130) CS 1 (Retired)
+pub const TEXT_FORMAT_ID: Tag = Tag(0x0000, 0x5130);
+
+/// NormalReverse (0000,5140) CS 1 (Retired)
+pub const NORMAL_REVERSE: Tag = Tag(0x0000, 0x5140);
+
+/// AddGrayScale (0000,5150) CS 1 (Retired)
+pub const ADD_GRAY_SCALE: Tag = Tag(0x0000, 0x5150);
+
+/// Borders (0000,5160) CS 1 (Retired)
+pub const BORDERS: Tag = Tag(0x0000, 0x5160);
+
+/// Copies (0000,5170) IS 1 (Retired)
+pub const COPIES: Tag = Tag(0x0000, 0x5170);
+
+/// CommandMagnificationType (0000,5180) CS 1 (Retired)
+pub const COMMAND_MAGNIFICATION_TYPE: Tag = Tag(0x0000, 0x5180);
+
+/// Erase (0000,5190) CS 1 (Retired)
+pub const ERASE: Tag = Tag(0x0000, 0x5190);
+
+/// Print (0000,51A0) CS 1 (Retired)
+pub const PRINT: Tag = Tag(0x0000, 0x51A0);
+
+/// Overlays (0000,51B0) US 1-n (Retired)
+pub const OVERLAYS: Tag = Tag(0x0000, 0x51B0);
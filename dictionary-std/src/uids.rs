@@ -0,0 +1,110 @@
+//! This module contains a subset of the registry of unique identifiers
+//! defined in the standard (PS3.6, tables A-1 and A-2), covering the
+//! transfer syntaxes and SOP classes in common use.
+//!
+//! Each UID is available as a named constant, such as [`CT_IMAGE_STORAGE`]
+//! or [`EXPLICIT_VR_LITTLE_ENDIAN`], and its name, type and retired status
+//! can be looked up with [`uid_entry`].
+//!
+//! ```
+//! use dicom_dictionary_std::uids::{self, UidType};
+//!
+//! let entry = uids::uid_entry("1.2.840.10008.5.1.4.1.1.2").unwrap();
+//! assert_eq!(entry.name, "CT Image Storage");
+//! assert_eq!(entry.uid, uids::CT_IMAGE_STORAGE);
+//! assert_eq!(entry.uid_type, UidType::SopClass);
+//! ```
+
+mod entries;
+
+pub use self::entries::*;
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref BY_UID: HashMap<&'static str, &'static UidEntry> =
+        entries::UIDS.iter().map(|e| (e.uid, e)).collect();
+}
+
+/// The type of a unique identifier in the registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UidType {
+    /// SOP Class
+    SopClass,
+    /// Meta SOP Class
+    MetaSopClass,
+    /// Transfer Syntax
+    TransferSyntax,
+    /// Well-known SOP Instance
+    WellKnownSopInstance,
+    /// Well-known frame of reference
+    WellKnownFrameOfReference,
+    /// Coding Scheme
+    CodingScheme,
+    /// Application Context Name
+    ApplicationContextName,
+    /// Service Class
+    ServiceClass,
+    /// Any other kind of registered UID
+    Other,
+}
+
+/// An entry of the UID registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UidEntry {
+    /// The UID value
+    pub uid: &'static str,
+    /// The full name of the UID, as in the standard
+    pub name: &'static str,
+    /// The keyword of the UID
+    pub alias: &'static str,
+    /// The type of UID
+    pub uid_type: UidType,
+    /// Whether the UID has been retired from the standard
+    pub retired: bool,
+}
+
+/// Look up a UID in the registry.
+///
+/// Trailing null characters and spaces are ignored, so that UID values
+/// may be given with the padding used in data sets.
+pub fn uid_entry(uid: &str) -> Option<&'static UidEntry> {
+    BY_UID.get(uid.trim_end_matches(['\0', ' '])).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_uids() {
+        let entry = uid_entry(EXPLICIT_VR_LITTLE_ENDIAN).unwrap();
+        assert_eq!(entry.name, "Explicit VR Little Endian");
+        assert_eq!(entry.alias, "ExplicitVRLittleEndian");
+        assert_eq!(entry.uid_type, UidType::TransferSyntax);
+        assert!(!entry.retired);
+
+        let entry = uid_entry("1.2.840.10008.1.2.2\0").unwrap();
+        assert_eq!(entry.name, "Explicit VR Big Endian");
+        assert!(entry.retired);
+
+        assert_eq!(
+            uid_entry(TALAIRACH_BRAIN_ATLAS).map(|e| e.uid_type),
+            Some(UidType::WellKnownFrameOfReference)
+        );
+        assert_eq!(
+            uid_entry("1.2.840.10008.2.16.4").map(|e| e.alias),
+            Some("DCM")
+        );
+        assert_eq!(
+            uid_entry(RT_BRACHY_TREATMENT_RECORD_STORAGE).map(|e| e.name),
+            Some("RT Brachy Treatment Record Storage")
+        );
+        assert_eq!(
+            uid_entry(UTC).map(|e| e.uid_type),
+            Some(UidType::WellKnownFrameOfReference)
+        );
+        assert!(uid_entry("1.2.3.4").is_none());
+    }
+}
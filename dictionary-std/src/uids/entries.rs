@@ -0,0 +1,522 @@
+//! A curated subset of the UID registry of PS3.6 (tables A-1 and A-2),
+//! written by hand in the format of `dicom-dictionary-builder --uids`.
+//! It covers the transfer syntaxes and SOP classes in common use,
+//! as well as a few well-known instances, frames of reference and
+//! coding schemes.
+//! Running the builder against the standard replaces it with the full registry.
+
+use super::{UidEntry, UidType::*};
+
+type U = UidEntry;
+
+/// Verification SOP Class
+pub const VERIFICATION: &str = "1.2.840.10008.1.1";
+/// Implicit VR Little Endian: Default Transfer Syntax for DICOM
+pub const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";
+/// Explicit VR Little Endian
+pub const EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+/// Encapsulated Uncompressed Explicit VR Little Endian
+pub const ENCAPSULATED_UNCOMPRESSED_EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1.98";
+/// Deflated Explicit VR Little Endian
+pub const DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1.99";
+/// Explicit VR Big Endian (Retired)
+pub const EXPLICIT_VR_BIG_ENDIAN: &str = "1.2.840.10008.1.2.2";
+/// JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression
+pub const JPEG_BASELINE8_BIT: &str = "1.2.840.10008.1.2.4.50";
+/// JPEG Extended (Process 2 & 4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)
+pub const JPEG_EXTENDED12_BIT: &str = "1.2.840.10008.1.2.4.51";
+/// JPEG Extended (Process 3 & 5) (Retired)
+pub const JPEG_EXTENDED35: &str = "1.2.840.10008.1.2.4.52";
+/// JPEG Spectral Selection, Non-Hierarchical (Process 6 & 8) (Retired)
+pub const JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL68: &str = "1.2.840.10008.1.2.4.53";
+/// JPEG Spectral Selection, Non-Hierarchical (Process 7 & 9) (Retired)
+pub const JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL79: &str = "1.2.840.10008.1.2.4.54";
+/// JPEG Full Progression, Non-Hierarchical (Process 10 & 12) (Retired)
+pub const JPEG_FULL_PROGRESSION_NON_HIERARCHICAL1012: &str = "1.2.840.10008.1.2.4.55";
+/// JPEG Full Progression, Non-Hierarchical (Process 11 & 13) (Retired)
+pub const JPEG_FULL_PROGRESSION_NON_HIERARCHICAL1113: &str = "1.2.840.10008.1.2.4.56";
+/// JPEG Lossless, Non-Hierarchical (Process 14)
+pub const JPEG_LOSSLESS: &str = "1.2.840.10008.1.2.4.57";
+/// JPEG Lossless, Non-Hierarchical (Process 15) (Retired)
+pub const JPEG_LOSSLESS_NON_HIERARCHICAL15: &str = "1.2.840.10008.1.2.4.58";
+/// JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1]): Default Transfer Syntax for Lossless JPEG Image Compression
+pub const JPEG_LOSSLESS_SV1: &str = "1.2.840.10008.1.2.4.70";
+/// JPEG-LS Lossless Image Compression
+pub const JPEGLS_LOSSLESS: &str = "1.2.840.10008.1.2.4.80";
+/// JPEG-LS Lossy (Near-Lossless) Image Compression
+pub const JPEGLS_NEAR_LOSSLESS: &str = "1.2.840.10008.1.2.4.81";
+/// JPEG 2000 Image Compression (Lossless Only)
+pub const JPEG2000_LOSSLESS: &str = "1.2.840.10008.1.2.4.90";
+/// JPEG 2000 Image Compression
+pub const JPEG2000: &str = "1.2.840.10008.1.2.4.91";
+/// JPEG 2000 Part 2 Multi-component Image Compression (Lossless Only)
+pub const JPEG2000MC_LOSSLESS: &str = "1.2.840.10008.1.2.4.92";
+/// JPEG 2000 Part 2 Multi-component Image Compression
+pub const JPEG2000MC: &str = "1.2.840.10008.1.2.4.93";
+/// JPIP Referenced
+pub const JPIP_REFERENCED: &str = "1.2.840.10008.1.2.4.94";
+/// JPIP Referenced Deflate
+pub const JPIP_REFERENCED_DEFLATE: &str = "1.2.840.10008.1.2.4.95";
+/// MPEG2 Main Profile / Main Level
+pub const MPEG2MPML: &str = "1.2.840.10008.1.2.4.100";
+/// MPEG2 Main Profile / High Level
+pub const MPEG2MPHL: &str = "1.2.840.10008.1.2.4.101";
+/// MPEG-4 AVC/H.264 High Profile / Level 4.1
+pub const MPEG4HP41: &str = "1.2.840.10008.1.2.4.102";
+/// MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1
+pub const MPEG4HP41BD: &str = "1.2.840.10008.1.2.4.103";
+/// MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video
+pub const MPEG4HP422D: &str = "1.2.840.10008.1.2.4.104";
+/// MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video
+pub const MPEG4HP423D: &str = "1.2.840.10008.1.2.4.105";
+/// MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2
+pub const MPEG4HP42STEREO: &str = "1.2.840.10008.1.2.4.106";
+/// HEVC/H.265 Main Profile / Level 5.1
+pub const HEVCMP51: &str = "1.2.840.10008.1.2.4.107";
+/// HEVC/H.265 Main 10 Profile / Level 5.1
+pub const HEVCM10P51: &str = "1.2.840.10008.1.2.4.108";
+/// High-Throughput JPEG 2000 Image Compression (Lossless Only)
+pub const HTJ2K_LOSSLESS: &str = "1.2.840.10008.1.2.4.201";
+/// High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)
+pub const HTJ2K_LOSSLESS_RPCL: &str = "1.2.840.10008.1.2.4.202";
+/// High-Throughput JPEG 2000 Image Compression
+pub const HTJ2K: &str = "1.2.840.10008.1.2.4.203";
+/// RLE Lossless
+pub const RLE_LOSSLESS: &str = "1.2.840.10008.1.2.5";
+/// RFC 2557 MIME encapsulation (Retired)
+pub const RFC2557MIME_ENCAPSULATION: &str = "1.2.840.10008.1.2.6.1";
+/// XML Encoding (Retired)
+pub const XML_ENCODING: &str = "1.2.840.10008.1.2.6.2";
+/// Media Storage Directory Storage
+pub const MEDIA_STORAGE_DIRECTORY_STORAGE: &str = "1.2.840.10008.1.3.10";
+/// Basic Study Content Notification SOP Class (Retired)
+pub const BASIC_STUDY_CONTENT_NOTIFICATION: &str = "1.2.840.10008.1.9";
+/// Storage Commitment Push Model SOP Class
+pub const STORAGE_COMMITMENT_PUSH_MODEL: &str = "1.2.840.10008.1.20.1";
+/// Storage Commitment Push Model SOP Instance
+pub const STORAGE_COMMITMENT_PUSH_MODEL_INSTANCE: &str = "1.2.840.10008.1.20.1.1";
+/// Storage Commitment Pull Model SOP Class (Retired)
+pub const STORAGE_COMMITMENT_PULL_MODEL: &str = "1.2.840.10008.1.20.2";
+/// Procedural Event Logging SOP Class
+pub const PROCEDURAL_EVENT_LOGGING: &str = "1.2.840.10008.1.40";
+/// DICOM Controlled Terminology
+pub const DCM: &str = "1.2.840.10008.2.16.4";
+/// Adult Mouse Anatomy Ontology
+pub const MA: &str = "1.2.840.10008.2.16.5";
+/// Uberon Ontology
+pub const UBERON: &str = "1.2.840.10008.2.16.6";
+/// Integrated Taxonomic Information System (ITIS) Taxonomic Serial Number (TSN)
+pub const ITIS_TSN: &str = "1.2.840.10008.2.16.7";
+/// Mouse Genome Initiative (MGI)
+pub const MGI: &str = "1.2.840.10008.2.16.8";
+/// PubChem Compound CID
+pub const PUBCHEM_CID: &str = "1.2.840.10008.2.16.9";
+/// DICOM Application Context Name
+pub const DICOM_APPLICATION_CONTEXT: &str = "1.2.840.10008.3.1.1.1";
+/// Modality Performed Procedure Step SOP Class
+pub const MODALITY_PERFORMED_PROCEDURE_STEP: &str = "1.2.840.10008.3.1.2.3.3";
+/// Modality Performed Procedure Step Retrieve SOP Class
+pub const MODALITY_PERFORMED_PROCEDURE_STEP_RETRIEVE: &str = "1.2.840.10008.3.1.2.3.4";
+/// Modality Performed Procedure Step Notification SOP Class
+pub const MODALITY_PERFORMED_PROCEDURE_STEP_NOTIFICATION: &str = "1.2.840.10008.3.1.2.3.5";
+/// Basic Film Session SOP Class
+pub const BASIC_FILM_SESSION: &str = "1.2.840.10008.5.1.1.1";
+/// Basic Film Box SOP Class
+pub const BASIC_FILM_BOX: &str = "1.2.840.10008.5.1.1.2";
+/// Basic Grayscale Image Box SOP Class
+pub const BASIC_GRAYSCALE_IMAGE_BOX: &str = "1.2.840.10008.5.1.1.4";
+/// Basic Color Image Box SOP Class
+pub const BASIC_COLOR_IMAGE_BOX: &str = "1.2.840.10008.5.1.1.4.1";
+/// Basic Grayscale Print Management Meta SOP Class
+pub const BASIC_GRAYSCALE_PRINT_MANAGEMENT_META: &str = "1.2.840.10008.5.1.1.9";
+/// Print Job SOP Class
+pub const PRINT_JOB: &str = "1.2.840.10008.5.1.1.14";
+/// Printer SOP Class
+pub const PRINTER: &str = "1.2.840.10008.5.1.1.16";
+/// Printer SOP Instance
+pub const PRINTER_INSTANCE: &str = "1.2.840.10008.5.1.1.17";
+/// Basic Color Print Management Meta SOP Class
+pub const BASIC_COLOR_PRINT_MANAGEMENT_META: &str = "1.2.840.10008.5.1.1.18";
+/// Computed Radiography Image Storage
+pub const COMPUTED_RADIOGRAPHY_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.1";
+/// Digital X-Ray Image Storage - For Presentation
+pub const DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION: &str = "1.2.840.10008.5.1.4.1.1.1.1";
+/// Digital X-Ray Image Storage - For Processing
+pub const DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PROCESSING: &str = "1.2.840.10008.5.1.4.1.1.1.1.1";
+/// Digital Mammography X-Ray Image Storage - For Presentation
+pub const DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION: &str =
+    "1.2.840.10008.5.1.4.1.1.1.2";
+/// Digital Mammography X-Ray Image Storage - For Processing
+pub const DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PROCESSING: &str =
+    "1.2.840.10008.5.1.4.1.1.1.2.1";
+/// Digital Intra-Oral X-Ray Image Storage - For Presentation
+pub const DIGITAL_INTRA_ORAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION: &str =
+    "1.2.840.10008.5.1.4.1.1.1.3";
+/// Digital Intra-Oral X-Ray Image Storage - For Processing
+pub const DIGITAL_INTRA_ORAL_X_RAY_IMAGE_STORAGE_FOR_PROCESSING: &str =
+    "1.2.840.10008.5.1.4.1.1.1.3.1";
+/// CT Image Storage
+pub const CT_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.2";
+/// Enhanced CT Image Storage
+pub const ENHANCED_CT_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.2.1";
+/// Legacy Converted Enhanced CT Image Storage
+pub const LEGACY_CONVERTED_ENHANCED_CT_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.2.2";
+/// Ultrasound Multi-frame Image Storage (Retired)
+pub const ULTRASOUND_MULTI_FRAME_IMAGE_STORAGE_RETIRED: &str = "1.2.840.10008.5.1.4.1.1.3";
+/// Ultrasound Multi-frame Image Storage
+pub const ULTRASOUND_MULTI_FRAME_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.3.1";
+/// MR Image Storage
+pub const MR_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.4";
+/// Enhanced MR Image Storage
+pub const ENHANCED_MR_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.4.1";
+/// MR Spectroscopy Storage
+pub const MR_SPECTROSCOPY_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.4.2";
+/// Enhanced MR Color Image Storage
+pub const ENHANCED_MR_COLOR_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.4.3";
+/// Legacy Converted Enhanced MR Image Storage
+pub const LEGACY_CONVERTED_ENHANCED_MR_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.4.4";
+/// Nuclear Medicine Image Storage (Retired)
+pub const NUCLEAR_MEDICINE_IMAGE_STORAGE_RETIRED: &str = "1.2.840.10008.5.1.4.1.1.5";
+/// Ultrasound Image Storage (Retired)
+pub const ULTRASOUND_IMAGE_STORAGE_RETIRED: &str = "1.2.840.10008.5.1.4.1.1.6";
+/// Ultrasound Image Storage
+pub const ULTRASOUND_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.6.1";
+/// Enhanced US Volume Storage
+pub const ENHANCED_US_VOLUME_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.6.2";
+/// Secondary Capture Image Storage
+pub const SECONDARY_CAPTURE_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.7";
+/// Multi-frame Single Bit Secondary Capture Image Storage
+pub const MULTI_FRAME_SINGLE_BIT_SECONDARY_CAPTURE_IMAGE_STORAGE: &str =
+    "1.2.840.10008.5.1.4.1.1.7.1";
+/// Multi-frame Grayscale Byte Secondary Capture Image Storage
+pub const MULTI_FRAME_GRAYSCALE_BYTE_SECONDARY_CAPTURE_IMAGE_STORAGE: &str =
+    "1.2.840.10008.5.1.4.1.1.7.2";
+/// Multi-frame Grayscale Word Secondary Capture Image Storage
+pub const MULTI_FRAME_GRAYSCALE_WORD_SECONDARY_CAPTURE_IMAGE_STORAGE: &str =
+    "1.2.840.10008.5.1.4.1.1.7.3";
+/// Multi-frame True Color Secondary Capture Image Storage
+pub const MULTI_FRAME_TRUE_COLOR_SECONDARY_CAPTURE_IMAGE_STORAGE: &str =
+    "1.2.840.10008.5.1.4.1.1.7.4";
+/// 12-lead ECG Waveform Storage
+pub const TWELVE_LEAD_ECG_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.1.1";
+/// General ECG Waveform Storage
+pub const GENERAL_ECG_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.1.2";
+/// Ambulatory ECG Waveform Storage
+pub const AMBULATORY_ECG_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.1.3";
+/// Hemodynamic Waveform Storage
+pub const HEMODYNAMIC_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.2.1";
+/// Cardiac Electrophysiology Waveform Storage
+pub const CARDIAC_ELECTROPHYSIOLOGY_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.3.1";
+/// Basic Voice Audio Waveform Storage
+pub const BASIC_VOICE_AUDIO_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.4.1";
+/// Arterial Pulse Waveform Storage
+pub const ARTERIAL_PULSE_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.5.1";
+/// Respiratory Waveform Storage
+pub const RESPIRATORY_WAVEFORM_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.9.6.1";
+/// Grayscale Softcopy Presentation State Storage
+pub const GRAYSCALE_SOFTCOPY_PRESENTATION_STATE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.11.1";
+/// Color Softcopy Presentation State Storage
+pub const COLOR_SOFTCOPY_PRESENTATION_STATE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.11.2";
+/// Pseudo-Color Softcopy Presentation State Storage
+pub const PSEUDO_COLOR_SOFTCOPY_PRESENTATION_STATE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.11.3";
+/// Blending Softcopy Presentation State Storage
+pub const BLENDING_SOFTCOPY_PRESENTATION_STATE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.11.4";
+/// X-Ray Angiographic Image Storage
+pub const X_RAY_ANGIOGRAPHIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.12.1";
+/// Enhanced XA Image Storage
+pub const ENHANCED_XA_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.12.1.1";
+/// X-Ray Radiofluoroscopic Image Storage
+pub const X_RAY_RADIOFLUOROSCOPIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.12.2";
+/// Enhanced XRF Image Storage
+pub const ENHANCED_XRF_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.12.2.1";
+/// X-Ray 3D Angiographic Image Storage
+pub const X_RAY3D_ANGIOGRAPHIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.13.1.1";
+/// X-Ray 3D Craniofacial Image Storage
+pub const X_RAY3D_CRANIOFACIAL_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.13.1.2";
+/// Breast Tomosynthesis Image Storage
+pub const BREAST_TOMOSYNTHESIS_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.13.1.3";
+/// Nuclear Medicine Image Storage
+pub const NUCLEAR_MEDICINE_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.20";
+/// Parametric Map Storage
+pub const PARAMETRIC_MAP_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.30";
+/// Raw Data Storage
+pub const RAW_DATA_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.66";
+/// Spatial Registration Storage
+pub const SPATIAL_REGISTRATION_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.66.1";
+/// Spatial Fiducials Storage
+pub const SPATIAL_FIDUCIALS_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.66.2";
+/// Deformable Spatial Registration Storage
+pub const DEFORMABLE_SPATIAL_REGISTRATION_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.66.3";
+/// Segmentation Storage
+pub const SEGMENTATION_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.66.4";
+/// Surface Segmentation Storage
+pub const SURFACE_SEGMENTATION_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.66.5";
+/// Real World Value Mapping Storage
+pub const REAL_WORLD_VALUE_MAPPING_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.67";
+/// VL Endoscopic Image Storage
+pub const VL_ENDOSCOPIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.77.1.1";
+/// Video Endoscopic Image Storage
+pub const VIDEO_ENDOSCOPIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.77.1.1.1";
+/// VL Microscopic Image Storage
+pub const VL_MICROSCOPIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.77.1.2";
+/// VL Slide-Coordinates Microscopic Image Storage
+pub const VL_SLIDE_COORDINATES_MICROSCOPIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.77.1.3";
+/// VL Photographic Image Storage
+pub const VL_PHOTOGRAPHIC_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.77.1.4";
+/// Ophthalmic Photography 8 Bit Image Storage
+pub const OPHTHALMIC_PHOTOGRAPHY8_BIT_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.77.1.5.1";
+/// VL Whole Slide Microscopy Image Storage
+pub const VL_WHOLE_SLIDE_MICROSCOPY_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.77.1.6";
+/// Basic Text SR Storage
+pub const BASIC_TEXT_SR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.11";
+/// Enhanced SR Storage
+pub const ENHANCED_SR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.22";
+/// Comprehensive SR Storage
+pub const COMPREHENSIVE_SR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.33";
+/// Comprehensive 3D SR Storage
+pub const COMPREHENSIVE3DSR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.34";
+/// Extensible SR Storage
+pub const EXTENSIBLE_SR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.35";
+/// Procedure Log Storage
+pub const PROCEDURE_LOG_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.40";
+/// Mammography CAD SR Storage
+pub const MAMMOGRAPHY_CADSR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.50";
+/// Key Object Selection Document Storage
+pub const KEY_OBJECT_SELECTION_DOCUMENT_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.59";
+/// Chest CAD SR Storage
+pub const CHEST_CADSR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.65";
+/// X-Ray Radiation Dose SR Storage
+pub const X_RAY_RADIATION_DOSE_SR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.67";
+/// Radiopharmaceutical Radiation Dose SR Storage
+pub const RADIOPHARMACEUTICAL_RADIATION_DOSE_SR_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.88.68";
+/// Encapsulated PDF Storage
+pub const ENCAPSULATED_PDF_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.104.1";
+/// Encapsulated CDA Storage
+pub const ENCAPSULATED_CDA_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.104.2";
+/// Encapsulated STL Storage
+pub const ENCAPSULATED_STL_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.104.3";
+/// Positron Emission Tomography Image Storage
+pub const POSITRON_EMISSION_TOMOGRAPHY_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.128";
+/// Legacy Converted Enhanced PET Image Storage
+pub const LEGACY_CONVERTED_ENHANCED_PET_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.128.1";
+/// Enhanced PET Image Storage
+pub const ENHANCED_PET_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.130";
+/// RT Image Storage
+pub const RT_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.1";
+/// RT Dose Storage
+pub const RT_DOSE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.2";
+/// RT Structure Set Storage
+pub const RT_STRUCTURE_SET_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.3";
+/// RT Beams Treatment Record Storage
+pub const RT_BEAMS_TREATMENT_RECORD_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.4";
+/// RT Plan Storage
+pub const RT_PLAN_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.5";
+/// RT Brachy Treatment Record Storage
+pub const RT_BRACHY_TREATMENT_RECORD_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.6";
+/// RT Treatment Summary Record Storage
+pub const RT_TREATMENT_SUMMARY_RECORD_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.7";
+/// RT Ion Plan Storage
+pub const RT_ION_PLAN_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.8";
+/// RT Ion Beams Treatment Record Storage
+pub const RT_ION_BEAMS_TREATMENT_RECORD_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.481.9";
+/// Patient Root Query/Retrieve Information Model - FIND
+pub const PATIENT_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_FIND: &str = "1.2.840.10008.5.1.4.1.2.1.1";
+/// Patient Root Query/Retrieve Information Model - MOVE
+pub const PATIENT_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_MOVE: &str = "1.2.840.10008.5.1.4.1.2.1.2";
+/// Patient Root Query/Retrieve Information Model - GET
+pub const PATIENT_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_GET: &str = "1.2.840.10008.5.1.4.1.2.1.3";
+/// Study Root Query/Retrieve Information Model - FIND
+pub const STUDY_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_FIND: &str = "1.2.840.10008.5.1.4.1.2.2.1";
+/// Study Root Query/Retrieve Information Model - MOVE
+pub const STUDY_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_MOVE: &str = "1.2.840.10008.5.1.4.1.2.2.2";
+/// Study Root Query/Retrieve Information Model - GET
+pub const STUDY_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_GET: &str = "1.2.840.10008.5.1.4.1.2.2.3";
+/// Patient/Study Only Query/Retrieve Information Model - FIND (Retired)
+pub const PATIENT_STUDY_ONLY_QUERY_RETRIEVE_INFORMATION_MODEL_FIND: &str =
+    "1.2.840.10008.5.1.4.1.2.3.1";
+/// Modality Worklist Information Model - FIND
+pub const MODALITY_WORKLIST_INFORMATION_MODEL_FIND: &str = "1.2.840.10008.5.1.4.31";
+/// Instance Availability Notification SOP Class
+pub const INSTANCE_AVAILABILITY_NOTIFICATION: &str = "1.2.840.10008.5.1.4.33";
+/// Universal Coordinated Time
+pub const UTC: &str = "1.2.840.10008.15.1.1";
+/// Talairach Brain Atlas Frame of Reference
+pub const TALAIRACH_BRAIN_ATLAS: &str = "1.2.840.10008.1.4.1.1";
+/// SPM2 T1 Frame of Reference
+pub const SPM2T1: &str = "1.2.840.10008.1.4.1.2";
+/// SPM2 T2 Frame of Reference
+pub const SPM2T2: &str = "1.2.840.10008.1.4.1.3";
+/// SPM2 PD Frame of Reference
+pub const SPM2PD: &str = "1.2.840.10008.1.4.1.4";
+/// SPM2 EPI Frame of Reference
+pub const SPM2EPI: &str = "1.2.840.10008.1.4.1.5";
+
+#[rustfmt::skip]
+pub(crate) const UIDS: &[U] = &[
+    U { uid: VERIFICATION, name: "Verification SOP Class", alias: "Verification", uid_type: SopClass, retired: false },
+    U { uid: IMPLICIT_VR_LITTLE_ENDIAN, name: "Implicit VR Little Endian: Default Transfer Syntax for DICOM", alias: "ImplicitVRLittleEndian", uid_type: TransferSyntax, retired: false },
+    U { uid: EXPLICIT_VR_LITTLE_ENDIAN, name: "Explicit VR Little Endian", alias: "ExplicitVRLittleEndian", uid_type: TransferSyntax, retired: false },
+    U { uid: ENCAPSULATED_UNCOMPRESSED_EXPLICIT_VR_LITTLE_ENDIAN, name: "Encapsulated Uncompressed Explicit VR Little Endian", alias: "EncapsulatedUncompressedExplicitVRLittleEndian", uid_type: TransferSyntax, retired: false },
+    U { uid: DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN, name: "Deflated Explicit VR Little Endian", alias: "DeflatedExplicitVRLittleEndian", uid_type: TransferSyntax, retired: false },
+    U { uid: EXPLICIT_VR_BIG_ENDIAN, name: "Explicit VR Big Endian", alias: "ExplicitVRBigEndian", uid_type: TransferSyntax, retired: true },
+    U { uid: JPEG_BASELINE8_BIT, name: "JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression", alias: "JPEGBaseline8Bit", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEG_EXTENDED12_BIT, name: "JPEG Extended (Process 2 & 4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)", alias: "JPEGExtended12Bit", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEG_EXTENDED35, name: "JPEG Extended (Process 3 & 5)", alias: "JPEGExtended35", uid_type: TransferSyntax, retired: true },
+    U { uid: JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL68, name: "JPEG Spectral Selection, Non-Hierarchical (Process 6 & 8)", alias: "JPEGSpectralSelectionNonHierarchical68", uid_type: TransferSyntax, retired: true },
+    U { uid: JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL79, name: "JPEG Spectral Selection, Non-Hierarchical (Process 7 & 9)", alias: "JPEGSpectralSelectionNonHierarchical79", uid_type: TransferSyntax, retired: true },
+    U { uid: JPEG_FULL_PROGRESSION_NON_HIERARCHICAL1012, name: "JPEG Full Progression, Non-Hierarchical (Process 10 & 12)", alias: "JPEGFullProgressionNonHierarchical1012", uid_type: TransferSyntax, retired: true },
+    U { uid: JPEG_FULL_PROGRESSION_NON_HIERARCHICAL1113, name: "JPEG Full Progression, Non-Hierarchical (Process 11 & 13)", alias: "JPEGFullProgressionNonHierarchical1113", uid_type: TransferSyntax, retired: true },
+    U { uid: JPEG_LOSSLESS, name: "JPEG Lossless, Non-Hierarchical (Process 14)", alias: "JPEGLossless", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEG_LOSSLESS_NON_HIERARCHICAL15, name: "JPEG Lossless, Non-Hierarchical (Process 15)", alias: "JPEGLosslessNonHierarchical15", uid_type: TransferSyntax, retired: true },
+    U { uid: JPEG_LOSSLESS_SV1, name: "JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1]): Default Transfer Syntax for Lossless JPEG Image Compression", alias: "JPEGLosslessSV1", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEGLS_LOSSLESS, name: "JPEG-LS Lossless Image Compression", alias: "JPEGLSLossless", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEGLS_NEAR_LOSSLESS, name: "JPEG-LS Lossy (Near-Lossless) Image Compression", alias: "JPEGLSNearLossless", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEG2000_LOSSLESS, name: "JPEG 2000 Image Compression (Lossless Only)", alias: "JPEG2000Lossless", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEG2000, name: "JPEG 2000 Image Compression", alias: "JPEG2000", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEG2000MC_LOSSLESS, name: "JPEG 2000 Part 2 Multi-component Image Compression (Lossless Only)", alias: "JPEG2000MCLossless", uid_type: TransferSyntax, retired: false },
+    U { uid: JPEG2000MC, name: "JPEG 2000 Part 2 Multi-component Image Compression", alias: "JPEG2000MC", uid_type: TransferSyntax, retired: false },
+    U { uid: JPIP_REFERENCED, name: "JPIP Referenced", alias: "JPIPReferenced", uid_type: TransferSyntax, retired: false },
+    U { uid: JPIP_REFERENCED_DEFLATE, name: "JPIP Referenced Deflate", alias: "JPIPReferencedDeflate", uid_type: TransferSyntax, retired: false },
+    U { uid: MPEG2MPML, name: "MPEG2 Main Profile / Main Level", alias: "MPEG2MPML", uid_type: TransferSyntax, retired: false },
+    U { uid: MPEG2MPHL, name: "MPEG2 Main Profile / High Level", alias: "MPEG2MPHL", uid_type: TransferSyntax, retired: false },
+    U { uid: MPEG4HP41, name: "MPEG-4 AVC/H.264 High Profile / Level 4.1", alias: "MPEG4HP41", uid_type: TransferSyntax, retired: false },
+    U { uid: MPEG4HP41BD, name: "MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1", alias: "MPEG4HP41BD", uid_type: TransferSyntax, retired: false },
+    U { uid: MPEG4HP422D, name: "MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video", alias: "MPEG4HP422D", uid_type: TransferSyntax, retired: false },
+    U { uid: MPEG4HP423D, name: "MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video", alias: "MPEG4HP423D", uid_type: TransferSyntax, retired: false },
+    U { uid: MPEG4HP42STEREO, name: "MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2", alias: "MPEG4HP42STEREO", uid_type: TransferSyntax, retired: false },
+    U { uid: HEVCMP51, name: "HEVC/H.265 Main Profile / Level 5.1", alias: "HEVCMP51", uid_type: TransferSyntax, retired: false },
+    U { uid: HEVCM10P51, name: "HEVC/H.265 Main 10 Profile / Level 5.1", alias: "HEVCM10P51", uid_type: TransferSyntax, retired: false },
+    U { uid: HTJ2K_LOSSLESS, name: "High-Throughput JPEG 2000 Image Compression (Lossless Only)", alias: "HTJ2KLossless", uid_type: TransferSyntax, retired: false },
+    U { uid: HTJ2K_LOSSLESS_RPCL, name: "High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)", alias: "HTJ2KLosslessRPCL", uid_type: TransferSyntax, retired: false },
+    U { uid: HTJ2K, name: "High-Throughput JPEG 2000 Image Compression", alias: "HTJ2K", uid_type: TransferSyntax, retired: false },
+    U { uid: RLE_LOSSLESS, name: "RLE Lossless", alias: "RLELossless", uid_type: TransferSyntax, retired: false },
+    U { uid: RFC2557MIME_ENCAPSULATION, name: "RFC 2557 MIME encapsulation", alias: "RFC2557MIMEEncapsulation", uid_type: TransferSyntax, retired: true },
+    U { uid: XML_ENCODING, name: "XML Encoding", alias: "XMLEncoding", uid_type: TransferSyntax, retired: true },
+    U { uid: MEDIA_STORAGE_DIRECTORY_STORAGE, name: "Media Storage Directory Storage", alias: "MediaStorageDirectoryStorage", uid_type: SopClass, retired: false },
+    U { uid: BASIC_STUDY_CONTENT_NOTIFICATION, name: "Basic Study Content Notification SOP Class", alias: "BasicStudyContentNotification", uid_type: SopClass, retired: true },
+    U { uid: STORAGE_COMMITMENT_PUSH_MODEL, name: "Storage Commitment Push Model SOP Class", alias: "StorageCommitmentPushModel", uid_type: SopClass, retired: false },
+    U { uid: STORAGE_COMMITMENT_PUSH_MODEL_INSTANCE, name: "Storage Commitment Push Model SOP Instance", alias: "StorageCommitmentPushModelInstance", uid_type: WellKnownSopInstance, retired: false },
+    U { uid: STORAGE_COMMITMENT_PULL_MODEL, name: "Storage Commitment Pull Model SOP Class", alias: "StorageCommitmentPullModel", uid_type: SopClass, retired: true },
+    U { uid: PROCEDURAL_EVENT_LOGGING, name: "Procedural Event Logging SOP Class", alias: "ProceduralEventLogging", uid_type: SopClass, retired: false },
+    U { uid: DCM, name: "DICOM Controlled Terminology", alias: "DCM", uid_type: CodingScheme, retired: false },
+    U { uid: MA, name: "Adult Mouse Anatomy Ontology", alias: "MA", uid_type: CodingScheme, retired: false },
+    U { uid: UBERON, name: "Uberon Ontology", alias: "UBERON", uid_type: CodingScheme, retired: false },
+    U { uid: ITIS_TSN, name: "Integrated Taxonomic Information System (ITIS) Taxonomic Serial Number (TSN)", alias: "ITIS_TSN", uid_type: CodingScheme, retired: false },
+    U { uid: MGI, name: "Mouse Genome Initiative (MGI)", alias: "MGI", uid_type: CodingScheme, retired: false },
+    U { uid: PUBCHEM_CID, name: "PubChem Compound CID", alias: "PUBCHEM_CID", uid_type: CodingScheme, retired: false },
+    U { uid: DICOM_APPLICATION_CONTEXT, name: "DICOM Application Context Name", alias: "DICOMApplicationContext", uid_type: ApplicationContextName, retired: false },
+    U { uid: MODALITY_PERFORMED_PROCEDURE_STEP, name: "Modality Performed Procedure Step SOP Class", alias: "ModalityPerformedProcedureStep", uid_type: SopClass, retired: false },
+    U { uid: MODALITY_PERFORMED_PROCEDURE_STEP_RETRIEVE, name: "Modality Performed Procedure Step Retrieve SOP Class", alias: "ModalityPerformedProcedureStepRetrieve", uid_type: SopClass, retired: false },
+    U { uid: MODALITY_PERFORMED_PROCEDURE_STEP_NOTIFICATION, name: "Modality Performed Procedure Step Notification SOP Class", alias: "ModalityPerformedProcedureStepNotification", uid_type: SopClass, retired: false },
+    U { uid: BASIC_FILM_SESSION, name: "Basic Film Session SOP Class", alias: "BasicFilmSession", uid_type: SopClass, retired: false },
+    U { uid: BASIC_FILM_BOX, name: "Basic Film Box SOP Class", alias: "BasicFilmBox", uid_type: SopClass, retired: false },
+    U { uid: BASIC_GRAYSCALE_IMAGE_BOX, name: "Basic Grayscale Image Box SOP Class", alias: "BasicGrayscaleImageBox", uid_type: SopClass, retired: false },
+    U { uid: BASIC_COLOR_IMAGE_BOX, name: "Basic Color Image Box SOP Class", alias: "BasicColorImageBox", uid_type: SopClass, retired: false },
+    U { uid: BASIC_GRAYSCALE_PRINT_MANAGEMENT_META, name: "Basic Grayscale Print Management Meta SOP Class", alias: "BasicGrayscalePrintManagementMeta", uid_type: MetaSopClass, retired: false },
+    U { uid: PRINT_JOB, name: "Print Job SOP Class", alias: "PrintJob", uid_type: SopClass, retired: false },
+    U { uid: PRINTER, name: "Printer SOP Class", alias: "Printer", uid_type: SopClass, retired: false },
+    U { uid: PRINTER_INSTANCE, name: "Printer SOP Instance", alias: "PrinterInstance", uid_type: WellKnownSopInstance, retired: false },
+    U { uid: BASIC_COLOR_PRINT_MANAGEMENT_META, name: "Basic Color Print Management Meta SOP Class", alias: "BasicColorPrintManagementMeta", uid_type: MetaSopClass, retired: false },
+    U { uid: COMPUTED_RADIOGRAPHY_IMAGE_STORAGE, name: "Computed Radiography Image Storage", alias: "ComputedRadiographyImageStorage", uid_type: SopClass, retired: false },
+    U { uid: DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION, name: "Digital X-Ray Image Storage - For Presentation", alias: "DigitalXRayImageStorageForPresentation", uid_type: SopClass, retired: false },
+    U { uid: DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PROCESSING, name: "Digital X-Ray Image Storage - For Processing", alias: "DigitalXRayImageStorageForProcessing", uid_type: SopClass, retired: false },
+    U { uid: DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION, name: "Digital Mammography X-Ray Image Storage - For Presentation", alias: "DigitalMammographyXRayImageStorageForPresentation", uid_type: SopClass, retired: false },
+    U { uid: DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PROCESSING, name: "Digital Mammography X-Ray Image Storage - For Processing", alias: "DigitalMammographyXRayImageStorageForProcessing", uid_type: SopClass, retired: false },
+    U { uid: DIGITAL_INTRA_ORAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION, name: "Digital Intra-Oral X-Ray Image Storage - For Presentation", alias: "DigitalIntraOralXRayImageStorageForPresentation", uid_type: SopClass, retired: false },
+    U { uid: DIGITAL_INTRA_ORAL_X_RAY_IMAGE_STORAGE_FOR_PROCESSING, name: "Digital Intra-Oral X-Ray Image Storage - For Processing", alias: "DigitalIntraOralXRayImageStorageForProcessing", uid_type: SopClass, retired: false },
+    U { uid: CT_IMAGE_STORAGE, name: "CT Image Storage", alias: "CTImageStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_CT_IMAGE_STORAGE, name: "Enhanced CT Image Storage", alias: "EnhancedCTImageStorage", uid_type: SopClass, retired: false },
+    U { uid: LEGACY_CONVERTED_ENHANCED_CT_IMAGE_STORAGE, name: "Legacy Converted Enhanced CT Image Storage", alias: "LegacyConvertedEnhancedCTImageStorage", uid_type: SopClass, retired: false },
+    U { uid: ULTRASOUND_MULTI_FRAME_IMAGE_STORAGE_RETIRED, name: "Ultrasound Multi-frame Image Storage", alias: "UltrasoundMultiFrameImageStorageRetired", uid_type: SopClass, retired: true },
+    U { uid: ULTRASOUND_MULTI_FRAME_IMAGE_STORAGE, name: "Ultrasound Multi-frame Image Storage", alias: "UltrasoundMultiFrameImageStorage", uid_type: SopClass, retired: false },
+    U { uid: MR_IMAGE_STORAGE, name: "MR Image Storage", alias: "MRImageStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_MR_IMAGE_STORAGE, name: "Enhanced MR Image Storage", alias: "EnhancedMRImageStorage", uid_type: SopClass, retired: false },
+    U { uid: MR_SPECTROSCOPY_STORAGE, name: "MR Spectroscopy Storage", alias: "MRSpectroscopyStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_MR_COLOR_IMAGE_STORAGE, name: "Enhanced MR Color Image Storage", alias: "EnhancedMRColorImageStorage", uid_type: SopClass, retired: false },
+    U { uid: LEGACY_CONVERTED_ENHANCED_MR_IMAGE_STORAGE, name: "Legacy Converted Enhanced MR Image Storage", alias: "LegacyConvertedEnhancedMRImageStorage", uid_type: SopClass, retired: false },
+    U { uid: NUCLEAR_MEDICINE_IMAGE_STORAGE_RETIRED, name: "Nuclear Medicine Image Storage", alias: "NuclearMedicineImageStorageRetired", uid_type: SopClass, retired: true },
+    U { uid: ULTRASOUND_IMAGE_STORAGE_RETIRED, name: "Ultrasound Image Storage", alias: "UltrasoundImageStorageRetired", uid_type: SopClass, retired: true },
+    U { uid: ULTRASOUND_IMAGE_STORAGE, name: "Ultrasound Image Storage", alias: "UltrasoundImageStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_US_VOLUME_STORAGE, name: "Enhanced US Volume Storage", alias: "EnhancedUSVolumeStorage", uid_type: SopClass, retired: false },
+    U { uid: SECONDARY_CAPTURE_IMAGE_STORAGE, name: "Secondary Capture Image Storage", alias: "SecondaryCaptureImageStorage", uid_type: SopClass, retired: false },
+    U { uid: MULTI_FRAME_SINGLE_BIT_SECONDARY_CAPTURE_IMAGE_STORAGE, name: "Multi-frame Single Bit Secondary Capture Image Storage", alias: "MultiFrameSingleBitSecondaryCaptureImageStorage", uid_type: SopClass, retired: false },
+    U { uid: MULTI_FRAME_GRAYSCALE_BYTE_SECONDARY_CAPTURE_IMAGE_STORAGE, name: "Multi-frame Grayscale Byte Secondary Capture Image Storage", alias: "MultiFrameGrayscaleByteSecondaryCaptureImageStorage", uid_type: SopClass, retired: false },
+    U { uid: MULTI_FRAME_GRAYSCALE_WORD_SECONDARY_CAPTURE_IMAGE_STORAGE, name: "Multi-frame Grayscale Word Secondary Capture Image Storage", alias: "MultiFrameGrayscaleWordSecondaryCaptureImageStorage", uid_type: SopClass, retired: false },
+    U { uid: MULTI_FRAME_TRUE_COLOR_SECONDARY_CAPTURE_IMAGE_STORAGE, name: "Multi-frame True Color Secondary Capture Image Storage", alias: "MultiFrameTrueColorSecondaryCaptureImageStorage", uid_type: SopClass, retired: false },
+    U { uid: TWELVE_LEAD_ECG_WAVEFORM_STORAGE, name: "12-lead ECG Waveform Storage", alias: "TwelveLeadECGWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: GENERAL_ECG_WAVEFORM_STORAGE, name: "General ECG Waveform Storage", alias: "GeneralECGWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: AMBULATORY_ECG_WAVEFORM_STORAGE, name: "Ambulatory ECG Waveform Storage", alias: "AmbulatoryECGWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: HEMODYNAMIC_WAVEFORM_STORAGE, name: "Hemodynamic Waveform Storage", alias: "HemodynamicWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: CARDIAC_ELECTROPHYSIOLOGY_WAVEFORM_STORAGE, name: "Cardiac Electrophysiology Waveform Storage", alias: "CardiacElectrophysiologyWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: BASIC_VOICE_AUDIO_WAVEFORM_STORAGE, name: "Basic Voice Audio Waveform Storage", alias: "BasicVoiceAudioWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: ARTERIAL_PULSE_WAVEFORM_STORAGE, name: "Arterial Pulse Waveform Storage", alias: "ArterialPulseWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: RESPIRATORY_WAVEFORM_STORAGE, name: "Respiratory Waveform Storage", alias: "RespiratoryWaveformStorage", uid_type: SopClass, retired: false },
+    U { uid: GRAYSCALE_SOFTCOPY_PRESENTATION_STATE_STORAGE, name: "Grayscale Softcopy Presentation State Storage", alias: "GrayscaleSoftcopyPresentationStateStorage", uid_type: SopClass, retired: false },
+    U { uid: COLOR_SOFTCOPY_PRESENTATION_STATE_STORAGE, name: "Color Softcopy Presentation State Storage", alias: "ColorSoftcopyPresentationStateStorage", uid_type: SopClass, retired: false },
+    U { uid: PSEUDO_COLOR_SOFTCOPY_PRESENTATION_STATE_STORAGE, name: "Pseudo-Color Softcopy Presentation State Storage", alias: "PseudoColorSoftcopyPresentationStateStorage", uid_type: SopClass, retired: false },
+    U { uid: BLENDING_SOFTCOPY_PRESENTATION_STATE_STORAGE, name: "Blending Softcopy Presentation State Storage", alias: "BlendingSoftcopyPresentationStateStorage", uid_type: SopClass, retired: false },
+    U { uid: X_RAY_ANGIOGRAPHIC_IMAGE_STORAGE, name: "X-Ray Angiographic Image Storage", alias: "XRayAngiographicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_XA_IMAGE_STORAGE, name: "Enhanced XA Image Storage", alias: "EnhancedXAImageStorage", uid_type: SopClass, retired: false },
+    U { uid: X_RAY_RADIOFLUOROSCOPIC_IMAGE_STORAGE, name: "X-Ray Radiofluoroscopic Image Storage", alias: "XRayRadiofluoroscopicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_XRF_IMAGE_STORAGE, name: "Enhanced XRF Image Storage", alias: "EnhancedXRFImageStorage", uid_type: SopClass, retired: false },
+    U { uid: X_RAY3D_ANGIOGRAPHIC_IMAGE_STORAGE, name: "X-Ray 3D Angiographic Image Storage", alias: "XRay3DAngiographicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: X_RAY3D_CRANIOFACIAL_IMAGE_STORAGE, name: "X-Ray 3D Craniofacial Image Storage", alias: "XRay3DCraniofacialImageStorage", uid_type: SopClass, retired: false },
+    U { uid: BREAST_TOMOSYNTHESIS_IMAGE_STORAGE, name: "Breast Tomosynthesis Image Storage", alias: "BreastTomosynthesisImageStorage", uid_type: SopClass, retired: false },
+    U { uid: NUCLEAR_MEDICINE_IMAGE_STORAGE, name: "Nuclear Medicine Image Storage", alias: "NuclearMedicineImageStorage", uid_type: SopClass, retired: false },
+    U { uid: PARAMETRIC_MAP_STORAGE, name: "Parametric Map Storage", alias: "ParametricMapStorage", uid_type: SopClass, retired: false },
+    U { uid: RAW_DATA_STORAGE, name: "Raw Data Storage", alias: "RawDataStorage", uid_type: SopClass, retired: false },
+    U { uid: SPATIAL_REGISTRATION_STORAGE, name: "Spatial Registration Storage", alias: "SpatialRegistrationStorage", uid_type: SopClass, retired: false },
+    U { uid: SPATIAL_FIDUCIALS_STORAGE, name: "Spatial Fiducials Storage", alias: "SpatialFiducialsStorage", uid_type: SopClass, retired: false },
+    U { uid: DEFORMABLE_SPATIAL_REGISTRATION_STORAGE, name: "Deformable Spatial Registration Storage", alias: "DeformableSpatialRegistrationStorage", uid_type: SopClass, retired: false },
+    U { uid: SEGMENTATION_STORAGE, name: "Segmentation Storage", alias: "SegmentationStorage", uid_type: SopClass, retired: false },
+    U { uid: SURFACE_SEGMENTATION_STORAGE, name: "Surface Segmentation Storage", alias: "SurfaceSegmentationStorage", uid_type: SopClass, retired: false },
+    U { uid: REAL_WORLD_VALUE_MAPPING_STORAGE, name: "Real World Value Mapping Storage", alias: "RealWorldValueMappingStorage", uid_type: SopClass, retired: false },
+    U { uid: VL_ENDOSCOPIC_IMAGE_STORAGE, name: "VL Endoscopic Image Storage", alias: "VLEndoscopicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: VIDEO_ENDOSCOPIC_IMAGE_STORAGE, name: "Video Endoscopic Image Storage", alias: "VideoEndoscopicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: VL_MICROSCOPIC_IMAGE_STORAGE, name: "VL Microscopic Image Storage", alias: "VLMicroscopicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: VL_SLIDE_COORDINATES_MICROSCOPIC_IMAGE_STORAGE, name: "VL Slide-Coordinates Microscopic Image Storage", alias: "VLSlideCoordinatesMicroscopicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: VL_PHOTOGRAPHIC_IMAGE_STORAGE, name: "VL Photographic Image Storage", alias: "VLPhotographicImageStorage", uid_type: SopClass, retired: false },
+    U { uid: OPHTHALMIC_PHOTOGRAPHY8_BIT_IMAGE_STORAGE, name: "Ophthalmic Photography 8 Bit Image Storage", alias: "OphthalmicPhotography8BitImageStorage", uid_type: SopClass, retired: false },
+    U { uid: VL_WHOLE_SLIDE_MICROSCOPY_IMAGE_STORAGE, name: "VL Whole Slide Microscopy Image Storage", alias: "VLWholeSlideMicroscopyImageStorage", uid_type: SopClass, retired: false },
+    U { uid: BASIC_TEXT_SR_STORAGE, name: "Basic Text SR Storage", alias: "BasicTextSRStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_SR_STORAGE, name: "Enhanced SR Storage", alias: "EnhancedSRStorage", uid_type: SopClass, retired: false },
+    U { uid: COMPREHENSIVE_SR_STORAGE, name: "Comprehensive SR Storage", alias: "ComprehensiveSRStorage", uid_type: SopClass, retired: false },
+    U { uid: COMPREHENSIVE3DSR_STORAGE, name: "Comprehensive 3D SR Storage", alias: "Comprehensive3DSRStorage", uid_type: SopClass, retired: false },
+    U { uid: EXTENSIBLE_SR_STORAGE, name: "Extensible SR Storage", alias: "ExtensibleSRStorage", uid_type: SopClass, retired: false },
+    U { uid: PROCEDURE_LOG_STORAGE, name: "Procedure Log Storage", alias: "ProcedureLogStorage", uid_type: SopClass, retired: false },
+    U { uid: MAMMOGRAPHY_CADSR_STORAGE, name: "Mammography CAD SR Storage", alias: "MammographyCADSRStorage", uid_type: SopClass, retired: false },
+    U { uid: KEY_OBJECT_SELECTION_DOCUMENT_STORAGE, name: "Key Object Selection Document Storage", alias: "KeyObjectSelectionDocumentStorage", uid_type: SopClass, retired: false },
+    U { uid: CHEST_CADSR_STORAGE, name: "Chest CAD SR Storage", alias: "ChestCADSRStorage", uid_type: SopClass, retired: false },
+    U { uid: X_RAY_RADIATION_DOSE_SR_STORAGE, name: "X-Ray Radiation Dose SR Storage", alias: "XRayRadiationDoseSRStorage", uid_type: SopClass, retired: false },
+    U { uid: RADIOPHARMACEUTICAL_RADIATION_DOSE_SR_STORAGE, name: "Radiopharmaceutical Radiation Dose SR Storage", alias: "RadiopharmaceuticalRadiationDoseSRStorage", uid_type: SopClass, retired: false },
+    U { uid: ENCAPSULATED_PDF_STORAGE, name: "Encapsulated PDF Storage", alias: "EncapsulatedPDFStorage", uid_type: SopClass, retired: false },
+    U { uid: ENCAPSULATED_CDA_STORAGE, name: "Encapsulated CDA Storage", alias: "EncapsulatedCDAStorage", uid_type: SopClass, retired: false },
+    U { uid: ENCAPSULATED_STL_STORAGE, name: "Encapsulated STL Storage", alias: "EncapsulatedSTLStorage", uid_type: SopClass, retired: false },
+    U { uid: POSITRON_EMISSION_TOMOGRAPHY_IMAGE_STORAGE, name: "Positron Emission Tomography Image Storage", alias: "PositronEmissionTomographyImageStorage", uid_type: SopClass, retired: false },
+    U { uid: LEGACY_CONVERTED_ENHANCED_PET_IMAGE_STORAGE, name: "Legacy Converted Enhanced PET Image Storage", alias: "LegacyConvertedEnhancedPETImageStorage", uid_type: SopClass, retired: false },
+    U { uid: ENHANCED_PET_IMAGE_STORAGE, name: "Enhanced PET Image Storage", alias: "EnhancedPETImageStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_IMAGE_STORAGE, name: "RT Image Storage", alias: "RTImageStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_DOSE_STORAGE, name: "RT Dose Storage", alias: "RTDoseStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_STRUCTURE_SET_STORAGE, name: "RT Structure Set Storage", alias: "RTStructureSetStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_BEAMS_TREATMENT_RECORD_STORAGE, name: "RT Beams Treatment Record Storage", alias: "RTBeamsTreatmentRecordStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_PLAN_STORAGE, name: "RT Plan Storage", alias: "RTPlanStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_BRACHY_TREATMENT_RECORD_STORAGE, name: "RT Brachy Treatment Record Storage", alias: "RTBrachyTreatmentRecordStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_TREATMENT_SUMMARY_RECORD_STORAGE, name: "RT Treatment Summary Record Storage", alias: "RTTreatmentSummaryRecordStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_ION_PLAN_STORAGE, name: "RT Ion Plan Storage", alias: "RTIonPlanStorage", uid_type: SopClass, retired: false },
+    U { uid: RT_ION_BEAMS_TREATMENT_RECORD_STORAGE, name: "RT Ion Beams Treatment Record Storage", alias: "RTIonBeamsTreatmentRecordStorage", uid_type: SopClass, retired: false },
+    U { uid: PATIENT_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_FIND, name: "Patient Root Query/Retrieve Information Model - FIND", alias: "PatientRootQueryRetrieveInformationModelFind", uid_type: SopClass, retired: false },
+    U { uid: PATIENT_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_MOVE, name: "Patient Root Query/Retrieve Information Model - MOVE", alias: "PatientRootQueryRetrieveInformationModelMove", uid_type: SopClass, retired: false },
+    U { uid: PATIENT_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_GET, name: "Patient Root Query/Retrieve Information Model - GET", alias: "PatientRootQueryRetrieveInformationModelGet", uid_type: SopClass, retired: false },
+    U { uid: STUDY_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_FIND, name: "Study Root Query/Retrieve Information Model - FIND", alias: "StudyRootQueryRetrieveInformationModelFind", uid_type: SopClass, retired: false },
+    U { uid: STUDY_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_MOVE, name: "Study Root Query/Retrieve Information Model - MOVE", alias: "StudyRootQueryRetrieveInformationModelMove", uid_type: SopClass, retired: false },
+    U { uid: STUDY_ROOT_QUERY_RETRIEVE_INFORMATION_MODEL_GET, name: "Study Root Query/Retrieve Information Model - GET", alias: "StudyRootQueryRetrieveInformationModelGet", uid_type: SopClass, retired: false },
+    U { uid: PATIENT_STUDY_ONLY_QUERY_RETRIEVE_INFORMATION_MODEL_FIND, name: "Patient/Study Only Query/Retrieve Information Model - FIND", alias: "PatientStudyOnlyQueryRetrieveInformationModelFind", uid_type: SopClass, retired: true },
+    U { uid: MODALITY_WORKLIST_INFORMATION_MODEL_FIND, name: "Modality Worklist Information Model - FIND", alias: "ModalityWorklistInformationModelFind", uid_type: SopClass, retired: false },
+    U { uid: INSTANCE_AVAILABILITY_NOTIFICATION, name: "Instance Availability Notification SOP Class", alias: "InstanceAvailabilityNotification", uid_type: SopClass, retired: false },
+    U { uid: UTC, name: "Universal Coordinated Time", alias: "UTC", uid_type: WellKnownFrameOfReference, retired: false },
+    U { uid: TALAIRACH_BRAIN_ATLAS, name: "Talairach Brain Atlas Frame of Reference", alias: "TalairachBrainAtlas", uid_type: WellKnownFrameOfReference, retired: false },
+    U { uid: SPM2T1, name: "SPM2 T1 Frame of Reference", alias: "SPM2T1", uid_type: WellKnownFrameOfReference, retired: false },
+    U { uid: SPM2T2, name: "SPM2 T2 Frame of Reference", alias: "SPM2T2", uid_type: WellKnownFrameOfReference, retired: false },
+    U { uid: SPM2PD, name: "SPM2 PD Frame of Reference", alias: "SPM2PD", uid_type: WellKnownFrameOfReference, retired: false },
+    U { uid: SPM2EPI, name: "SPM2 EPI Frame of Reference", alias: "SPM2EPI", uid_type: WellKnownFrameOfReference, retired: false },
+];
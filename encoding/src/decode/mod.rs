@@ -7,6 +7,7 @@ use crate::transfer_syntax::implicit_le::{
 };
 use byteordered::Endianness;
 use dicom_core::header::{DataElementHeader, SequenceItemHeader};
+use dicom_core::value::PrimitiveValue;
 use dicom_core::Tag;
 use std::io::Read;
 
@@ -269,6 +270,29 @@ pub trait Decode {
 
     /// Decode a DICOM attribute tag from the given source.
     fn decode_tag(&self, source: &mut Self::Source) -> Result<Tag>;
+
+    /// Record the value of a primitive data element which was just read.
+    ///
+    /// Decoders which need to know the values of other attributes in the
+    /// data set in order to resolve a value representation (such as
+    /// Pixel Representation for attributes of VR `US or SS` in implicit
+    /// VR) may keep them here. Parsers should call this method after
+    /// reading each primitive value. The default implementation does
+    /// nothing.
+    fn record_value(&self, _header: &DataElementHeader, _value: &PrimitiveValue) {}
+
+    /// Begin reading the data set of a sequence item.
+    ///
+    /// The values recorded with `record_value` from now on only apply to
+    /// the item, until the matching call to `leave_item`. Parsers should
+    /// call this method when an item starts. The default implementation
+    /// does nothing.
+    fn enter_item(&self) {}
+
+    /// Finish reading the data set of a sequence item, discarding the
+    /// values recorded in it. Parsers should call this method when an
+    /// item ends. The default implementation does nothing.
+    fn leave_item(&self) {}
 }
 
 impl<T: ?Sized> Decode for Box<T>
@@ -288,6 +312,18 @@ where
     fn decode_tag(&self, source: &mut Self::Source) -> Result<Tag> {
         (**self).decode_tag(source)
     }
+
+    fn record_value(&self, header: &DataElementHeader, value: &PrimitiveValue) {
+        (**self).record_value(header, value)
+    }
+
+    fn enter_item(&self) {
+        (**self).enter_item()
+    }
+
+    fn leave_item(&self) {
+        (**self).leave_item()
+    }
 }
 
 impl<'a, T: ?Sized> Decode for &'a T
@@ -307,4 +343,16 @@ where
     fn decode_tag(&self, source: &mut Self::Source) -> Result<Tag> {
         (**self).decode_tag(source)
     }
+
+    fn record_value(&self, header: &DataElementHeader, value: &PrimitiveValue) {
+        (**self).record_value(header, value)
+    }
+
+    fn enter_item(&self) {
+        (**self).enter_item()
+    }
+
+    fn leave_item(&self) {
+        (**self).leave_item()
+    }
 }
@@ -15,13 +15,16 @@
 //! At the moment, this library supports only IR-6 and IR-192.
 
 use crate::error::{Result, TextEncodingError};
+use dicom_core::value::PersonName;
 use encoding::all::{ISO_8859_1, UTF_8};
 use encoding::{DecoderTrap, EncoderTrap, Encoding, RawDecoder, StringWriter};
 use std::fmt::Debug;
 
 /// A holder of encoding and decoding mechanisms for text in DICOM content,
 /// which according to the standard, depends on the specific character set.
-pub trait TextCodec: Debug {
+///
+/// Codecs are `Send`, so that parsers can be moved to other threads.
+pub trait TextCodec: Debug + Send {
     /// Decode the given byte buffer as a single string. The resulting string
     /// _may_ contain backslash characters ('\') to delimit individual values,
     /// and should be split later on if required.
@@ -31,6 +34,34 @@ pub trait TextCodec: Debug {
     /// feature multiple text values by using the backslash character ('\')
     /// as the value delimiter.
     fn encode(&self, text: &str) -> Result<Vec<u8>>;
+
+    /// Decode the given byte buffer as a single person name.
+    ///
+    /// The value is decoded as a whole before it is split into
+    /// component groups, since the group separator byte may also occur
+    /// within the multi-byte characters of some character sets.
+    /// Trailing padding is ignored.
+    fn decode_person_name(&self, text: &[u8]) -> Result<PersonName> {
+        Ok(PersonName::parse(&self.decode(text)?))
+    }
+
+    /// Encode a single person name into a byte vector,
+    /// encoding each component group on its own.
+    fn encode_person_name(&self, name: &PersonName) -> Result<Vec<u8>> {
+        let groups = name.groups();
+        let len = groups
+            .iter()
+            .rposition(|g| !g.is_empty())
+            .map_or(0, |i| i + 1);
+        let mut out = Vec::new();
+        for (i, group) in groups[..len].iter().enumerate() {
+            if i > 0 {
+                out.push(b'=');
+            }
+            out.extend(self.encode(&group.to_string())?);
+        }
+        Ok(out)
+    }
 }
 
 impl<T: ?Sized> TextCodec for Box<T>
@@ -48,7 +79,7 @@ where
 
 impl<'a, T: ?Sized> TextCodec for &'a T
 where
-    T: TextCodec,
+    T: TextCodec + Sync,
 {
     fn decode(&self, text: &[u8]) -> Result<String> {
         (*self).decode(text)
@@ -82,8 +113,8 @@ impl SpecificCharacterSet {
     pub fn from_code(uid: &str) -> Option<Self> {
         use self::SpecificCharacterSet::*;
         match uid {
-            "Default" | "ISO_IR_6" => Some(Default),
-            "ISO_IR_192" => Some(IsoIr192),
+            "Default" | "ISO_IR_6" | "ISO_IR 6" => Some(Default),
+            "ISO_IR_192" | "ISO_IR 192" => Some(IsoIr192),
             _ => None,
         }
     }
@@ -175,7 +206,10 @@ pub fn validate_iso_8859(text: &[u8]) -> TextValidationOutcome {
 /// Check whether the given byte slice contains only valid characters for a
 /// Date value representation.
 pub fn validate_da(text: &[u8]) -> TextValidationOutcome {
-    if text.iter().cloned().all(|c| c >= b'0' && c <= b'9') {
+    if text.iter().cloned().all(|c| match c {
+        b'\\' | b'-' | b' ' => true,
+        c => c.is_ascii_digit(),
+    }) {
         TextValidationOutcome::Ok
     } else {
         TextValidationOutcome::NotOk
@@ -220,3 +254,62 @@ pub fn validate_cs(text: &[u8]) -> TextValidationOutcome {
         TextValidationOutcome::NotOk
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn person_names_by_component_group() {
+        let codec = Utf8CharacterSetCodec;
+        let name = codec
+            .decode_person_name("Yamada^Tarou=山田^太郎=やまだ^たろう ".as_bytes())
+            .unwrap();
+        assert_eq!(name.family_name(), "Yamada");
+        assert_eq!(name.ideographic.given, "太郎");
+        assert_eq!(name.phonetic.family, "やまだ");
+        assert_eq!(
+            codec.encode_person_name(&name).unwrap(),
+            "Yamada^Tarou=山田^太郎=やまだ^たろう".as_bytes()
+        );
+
+        let codec = DefaultCharacterSetCodec;
+        let name = codec.decode_person_name(b"Doe^John^^Dr.==").unwrap();
+        assert_eq!(name.prefix(), "Dr.");
+        assert_eq!(codec.encode_person_name(&name).unwrap(), b"Doe^John^^Dr.");
+        assert!(codec
+            .encode_person_name(&PersonName::parse("=山田"))
+            .is_err());
+    }
+
+    /// A codec of two bytes per character, the first of which is the
+    /// group separator, as may happen in multi-byte character sets.
+    #[derive(Debug)]
+    struct DoubleByteCodec;
+
+    impl TextCodec for DoubleByteCodec {
+        fn decode(&self, text: &[u8]) -> Result<String> {
+            Ok(text
+                .chunks(2)
+                .filter_map(|c| c.get(1))
+                .map(|&b| b as char)
+                .collect())
+        }
+
+        fn encode(&self, text: &str) -> Result<Vec<u8>> {
+            Ok(text.bytes().flat_map(|b| vec![b'=', b]).collect())
+        }
+    }
+
+    #[test]
+    fn person_names_are_split_after_decoding() {
+        let codec = DoubleByteCodec;
+        let name = codec
+            .decode_person_name(&codec.encode("Yamada^Tarou=YAMADA").unwrap())
+            .unwrap();
+        assert_eq!(name.family_name(), "Yamada");
+        assert_eq!(name.given_name(), "Tarou");
+        assert_eq!(name.ideographic.family, "YAMADA");
+        assert!(name.phonetic.is_empty());
+    }
+}
@@ -16,7 +16,8 @@ use std::marker::PhantomData;
 /// A data element decoder for the Explicit VR Big Endian transfer syntax.
 pub struct ExplicitVRBigEndianDecoder<S: ?Sized> {
     basic: BigEndianBasicDecoder,
-    phantom: PhantomData<S>,
+    /// the source is only borrowed, so it does not affect `Send`
+    phantom: PhantomData<fn(&mut S)>,
 }
 
 impl<S: ?Sized> Default for ExplicitVRBigEndianDecoder<S> {
@@ -190,7 +191,7 @@ where
     type Writer = W;
 
     fn encode_tag(&self, to: &mut W, tag: Tag) -> Result<()> {
-        let mut buf = [0u8, 4];
+        let mut buf = [0u8; 4];
         BigEndian::write_u16(&mut buf[..], tag.group());
         BigEndian::write_u16(&mut buf[2..], tag.element());
         to.write_all(&buf)?;
@@ -239,7 +240,7 @@ where
     fn encode_item_header(&self, to: &mut W, len: u32) -> Result<()> {
         let mut buf = [0u8; 8];
         BigEndian::write_u16(&mut buf, 0xFFFE);
-        BigEndian::write_u16(&mut buf, 0xE000);
+        BigEndian::write_u16(&mut buf[2..], 0xE000);
         BigEndian::write_u32(&mut buf[4..], len);
         to.write_all(&buf)?;
         Ok(())
@@ -248,7 +249,7 @@ where
     fn encode_item_delimiter(&self, to: &mut W) -> Result<()> {
         let mut buf = [0u8; 8];
         BigEndian::write_u16(&mut buf, 0xFFFE);
-        BigEndian::write_u16(&mut buf, 0xE00D);
+        BigEndian::write_u16(&mut buf[2..], 0xE00D);
         // remaining bytes are already zero, so it's ready to write
         to.write_all(&buf)?;
         Ok(())
@@ -257,7 +258,7 @@ where
     fn encode_sequence_delimiter(&self, to: &mut W) -> Result<()> {
         let mut buf = [0u8; 8];
         BigEndian::write_u16(&mut buf, 0xFFFE);
-        BigEndian::write_u16(&mut buf, 0xE0DD);
+        BigEndian::write_u16(&mut buf[2..], 0xE0DD);
         // remaining bytes are already zero, so it's ready to write
         to.write_all(&buf)?;
         Ok(())
@@ -437,4 +438,20 @@ mod tests {
             assert_eq!(elem.len(), Length(0));
         }
     }
+
+    #[test]
+    fn encode_items() {
+        let enc = ExplicitVRBigEndianEncoder::default();
+        let mut buf = Vec::new();
+        let de = DataElementHeader::new(Tag(0x0008, 0x103F), VR::SQ, Length::UNDEFINED);
+        enc.encode_element_header(&mut buf, de)
+            .expect("should write it fine");
+        enc.encode_item_header(&mut buf, Length::UNDEFINED.0)
+            .expect("should write it fine");
+        enc.encode_item_delimiter(&mut buf)
+            .expect("should write it fine");
+        enc.encode_sequence_delimiter(&mut buf)
+            .expect("should write it fine");
+        assert_eq!(&buf[..], RAW_SEQUENCE_ITEMS);
+    }
 }
@@ -16,7 +16,8 @@ use std::marker::PhantomData;
 /// A data element decoder for the Explicit VR Little Endian transfer syntax.
 pub struct ExplicitVRLittleEndianDecoder<S: ?Sized> {
     basic: LittleEndianBasicDecoder,
-    phantom: PhantomData<S>,
+    /// the source is only borrowed, so it does not affect `Send`
+    phantom: PhantomData<fn(&mut S)>,
 }
 
 impl<S: ?Sized> Default for ExplicitVRLittleEndianDecoder<S> {
@@ -191,7 +192,7 @@ where
     type Writer = W;
 
     fn encode_tag(&self, to: &mut W, tag: Tag) -> Result<()> {
-        let mut buf = [0u8, 4];
+        let mut buf = [0u8; 4];
         LittleEndian::write_u16(&mut buf[..], tag.group());
         LittleEndian::write_u16(&mut buf[2..], tag.element());
         to.write_all(&buf)?;
@@ -238,7 +239,7 @@ where
     fn encode_item_header(&self, to: &mut W, len: u32) -> Result<()> {
         let mut buf = [0u8; 8];
         LittleEndian::write_u16(&mut buf, 0xFFFE);
-        LittleEndian::write_u16(&mut buf, 0xE000);
+        LittleEndian::write_u16(&mut buf[2..], 0xE000);
         LittleEndian::write_u32(&mut buf[4..], len);
         to.write_all(&buf)?;
         Ok(())
@@ -247,7 +248,7 @@ where
     fn encode_item_delimiter(&self, to: &mut W) -> Result<()> {
         let mut buf = [0u8; 8];
         LittleEndian::write_u16(&mut buf, 0xFFFE);
-        LittleEndian::write_u16(&mut buf, 0xE00D);
+        LittleEndian::write_u16(&mut buf[2..], 0xE00D);
         to.write_all(&buf)?;
         Ok(())
     }
@@ -255,7 +256,7 @@ where
     fn encode_sequence_delimiter(&self, to: &mut W) -> Result<()> {
         let mut buf = [0u8; 8];
         LittleEndian::write_u16(&mut buf, 0xFFFE);
-        LittleEndian::write_u16(&mut buf, 0xE0DD);
+        LittleEndian::write_u16(&mut buf[2..], 0xE0DD);
         to.write_all(&buf)?;
         Ok(())
     }
@@ -434,4 +435,20 @@ mod tests {
             assert_eq!(elem.len(), Length(0));
         }
     }
+
+    #[test]
+    fn encode_items() {
+        let enc = ExplicitVRLittleEndianEncoder::default();
+        let mut buf = Vec::new();
+        let de = DataElementHeader::new(Tag(0x0008, 0x103F), VR::SQ, Length::UNDEFINED);
+        enc.encode_element_header(&mut buf, de)
+            .expect("should write it fine");
+        enc.encode_item_header(&mut buf, Length::UNDEFINED.0)
+            .expect("should write it fine");
+        enc.encode_item_delimiter(&mut buf)
+            .expect("should write it fine");
+        enc.encode_sequence_delimiter(&mut buf)
+            .expect("should write it fine");
+        assert_eq!(&buf[..], RAW_SEQUENCE_ITEMS);
+    }
 }
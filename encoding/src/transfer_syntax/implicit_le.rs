@@ -7,10 +7,12 @@ use crate::encode::{BasicEncode, Encode};
 use crate::error::Result;
 use byteordered::byteorder::{ByteOrder, LittleEndian};
 use byteordered::Endianness;
-use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry, VirtualVr};
 use dicom_core::header::{DataElementHeader, Header, Length, SequenceItemHeader};
+use dicom_core::value::PrimitiveValue;
 use dicom_core::{Tag, VR};
 use dicom_dictionary_std::StandardDataDictionary;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::io::{Read, Write};
 use std::marker::PhantomData;
@@ -25,7 +27,83 @@ pub type StandardImplicitVRLittleEndianDecoder<S> =
 pub struct ImplicitVRLittleEndianDecoder<S: ?Sized, D> {
     dict: D,
     basic: LittleEndianBasicDecoder,
-    phantom: PhantomData<S>,
+    context: DataSetContext,
+    /// the source is only borrowed, so it does not affect `Send`
+    phantom: PhantomData<fn(&mut S)>,
+}
+
+/// The values of the attributes read so far which are needed
+/// to resolve ambiguous value representations.
+///
+/// Each sequence item is a data set of its own, so the values recorded
+/// in an item are discarded once the item ends. Values not defined in
+/// the item are taken from the enclosing data sets.
+#[derive(Debug, Default)]
+struct DataSetContext {
+    /// the values in effect in the data set being read
+    current: Cell<ContextValues>,
+    /// the values of the enclosing data sets, from the outermost
+    enclosing: RefCell<Vec<ContextValues>>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct ContextValues {
+    /// Pixel Representation (0028,0103)
+    pixel_representation: Option<u16>,
+    /// Bits Allocated (0028,0100)
+    bits_allocated: Option<u16>,
+    /// Waveform Bits Allocated (5400,1004)
+    waveform_bits_allocated: Option<u16>,
+}
+
+impl DataSetContext {
+    /// Resolve a virtual value representation into a concrete one,
+    /// as defined in PS3.5 Annex A.1.
+    fn resolve(&self, tag: Tag, vr: VirtualVr) -> VR {
+        let values = self.current.get();
+        match vr {
+            VirtualVr::Exact(vr) => vr,
+            VirtualVr::Xs => match values.pixel_representation {
+                Some(1) => VR::SS,
+                _ => VR::US,
+            },
+            VirtualVr::Ox => {
+                let bits_allocated = if tag.group() == 0x5400 {
+                    values.waveform_bits_allocated
+                } else {
+                    values.bits_allocated
+                };
+                match bits_allocated {
+                    Some(bits) if bits <= 8 => VR::OB,
+                    _ => VR::OW,
+                }
+            }
+            // always OW in Implicit VR Little Endian
+            VirtualVr::Px | VirtualVr::Lt => VR::OW,
+        }
+    }
+
+    fn record(&self, tag: Tag, value: &PrimitiveValue) {
+        let mut values = self.current.get();
+        let field = match tag {
+            Tag(0x0028, 0x0103) => &mut values.pixel_representation,
+            Tag(0x0028, 0x0100) => &mut values.bits_allocated,
+            Tag(0x5400, 0x1004) => &mut values.waveform_bits_allocated,
+            _ => return,
+        };
+        *field = value.uint16();
+        self.current.set(values);
+    }
+
+    fn enter_item(&self) {
+        self.enclosing.borrow_mut().push(self.current.get());
+    }
+
+    fn leave_item(&self) {
+        if let Some(values) = self.enclosing.borrow_mut().pop() {
+            self.current.set(values);
+        }
+    }
 }
 
 impl<S: ?Sized, D> fmt::Debug for ImplicitVRLittleEndianDecoder<S, D> {
@@ -33,6 +111,7 @@ impl<S: ?Sized, D> fmt::Debug for ImplicitVRLittleEndianDecoder<S, D> {
         f.debug_struct("ImplicitVRLittleEndianDecoder")
             .field("dict", &"«omitted»")
             .field("basic", &self.basic)
+            .field("context", &self.context)
             .field("phantom", &self.phantom)
             .finish()
     }
@@ -44,6 +123,7 @@ impl<S: ?Sized> ImplicitVRLittleEndianDecoder<S, StandardDataDictionary> {
         ImplicitVRLittleEndianDecoder {
             dict: StandardDataDictionary,
             basic: LittleEndianBasicDecoder,
+            context: DataSetContext::default(),
             phantom: PhantomData,
         }
     }
@@ -65,6 +145,7 @@ where
         ImplicitVRLittleEndianDecoder {
             dict: dictionary,
             basic: LittleEndianBasicDecoder,
+            context: DataSetContext::default(),
             phantom: PhantomData,
         }
     }
@@ -88,12 +169,17 @@ where
         // VR resolution is done with the help of the data dictionary.
         // In Implicit VR Little Endian, the VR of OB may not be used for Pixel
         // Data (7FE0,0010). This edge case is addressed manually.
+        // Private creator data elements are always of VR LO.
+        // Attributes with more than one possible VR are resolved
+        // from the values of other attributes read so far.
         let vr = if tag == Tag(0x7FE0, 0x0010) {
             VR::OW
+        } else if tag.is_private_creator() {
+            VR::LO
         } else {
             self.dict
                 .by_tag(tag)
-                .map(|entry| entry.vr())
+                .map(|entry| self.context.resolve(tag, entry.virtual_vr()))
                 .unwrap_or(VR::UN)
         };
         Ok(DataElementHeader::new(tag, vr, Length(len)))
@@ -115,6 +201,18 @@ where
     fn decode_tag(&self, source: &mut S) -> Result<Tag> {
         self.basic.decode_tag(source)
     }
+
+    fn record_value(&self, header: &DataElementHeader, value: &PrimitiveValue) {
+        self.context.record(header.tag(), value)
+    }
+
+    fn enter_item(&self) {
+        self.context.enter_item()
+    }
+
+    fn leave_item(&self) {
+        self.context.leave_item()
+    }
 }
 
 /// A concrete encoder for the transfer syntax ImplicitVRLittleEndian
@@ -199,7 +297,7 @@ where
     type Writer = W;
 
     fn encode_tag(&self, to: &mut W, tag: Tag) -> Result<()> {
-        let mut buf = [0u8, 4];
+        let mut buf = [0u8; 4];
         LittleEndian::write_u16(&mut buf[..], tag.group());
         LittleEndian::write_u16(&mut buf[2..], tag.element());
         to.write_all(&buf)?;
@@ -218,7 +316,7 @@ where
     fn encode_item_header(&self, to: &mut W, len: u32) -> Result<()> {
         let mut buf = [0u8; 8];
         LittleEndian::write_u16(&mut buf, 0xFFFE);
-        LittleEndian::write_u16(&mut buf, 0xE000);
+        LittleEndian::write_u16(&mut buf[2..], 0xE000);
         LittleEndian::write_u32(&mut buf[4..], len);
         to.write_all(&buf)?;
         Ok(())
@@ -227,7 +325,7 @@ where
     fn encode_item_delimiter(&self, to: &mut W) -> Result<()> {
         let mut buf = [0u8; 8];
         LittleEndian::write_u16(&mut buf, 0xFFFE);
-        LittleEndian::write_u16(&mut buf, 0xE00D);
+        LittleEndian::write_u16(&mut buf[2..], 0xE00D);
         to.write_all(&buf)?;
         Ok(())
     }
@@ -235,7 +333,7 @@ where
     fn encode_sequence_delimiter(&self, to: &mut W) -> Result<()> {
         let mut buf = [0u8; 8];
         LittleEndian::write_u16(&mut buf, 0xFFFE);
-        LittleEndian::write_u16(&mut buf, 0xE0DD);
+        LittleEndian::write_u16(&mut buf[2..], 0xE0DD);
         to.write_all(&buf)?;
         Ok(())
     }
@@ -387,4 +485,71 @@ mod tests {
 
         assert_eq!(&buf[..], &RAW[..]);
     }
+
+    #[test]
+    fn resolve_ambiguous_vrs_from_context() {
+        use dicom_core::PrimitiveValue;
+
+        // (0028,0106) Smallest Image Pixel Value, length 2
+        // (5400,1010) Waveform Data, length 4
+        const RAW: &[u8] = &[
+            0x28, 0x00, 0x06, 0x01, 0x02, 0x00, 0x00, 0x00, //
+            0x00, 0x54, 0x10, 0x10, 0x04, 0x00, 0x00, 0x00,
+        ];
+        let reader = ImplicitVRLittleEndianDecoder::with_std_dict();
+
+        // no context: fall back to US and OW
+        let elem = reader.decode_header(&mut &RAW[..]).unwrap();
+        assert_eq!(elem.vr(), VR::US);
+        let elem = reader.decode_header(&mut &RAW[8..]).unwrap();
+        assert_eq!(elem.vr(), VR::OW);
+
+        reader.record_value(
+            &DataElementHeader::new(Tag(0x0028, 0x0103), VR::US, Length(2)),
+            &PrimitiveValue::U16(std::iter::once(1).collect()),
+        );
+        reader.record_value(
+            &DataElementHeader::new(Tag(0x5400, 0x1004), VR::US, Length(2)),
+            &PrimitiveValue::U16(std::iter::once(8).collect()),
+        );
+        let elem = reader.decode_header(&mut &RAW[..]).unwrap();
+        assert_eq!(elem.vr(), VR::SS);
+        let elem = reader.decode_header(&mut &RAW[8..]).unwrap();
+        assert_eq!(elem.vr(), VR::OB);
+
+        // values recorded in an item only apply to that item
+        reader.enter_item();
+        let elem = reader.decode_header(&mut &RAW[..]).unwrap();
+        assert_eq!(elem.vr(), VR::SS);
+        reader.record_value(
+            &DataElementHeader::new(Tag(0x0028, 0x0103), VR::US, Length(2)),
+            &PrimitiveValue::U16(std::iter::once(0).collect()),
+        );
+        let elem = reader.decode_header(&mut &RAW[..]).unwrap();
+        assert_eq!(elem.vr(), VR::US);
+        reader.leave_item();
+        let elem = reader.decode_header(&mut &RAW[..]).unwrap();
+        assert_eq!(elem.vr(), VR::SS);
+    }
+
+    #[test]
+    fn encode_items() {
+        const RAW_SEQUENCE_ITEMS: &[u8] = &[
+            0x08, 0x00, 0x3F, 0x10, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF,
+            0xFF, 0xFF, 0xFE, 0xFF, 0x0D, 0xE0, 0x00, 0x00, 0x00, 0x00, 0xFE, 0xFF, 0xDD, 0xE0,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let enc = ImplicitVRLittleEndianEncoder::default();
+        let mut buf = Vec::new();
+        let de = DataElementHeader::new(Tag(0x0008, 0x103F), VR::SQ, Length::UNDEFINED);
+        enc.encode_element_header(&mut buf, de)
+            .expect("should write it fine");
+        enc.encode_item_header(&mut buf, Length::UNDEFINED.0)
+            .expect("should write it fine");
+        enc.encode_item_delimiter(&mut buf)
+            .expect("should write it fine");
+        enc.encode_sequence_delimiter(&mut buf)
+            .expect("should write it fine");
+        assert_eq!(&buf[..], RAW_SEQUENCE_ITEMS);
+    }
 }
@@ -18,7 +18,7 @@ use std::io::{Read, Write};
 pub use byteordered::Endianness;
 
 /// A decoder with its type erased.
-pub type DynDecoder = Box<dyn Decode<Source = dyn Read>>;
+pub type DynDecoder = Box<dyn Decode<Source = dyn Read> + Send>;
 
 /// An encoder with its type erased.
 pub type DynEncoder = Box<dyn Encode<Writer = dyn Write>>;
@@ -0,0 +1,698 @@
+//! This module implements a value conformance checker, which walks through
+//! a DICOM object and validates each element value against the rules of
+//! its value representation (PS3.5 section 6.2) and against the value
+//! multiplicity in the data dictionary.
+//!
+//! The following rules are checked:
+//!
+//! - the in-memory value type is compatible with the VR;
+//! - text values only use characters of the VR's repertoire, and only use
+//!   characters outside of the default character repertoire when
+//!   _Specific Character Set_ is defined;
+//! - text values do not exceed the VR's maximum length;
+//! - text values are padded with the right character (NUL for UI,
+//!   space otherwise);
+//! - AS, DA, DS, DT, IS, TM and UI values have a valid syntax;
+//! - the number of values is allowed by the attribute's VM, when the
+//!   dictionary provides one.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use dicom_object::open_file;
+//! # use dicom_object::conformance;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let obj = open_file("0001.dcm")?;
+//! for finding in conformance::check(&obj) {
+//!     println!("{}", finding);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::iod::PathSegment;
+use crate::mem::{InMemDicomObject, InMemElement};
+use crate::util::{is_text_vr, split_text};
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry, ValueMultiplicity};
+use dicom_core::header::Header;
+use dicom_core::value::{PrimitiveValue, Value};
+use dicom_core::{Tag, VR};
+use dicom_encoding::text::{
+    validate_cs, validate_da, validate_dt, validate_tm, TextValidationOutcome,
+};
+use std::fmt;
+
+/// The Specific Character Set attribute.
+const SPECIFIC_CHARACTER_SET: Tag = Tag(0x0008, 0x0005);
+
+/// The kind of conformance problem found in an element.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FindingKind {
+    /// The value type in memory does not match the element's VR.
+    UnexpectedValueType,
+    /// The value contains characters outside of the VR's repertoire.
+    InvalidCharacters,
+    /// The value exceeds the maximum length of the VR.
+    ValueTooLong {
+        /// The maximum length, in characters.
+        max: u32,
+        /// The actual length, in characters.
+        length: u32,
+    },
+    /// The value is padded with the wrong character.
+    InvalidPadding,
+    /// The value does not follow the syntax of the VR.
+    InvalidSyntax,
+    /// The number of values is not allowed by the attribute's VM.
+    MultiplicityMismatch {
+        /// The value multiplicity of the attribute.
+        expected: ValueMultiplicity,
+        /// The number of values found.
+        found: u32,
+    },
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FindingKind::UnexpectedValueType => f.write_str("value type does not match the VR"),
+            FindingKind::InvalidCharacters => f.write_str("invalid characters"),
+            FindingKind::ValueTooLong { max, length } => {
+                write!(f, "value too long ({} > {})", length, max)
+            }
+            FindingKind::InvalidPadding => f.write_str("invalid padding"),
+            FindingKind::InvalidSyntax => f.write_str("invalid syntax"),
+            FindingKind::MultiplicityMismatch { expected, found } => {
+                write!(f, "{} values found, VM is {}", found, expected)
+            }
+        }
+    }
+}
+
+/// A conformance problem found in an element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    /// The sequence items leading to the element,
+    /// empty if the element is in the root data set.
+    pub path: Vec<PathSegment>,
+    /// The element tag.
+    pub tag: Tag,
+    /// The element VR.
+    pub vr: VR,
+    /// The index of the offending value, if the problem is specific to
+    /// one of the values.
+    pub value_index: Option<usize>,
+    /// The kind of problem.
+    pub kind: FindingKind,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for segment in &self.path {
+            write!(f, "{}[{}].", segment.tag, segment.item)?;
+        }
+        write!(f, "{} {}", self.tag, self.vr)?;
+        if let Some(i) = self.value_index {
+            write!(f, " #{}", i)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+/// Check all elements of the given object, including those in sequence
+/// items, and return the problems found.
+pub fn check<D>(obj: &InMemDicomObject<D>) -> Vec<Finding>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    let mut findings = Vec::new();
+    check_object(obj, false, &mut Vec::new(), &mut findings);
+    findings
+}
+
+fn check_object<D>(
+    obj: &InMemDicomObject<D>,
+    extended_charset: bool,
+    path: &mut Vec<PathSegment>,
+    findings: &mut Vec<Finding>,
+) where
+    D: DataDictionary,
+    D: Clone,
+{
+    let extended_charset = match obj.element(SPECIFIC_CHARACTER_SET) {
+        Ok(e) => e
+            .value()
+            .primitive()
+            .and_then(PrimitiveValue::strings)
+            .map(|s| s.iter().any(|s| !s.trim().is_empty()))
+            .unwrap_or(false),
+        Err(_) => extended_charset,
+    };
+
+    for elem in obj {
+        let tag = elem.tag();
+        let vr = elem.vr();
+        let mut report = |value_index, kind| {
+            findings.push(Finding {
+                path: path.clone(),
+                tag,
+                vr,
+                value_index,
+                kind,
+            })
+        };
+
+        match elem.value() {
+            Value::Sequence { items, .. } => {
+                if vr != VR::SQ {
+                    report(None, FindingKind::UnexpectedValueType);
+                }
+                for (i, item) in items.iter().enumerate() {
+                    path.push(PathSegment { tag, item: i });
+                    check_object(item, extended_charset, path, findings);
+                    path.pop();
+                }
+            }
+            Value::Primitive(value) => {
+                check_value(vr, value, extended_charset, &mut report);
+                check_multiplicity(elem, obj.dict(), &mut report);
+            }
+        }
+    }
+}
+
+fn check_multiplicity<D, F>(elem: &InMemElement<D>, dict: &D, report: &mut F)
+where
+    D: DataDictionary,
+    F: FnMut(Option<usize>, FindingKind),
+{
+    match elem.vr() {
+        VR::OB | VR::OD | VR::OF | VR::OL | VR::OV | VR::OW | VR::UN | VR::SQ => return,
+        VR::LT | VR::ST | VR::UT | VR::UR => return,
+        _ => {}
+    }
+    let found = match elem.value() {
+        // a single string may hold several values
+        Value::Primitive(PrimitiveValue::Str(text)) => split_text(elem.vr(), text).len() as u32,
+        value => value.multiplicity(),
+    };
+    if found == 0 {
+        return;
+    }
+    if let Some(vm) = dict.by_tag(elem.tag()).and_then(|e| e.vm()) {
+        if !vm.contains(found) {
+            report(
+                None,
+                FindingKind::MultiplicityMismatch {
+                    expected: vm,
+                    found,
+                },
+            );
+        }
+    }
+}
+
+fn check_value<F>(vr: VR, value: &PrimitiveValue, extended_charset: bool, report: &mut F)
+where
+    F: FnMut(Option<usize>, FindingKind),
+{
+    use PrimitiveValue::*;
+
+    let type_ok = match (vr, value) {
+        (_, Empty) => true,
+        (VR::SQ, _) => false,
+        (VR::UN, _) => true,
+        (VR::US, U16(_)) | (VR::SS, I16(_)) => true,
+        (VR::UL, U32(_)) | (VR::SL, I32(_)) => true,
+        (VR::UV, U64(_)) | (VR::SV, I64(_)) => true,
+        (VR::FL, F32(_)) | (VR::FD, F64(_)) => true,
+        (VR::AT, Tags(_)) => true,
+        (VR::OB, U8(_)) => true,
+        (VR::OW, U16(_)) | (VR::OW, U8(_)) => true,
+        (VR::OF, F32(_)) | (VR::OD, F64(_)) => true,
+        (VR::OL, U32(_)) | (VR::OV, U64(_)) => true,
+        (VR::DS, F64(_)) | (VR::IS, I32(_)) => true,
+        (VR::DS, DecimalString(_)) | (VR::IS, IntegerString(_)) => true,
+        (VR::DA, Date(_)) | (VR::TM, Time(_)) | (VR::DT, DateTime(_)) => true,
+        (vr, Str(_)) | (vr, Strs(_)) => is_text_vr(vr),
+        _ => false,
+    };
+    if !type_ok {
+        report(None, FindingKind::UnexpectedValueType);
+        return;
+    }
+
+    match value {
+        Str(s) => match split_text(vr, s).as_slice() {
+            [value] => check_text(vr, value, None, extended_charset, report),
+            values => {
+                for (i, value) in values.iter().enumerate() {
+                    check_text(vr, value, Some(i), extended_charset, report);
+                }
+            }
+        },
+        Strs(c) => {
+            for (i, s) in c.iter().enumerate() {
+                check_text(vr, s, Some(i), extended_charset, report);
+            }
+        }
+        // numbers read from text are checked in their original form
+        DecimalString(c) => {
+            for (i, v) in c.iter().enumerate() {
+                check_text(vr, &v.to_string(), Some(i), extended_charset, report);
+            }
+        }
+        IntegerString(c) => {
+            for (i, v) in c.iter().enumerate() {
+                check_text(vr, &v.to_string(), Some(i), extended_charset, report);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The maximum length of a single value in characters, if limited.
+/// For PN, the limit applies to each component group.
+fn max_length(vr: VR) -> Option<u32> {
+    match vr {
+        VR::AE => Some(16),
+        VR::AS => Some(4),
+        VR::CS => Some(16),
+        VR::DA => Some(8),
+        VR::DS => Some(16),
+        VR::DT => Some(26),
+        VR::IS => Some(12),
+        VR::LO => Some(64),
+        VR::LT => Some(10240),
+        VR::PN => Some(64),
+        VR::SH => Some(16),
+        VR::ST => Some(1024),
+        VR::TM => Some(14),
+        VR::UI => Some(64),
+        _ => None,
+    }
+}
+
+fn check_text<F>(
+    vr: VR,
+    text: &str,
+    value_index: Option<usize>,
+    extended_charset: bool,
+    report: &mut F,
+) where
+    F: FnMut(Option<usize>, FindingKind),
+{
+    // padding
+    let value = if vr == VR::UI {
+        if text.ends_with(' ') {
+            report(value_index, FindingKind::InvalidPadding);
+        }
+        text.trim_end_matches(['\0', ' '])
+    } else {
+        if text.ends_with('\0') {
+            report(value_index, FindingKind::InvalidPadding);
+        }
+        text.trim_end_matches(['\0', ' '])
+    };
+
+    // length
+    if let Some(max) = max_length(vr) {
+        let length = if vr == VR::PN {
+            value
+                .split('=')
+                .map(|g| g.chars().count())
+                .max()
+                .unwrap_or(0)
+        } else {
+            value.chars().count()
+        } as u32;
+        if length > max {
+            report(value_index, FindingKind::ValueTooLong { max, length });
+        }
+    }
+
+    // repertoire
+    let valid_chars = match vr {
+        VR::AE => value
+            .bytes()
+            .all(|c| (0x20..0x7F).contains(&c) && c != b'\\'),
+        VR::AS => value
+            .bytes()
+            .all(|c| c.is_ascii_digit() || b"DWMY".contains(&c)),
+        VR::CS => validate_cs(value.as_bytes()) == TextValidationOutcome::Ok,
+        VR::DA => validate_da(value.as_bytes()) == TextValidationOutcome::Ok,
+        VR::TM => validate_tm(value.as_bytes()) == TextValidationOutcome::Ok,
+        VR::DT => validate_dt(value.as_bytes()) == TextValidationOutcome::Ok,
+        VR::DS => value
+            .bytes()
+            .all(|c| c.is_ascii_digit() || b"+-Ee. ".contains(&c)),
+        VR::IS => value
+            .bytes()
+            .all(|c| c.is_ascii_digit() || b"+- ".contains(&c)),
+        VR::UI => value.bytes().all(|c| c.is_ascii_digit() || c == b'.'),
+        VR::UR => value.bytes().all(|c| c > 0x20 && c < 0x7F),
+        VR::LT | VR::ST | VR::UT => value.chars().all(|c| {
+            (!c.is_control() || "\t\n\x0C\r\x1B".contains(c)) && (extended_charset || c.is_ascii())
+        }),
+        _ => value
+            .chars()
+            .all(|c| (!c.is_control() || c == '\x1B') && (extended_charset || c.is_ascii())),
+    };
+    if !valid_chars {
+        report(value_index, FindingKind::InvalidCharacters);
+        return;
+    }
+
+    // syntax
+    let valid_syntax = match vr {
+        VR::AS => is_valid_age(value),
+        VR::DA => is_valid_date(value),
+        VR::TM => is_valid_time(value),
+        VR::DT => is_valid_datetime(value),
+        VR::DS => value.trim().is_empty() || value.trim().parse::<f64>().is_ok(),
+        VR::IS => value.trim().is_empty() || value.trim().parse::<i32>().is_ok(),
+        VR::UI => is_valid_uid(value),
+        _ => true,
+    };
+    if !valid_syntax {
+        report(value_index, FindingKind::InvalidSyntax);
+    }
+}
+
+fn all_digits(s: &str) -> bool {
+    s.bytes().all(|c| c.is_ascii_digit())
+}
+
+/// Parse a fixed-width number made of ASCII digits.
+fn number(s: &str) -> Option<u32> {
+    if s.is_empty() || !all_digits(s) {
+        None
+    } else {
+        s.parse().ok()
+    }
+}
+
+fn is_valid_age(value: &str) -> bool {
+    value.is_empty()
+        || (value.len() == 4
+            && all_digits(&value[..3])
+            && matches!(&value[3..], "D" | "W" | "M" | "Y"))
+}
+
+fn is_valid_date(value: &str) -> bool {
+    if value.is_empty() {
+        return true;
+    }
+    value.len() == 8 && chrono::NaiveDate::parse_from_str(value, "%Y%m%d").is_ok()
+}
+
+/// Check the syntax `HH[MM[SS[.F{1-6}]]]`.
+fn is_valid_time(value: &str) -> bool {
+    if value.is_empty() {
+        return true;
+    }
+    let (main, frac) = match value.find('.') {
+        Some(i) => (&value[..i], Some(&value[i + 1..])),
+        None => (value, None),
+    };
+    if let Some(frac) = frac {
+        if main.len() != 6 || frac.is_empty() || frac.len() > 6 || !all_digits(frac) {
+            return false;
+        }
+    }
+    if main.len() % 2 != 0 || main.len() > 6 {
+        return false;
+    }
+    let limits = [23, 59, 60];
+    main.as_bytes()
+        .chunks(2)
+        .zip(&limits)
+        .all(|(part, &limit)| {
+            std::str::from_utf8(part)
+                .ok()
+                .and_then(number)
+                .map(|n| n <= limit)
+                .unwrap_or(false)
+        })
+}
+
+/// Check the syntax `YYYY[MM[DD[HH[MM[SS[.F{1-6}]]]]]][&ZZXX]`.
+fn is_valid_datetime(value: &str) -> bool {
+    if value.is_empty() {
+        return true;
+    }
+    let (main, offset) = match value.rfind(['+', '-']) {
+        Some(i) => (&value[..i], Some(&value[i + 1..])),
+        None => (value, None),
+    };
+    if let Some(offset) = offset {
+        let valid_offset = offset.len() == 4
+            && number(&offset[..2]).map(|h| h <= 14).unwrap_or(false)
+            && number(&offset[2..]).map(|m| m <= 59).unwrap_or(false);
+        if !valid_offset {
+            return false;
+        }
+    }
+    let date_len = main.find('.').unwrap_or(main.len()).min(8);
+    let (date, time) = main.split_at(date_len);
+    if date.len() < 4 || date.len() % 2 != 0 || !all_digits(date) {
+        return false;
+    }
+    if date.len() >= 6 {
+        let month = number(&date[4..6]).unwrap_or(0);
+        if !(1..=12).contains(&month) {
+            return false;
+        }
+    }
+    if date.len() == 8 && chrono::NaiveDate::parse_from_str(date, "%Y%m%d").is_err() {
+        return false;
+    }
+    if time.is_empty() {
+        return true;
+    }
+    date.len() == 8 && is_valid_time(time)
+}
+
+/// Check the syntax of a UID: numeric components separated by periods,
+/// without leading zeros.
+fn is_valid_uid(value: &str) -> bool {
+    value.is_empty()
+        || value.split('.').all(|component| {
+            !component.is_empty()
+                && all_digits(component)
+                && (component == "0" || !component.starts_with('0'))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::put_strs;
+    use dicom_core::DataElement;
+    use dicom_dictionary_std::StandardDataDictionary;
+    use smallvec::smallvec;
+
+    type Obj = InMemDicomObject<StandardDataDictionary>;
+
+    #[test]
+    fn conformant_object_has_no_findings() {
+        let mut obj = Obj::create_empty();
+        put_strs(
+            &mut obj,
+            Tag(0x0008, 0x0018),
+            VR::UI,
+            &["1.2.840.10008.1.2"],
+        );
+        put_strs(&mut obj, Tag(0x0008, 0x0020), VR::DA, &["20190228"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0030), VR::TM, &["235959.123456 "]);
+        put_strs(
+            &mut obj,
+            Tag(0x0008, 0x002A),
+            VR::DT,
+            &["20190228120000+0100"],
+        );
+        put_strs(&mut obj, Tag(0x0008, 0x0060), VR::CS, &["CT"]);
+        put_strs(&mut obj, Tag(0x0010, 0x1010), VR::AS, &["034Y"]);
+        put_strs(&mut obj, Tag(0x0028, 0x0030), VR::DS, &["0.5", "-1e-3"]);
+        put_strs(&mut obj, Tag(0x0020, 0x0013), VR::IS, &[" 12 "]);
+        obj.put(DataElement::new(
+            Tag(0x0028, 0x0010),
+            VR::US,
+            PrimitiveValue::U16(smallvec![512]).into(),
+        ));
+
+        assert_eq!(check(&obj), vec![]);
+    }
+
+    #[test]
+    fn bad_values_are_reported() {
+        let mut obj = Obj::create_empty();
+        put_strs(&mut obj, Tag(0x0008, 0x0018), VR::UI, &["1.02.3 "]);
+        put_strs(&mut obj, Tag(0x0008, 0x0020), VR::DA, &["20190230"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0030), VR::TM, &["12:00"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0060), VR::CS, &["ct"]);
+        put_strs(&mut obj, Tag(0x0010, 0x0010), VR::PN, &["Müller^Hans"]);
+        put_strs(&mut obj, Tag(0x0010, 0x0020), VR::LO, &["ID\0"]);
+        put_strs(
+            &mut obj,
+            Tag(0x0020, 0x0010),
+            VR::SH,
+            &["THIS ID IS TOO LONG"],
+        );
+        obj.put(DataElement::new(
+            Tag(0x0028, 0x0010),
+            VR::US,
+            PrimitiveValue::Strs(smallvec!["512".to_string()]).into(),
+        ));
+
+        let findings: Vec<_> = check(&obj)
+            .into_iter()
+            .map(|f| (f.tag, f.value_index, f.kind))
+            .collect();
+        assert_eq!(
+            findings,
+            vec![
+                (Tag(0x0008, 0x0018), Some(0), FindingKind::InvalidPadding),
+                (Tag(0x0008, 0x0018), Some(0), FindingKind::InvalidSyntax),
+                (Tag(0x0008, 0x0020), Some(0), FindingKind::InvalidSyntax),
+                (Tag(0x0008, 0x0030), Some(0), FindingKind::InvalidCharacters),
+                (Tag(0x0008, 0x0060), Some(0), FindingKind::InvalidCharacters),
+                (Tag(0x0010, 0x0010), Some(0), FindingKind::InvalidCharacters),
+                (Tag(0x0010, 0x0020), Some(0), FindingKind::InvalidPadding),
+                (
+                    Tag(0x0020, 0x0010),
+                    Some(0),
+                    FindingKind::ValueTooLong {
+                        max: 16,
+                        length: 19
+                    }
+                ),
+                (Tag(0x0028, 0x0010), None, FindingKind::UnexpectedValueType),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_character_set_in_items() {
+        let mut item = Obj::create_empty();
+        put_strs(&mut item, Tag(0x0010, 0x0010), VR::PN, &["Müller^Hans"]);
+        let mut obj = Obj::create_empty();
+        put_strs(&mut obj, Tag(0x0008, 0x0005), VR::CS, &["ISO_IR 100"]);
+        obj.put(DataElement::new(
+            Tag(0x0008, 0x1115),
+            VR::SQ,
+            Value::Sequence {
+                items: smallvec![item.clone()],
+                size: dicom_core::Length::UNDEFINED,
+            },
+        ));
+        assert_eq!(check(&obj), vec![]);
+
+        obj.put(DataElement::empty(Tag(0x0008, 0x0005), VR::CS));
+        let findings = check(&obj);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].to_string(),
+            "(0008,1115)[0].(0010,0010) PN #0: invalid characters"
+        );
+    }
+
+    #[test]
+    fn backslashes_in_single_string_separate_values() {
+        let mut obj = Obj::create_empty();
+        obj.put(DataElement::new(
+            Tag(0x0008, 0x0008),
+            VR::CS,
+            PrimitiveValue::Str("ORIGINAL\\PRIMARY\\AXIAL".to_string()).into(),
+        ));
+        // backslashes are text in VRs of a single value
+        obj.put(DataElement::new(
+            Tag(0x4000, 0x4000),
+            VR::LT,
+            PrimitiveValue::Str("C:\\path".to_string()).into(),
+        ));
+        assert_eq!(check(&obj), vec![]);
+
+        obj.put(DataElement::new(
+            Tag(0x0008, 0x0008),
+            VR::CS,
+            PrimitiveValue::Str("ORIGINAL\\A_VERY_LONG_VALUE".to_string()).into(),
+        ));
+        let findings = check(&obj);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].value_index, Some(1));
+
+        let mut obj = Obj::create_empty();
+        obj.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::Str("0.5\\0.5".to_string()).into(),
+        ));
+        assert_eq!(check(&obj), vec![]);
+    }
+
+    #[test]
+    fn multiplicity_is_checked_against_dictionary() {
+        let mut obj = Obj::create_empty();
+        obj.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::F64(smallvec![0.5, 0.5, 0.5]).into(),
+        ));
+        put_strs(&mut obj, Tag(0x0008, 0x0008), VR::CS, &["ORIGINAL"]);
+        let findings = check(&obj);
+        assert_eq!(
+            findings.iter().map(|f| f.kind).collect::<Vec<_>>(),
+            vec![
+                FindingKind::MultiplicityMismatch {
+                    expected: ValueMultiplicity {
+                        min: 2,
+                        max: None,
+                        step: 1
+                    },
+                    found: 1,
+                },
+                FindingKind::MultiplicityMismatch {
+                    expected: ValueMultiplicity {
+                        min: 2,
+                        max: Some(2),
+                        step: 1
+                    },
+                    found: 3,
+                },
+            ]
+        );
+        assert_eq!(
+            findings[1].to_string(),
+            "(0028,0030) DS: 3 values found, VM is 2"
+        );
+
+        obj.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::F64(smallvec![0.5, 0.5]).into(),
+        ));
+        put_strs(
+            &mut obj,
+            Tag(0x0008, 0x0008),
+            VR::CS,
+            &["ORIGINAL", "PRIMARY"],
+        );
+        assert_eq!(check(&obj), vec![]);
+    }
+
+    #[test]
+    fn syntax_helpers() {
+        assert!(is_valid_time("07"));
+        assert!(is_valid_time("0700"));
+        assert!(is_valid_time("070059.5"));
+        assert!(!is_valid_time("0700.5"));
+        assert!(!is_valid_time("2400"));
+        assert!(is_valid_datetime("2019"));
+        assert!(is_valid_datetime("201902281200-0500"));
+        assert!(!is_valid_datetime("201913"));
+        assert!(!is_valid_datetime("2019+01"));
+        assert!(is_valid_uid("1.2.0.3"));
+        assert!(!is_valid_uid("1..2"));
+    }
+}
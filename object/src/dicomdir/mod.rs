@@ -0,0 +1,619 @@
+//! Reading and writing of DICOMDIR files.
+//!
+//! A DICOMDIR describes the contents of a DICOM file-set through a flat
+//! Directory Record Sequence `(0004,1220)`. The hierarchy of the records
+//! (e.g. PATIENT → STUDY → SERIES → IMAGE) is encoded with byte offsets:
+//! each record may point to the next record at the same level
+//! `(0004,1400)` and to the first record of the level below `(0004,1420)`.
+//! These offsets are relative to the first byte of the file, preamble
+//! included. This module reads the file while keeping track of where each
+//! record starts, and resolves the offsets into a tree of
+//! [`DirectoryRecord`]s.
+//!
+//! New file-sets can be created with a [`FileSetBuilder`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use dicom_object::dicomdir::DicomDir;
+//! # fn foo() -> dicom_object::dicomdir::Result<()> {
+//! let dicomdir = DicomDir::open("DICOMDIR")?;
+//! for patient in dicomdir.records() {
+//!     for study in patient.children() {
+//!         for series in study.children() {
+//!             for image in series.children() {
+//!                 println!("{:?}", image.referenced_file_path());
+//!             }
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::mem::{InMemDicomObject, InMemElement};
+use crate::meta::FileMetaTable;
+use crate::RootDicomObject;
+use dicom_core::value::{Value, C};
+use dicom_core::{DataElement, Tag, VR};
+use dicom_dictionary_std::StandardDataDictionary;
+use dicom_encoding::text::SpecificCharacterSet;
+use dicom_parser::dataset::{DataSetReader, DataToken};
+use dicom_parser::error::DataSetSyntaxError;
+use dicom_transfer_syntax_registry::get_registry;
+use quick_error::quick_error;
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+mod write;
+
+pub use self::write::FileSetBuilder;
+
+/// Offset of the First Directory Record of the Root Directory Entity.
+const OFFSET_OF_FIRST_RECORD: Tag = Tag(0x0004, 0x1200);
+/// The Directory Record Sequence.
+const DIRECTORY_RECORD_SEQUENCE: Tag = Tag(0x0004, 0x1220);
+/// Offset of the Next Directory Record.
+const OFFSET_OF_NEXT_RECORD: Tag = Tag(0x0004, 0x1400);
+/// Record In-use Flag.
+const RECORD_IN_USE_FLAG: Tag = Tag(0x0004, 0x1410);
+/// Offset of Referenced Lower-Level Directory Entity.
+const OFFSET_OF_LOWER_LEVEL: Tag = Tag(0x0004, 0x1420);
+/// Directory Record Type.
+const DIRECTORY_RECORD_TYPE: Tag = Tag(0x0004, 0x1430);
+/// Referenced File ID.
+const REFERENCED_FILE_ID: Tag = Tag(0x0004, 0x1500);
+/// File-set ID.
+const FILE_SET_ID: Tag = Tag(0x0004, 0x1130);
+
+/// The size of the file preamble, which is included in record offsets.
+const PREAMBLE_LENGTH: u64 = 128;
+
+quick_error! {
+    /// An error which may occur when reading a DICOMDIR.
+    #[derive(Debug)]
+    pub enum Error {
+        /// The file could not be parsed as a DICOM file.
+        Parse(err: dicom_parser::error::Error) {
+            description("failed to parse DICOMDIR")
+            from()
+            display("failed to parse DICOMDIR: {}", err)
+        }
+        /// The file could not be read.
+        Io(err: io::Error) {
+            description("I/O error")
+            from()
+            display("I/O error: {}", err)
+        }
+        /// The DICOMDIR could not be encoded.
+        Encode(err: dicom_encoding::error::Error) {
+            description("failed to encode DICOMDIR")
+            from()
+            display("failed to encode DICOMDIR: {}", err)
+        }
+        /// An instance to be added to a file-set could not be read.
+        ReadInstance(path: PathBuf, err: dicom_parser::error::Error) {
+            description("failed to read DICOM instance")
+            display("failed to read DICOM instance {}: {}", path.display(), err)
+        }
+        /// An instance to be added to a file-set lacks a required attribute.
+        MissingAttribute(path: PathBuf, tag: Tag) {
+            description("missing required attribute")
+            display("DICOM instance {} is missing attribute {}", path.display(), tag)
+        }
+        /// The File-set ID is not a valid code string of up to 16 characters.
+        InvalidFileSetId(id: String) {
+            description("invalid File-set ID")
+            display("invalid File-set ID {:?}", id)
+        }
+        /// The data set has no Directory Record Sequence.
+        MissingRecordSequence {
+            description("missing Directory Record Sequence")
+            display("missing Directory Record Sequence (0004,1220)")
+        }
+        /// An offset does not point to the start of a directory record.
+        InvalidOffset(offset: u32) {
+            description("invalid directory record offset")
+            display("invalid directory record offset {}", offset)
+        }
+        /// A directory record is referenced more than once,
+        /// which would make the hierarchy ill-formed.
+        CyclicReference(offset: u32) {
+            description("directory record referenced more than once")
+            display("directory record at offset {} referenced more than once", offset)
+        }
+    }
+}
+
+/// Type alias for a result from this module.
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+/// The object type of DICOMDIR data sets and directory records.
+pub type DicomDirObject = InMemDicomObject<StandardDataDictionary>;
+
+/// A DICOMDIR file, with its directory records resolved into a tree.
+#[derive(Debug, Clone)]
+pub struct DicomDir {
+    /// the full data set, including the directory record sequence
+    obj: RootDicomObject<DicomDirObject>,
+    /// the records of the root directory entity
+    records: Vec<DirectoryRecord>,
+    /// the directory in which the file-set resides
+    base_dir: PathBuf,
+}
+
+impl DicomDir {
+    /// Read a DICOMDIR file.
+    ///
+    /// Referenced file IDs are resolved relative to the directory
+    /// containing the file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut file = BufReader::new(File::open(path)?);
+
+        // skip the preamble
+        let mut buf = [0u8; PREAMBLE_LENGTH as usize];
+        file.read_exact(&mut buf)?;
+
+        let base_dir = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(PathBuf::new);
+        Self::from_reader(file, base_dir)
+    }
+
+    /// Read a DICOMDIR from a byte source.
+    ///
+    /// The source is expected to be positioned right after the 128-byte
+    /// preamble, so that it starts with the file meta group. Referenced
+    /// file IDs are resolved relative to `base_dir`.
+    pub fn from_reader<S, P>(mut src: S, base_dir: P) -> Result<Self>
+    where
+        S: Read + 'static,
+        P: Into<PathBuf>,
+    {
+        let meta = FileMetaTable::from_reader(&mut src)?;
+        // the magic code, the group length element and the rest of the group
+        let data_set_start = PREAMBLE_LENGTH + 4 + 12 + u64::from(meta.information_group_length);
+        let ts = get_registry()
+            .get(&meta.transfer_syntax)
+            .ok_or(dicom_parser::error::Error::UnsupportedTransferSyntax)?;
+        let cs = SpecificCharacterSet::Default;
+        let dict = StandardDataDictionary;
+        let mut dataset = DataSetReader::new_with_dictionary(src, dict, ts, cs)?;
+
+        let mut obj = InMemDicomObject::new_empty_with_dict(dict);
+        let mut items: Option<(Vec<u32>, C<DicomDirObject>)> = None;
+
+        while let Some(token) = dataset.next() {
+            let elem = match token? {
+                DataToken::ElementHeader(header) => {
+                    let next_token = dataset
+                        .next()
+                        .ok_or(dicom_parser::error::Error::MissingElementValue)?;
+                    match next_token? {
+                        DataToken::PrimitiveValue(v) => {
+                            InMemElement::new(header.tag, header.vr, Value::Primitive(v))
+                        }
+                        token => return Err(unexpected(token)),
+                    }
+                }
+                DataToken::SequenceStart { tag, len } if tag == DIRECTORY_RECORD_SEQUENCE => {
+                    let (offsets, records) = read_records(&mut dataset, data_set_start)?;
+                    let elem = DataElement::new(
+                        tag,
+                        VR::SQ,
+                        Value::Sequence {
+                            items: records.clone(),
+                            size: len,
+                        },
+                    );
+                    items = Some((offsets, records));
+                    elem
+                }
+                DataToken::SequenceStart { tag, len } => {
+                    let items = InMemDicomObject::build_sequence(tag, len, &mut dataset, &dict)?;
+                    DataElement::new(tag, VR::SQ, Value::Sequence { items, size: len })
+                }
+                token => return Err(unexpected(token)),
+            };
+            obj.put(elem);
+        }
+
+        let (offsets, records) = items.ok_or(Error::MissingRecordSequence)?;
+        let mut by_offset: HashMap<u32, DicomDirObject> =
+            offsets.into_iter().zip(records).collect();
+
+        let first = obj
+            .element(OFFSET_OF_FIRST_RECORD)
+            .ok()
+            .and_then(|e| e.value().primitive())
+            .and_then(|v| v.uint32())
+            .unwrap_or(0);
+
+        let base_dir = base_dir.into();
+        let mut visited = HashSet::new();
+        let records = resolve_level(first, &mut by_offset, &mut visited, &base_dir)?;
+
+        Ok(DicomDir {
+            obj: RootDicomObject {
+                meta,
+                obj,
+                warnings: Vec::new(),
+            },
+            records,
+            base_dir,
+        })
+    }
+
+    /// Retrieve the file meta table of the DICOMDIR.
+    pub fn meta(&self) -> &FileMetaTable {
+        self.obj.meta()
+    }
+
+    /// Retrieve the full DICOMDIR data set.
+    pub fn object(&self) -> &RootDicomObject<DicomDirObject> {
+        &self.obj
+    }
+
+    /// Retrieve the File-set ID, if present and not empty.
+    pub fn file_set_id(&self) -> Option<String> {
+        string_of(&self.obj, FILE_SET_ID)
+    }
+
+    /// Retrieve the records of the root directory entity,
+    /// usually of type PATIENT.
+    pub fn records(&self) -> &[DirectoryRecord] {
+        &self.records
+    }
+
+    /// Retrieve the directory in which the file-set resides.
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+}
+
+/// A directory record of a DICOMDIR, with its lower-level records.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectoryRecord {
+    offset: u32,
+    obj: DicomDirObject,
+    children: Vec<DirectoryRecord>,
+    file_path: Option<PathBuf>,
+}
+
+impl DirectoryRecord {
+    /// Retrieve the byte offset of this record in the DICOMDIR file.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Retrieve the Directory Record Type (e.g. `"PATIENT"`, `"IMAGE"`),
+    /// without padding.
+    pub fn record_type(&self) -> Option<String> {
+        string_of(&self.obj, DIRECTORY_RECORD_TYPE)
+    }
+
+    /// Check whether the record is in use. Records without a
+    /// Record In-use Flag are considered in use.
+    pub fn in_use(&self) -> bool {
+        self.obj
+            .element(RECORD_IN_USE_FLAG)
+            .ok()
+            .and_then(|e| e.value().primitive())
+            .and_then(|v| v.uint16())
+            .map(|flag| flag != 0)
+            .unwrap_or(true)
+    }
+
+    /// Retrieve the directory record's data set.
+    pub fn object(&self) -> &DicomDirObject {
+        &self.obj
+    }
+
+    /// Retrieve the records of the lower-level directory entity.
+    pub fn children(&self) -> &[DirectoryRecord] {
+        &self.children
+    }
+
+    /// Retrieve the components of the Referenced File ID, if any.
+    pub fn referenced_file_id(&self) -> Option<Vec<String>> {
+        file_id_of(&self.obj)
+    }
+
+    /// Retrieve the path of the referenced file, resolved against the
+    /// directory of the file-set.
+    pub fn referenced_file_path(&self) -> Option<&Path> {
+        self.file_path.as_deref()
+    }
+}
+
+/// Read the items of the directory record sequence, along with the offset
+/// at which each of them starts, given the offset of the data set.
+fn read_records<S, P>(
+    dataset: &mut DataSetReader<S, P, StandardDataDictionary>,
+    data_set_start: u64,
+) -> Result<(Vec<u32>, C<DicomDirObject>)>
+where
+    S: Read + 'static,
+    P: dicom_parser::parser::Parse<dyn Read>,
+{
+    let mut offsets = Vec::new();
+    let mut items: C<_> = SmallVec::new();
+    while let Some(token) = dataset.next() {
+        match token? {
+            DataToken::ItemStart { len } => {
+                // the item header was just read
+                offsets.push((data_set_start + DataSetReader::position(dataset) - 8) as u32);
+                items.push(InMemDicomObject::build_object(
+                    &mut *dataset,
+                    StandardDataDictionary,
+                    true,
+                    len,
+                )?);
+            }
+            DataToken::SequenceEnd => return Ok((offsets, items)),
+            token => return Err(unexpected(token)),
+        }
+    }
+    Err(dicom_parser::error::Error::from(DataSetSyntaxError::PrematureEnd).into())
+}
+
+/// Collect the records of one directory entity, starting at the given
+/// offset and following the next record offsets.
+fn resolve_level(
+    mut offset: u32,
+    by_offset: &mut HashMap<u32, DicomDirObject>,
+    visited: &mut HashSet<u32>,
+    base_dir: &Path,
+) -> Result<Vec<DirectoryRecord>> {
+    let mut records = Vec::new();
+    while offset != 0 {
+        if !visited.insert(offset) {
+            return Err(Error::CyclicReference(offset));
+        }
+        let obj = by_offset
+            .remove(&offset)
+            .ok_or(Error::InvalidOffset(offset))?;
+        let next = offset_of(&obj, OFFSET_OF_NEXT_RECORD);
+        let lower = offset_of(&obj, OFFSET_OF_LOWER_LEVEL);
+        let children = resolve_level(lower, by_offset, visited, base_dir)?;
+        let file_path = file_id_of(&obj).map(|id| {
+            id.iter()
+                .fold(base_dir.to_path_buf(), |path, part| path.join(part))
+        });
+        records.push(DirectoryRecord {
+            offset,
+            obj,
+            children,
+            file_path,
+        });
+        offset = next;
+    }
+    Ok(records)
+}
+
+fn offset_of(obj: &DicomDirObject, tag: Tag) -> u32 {
+    obj.element(tag)
+        .ok()
+        .and_then(|e| e.value().primitive())
+        .and_then(|v| v.uint32())
+        .unwrap_or(0)
+}
+
+fn string_of(obj: &DicomDirObject, tag: Tag) -> Option<String> {
+    obj.element(tag)
+        .ok()
+        .and_then(|e| e.value().primitive())
+        .and_then(|v| v.string())
+        .map(|s| s.trim_end_matches([' ', '\0']).to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn file_id_of(obj: &DicomDirObject) -> Option<Vec<String>> {
+    let parts: Vec<String> = obj
+        .element(REFERENCED_FILE_ID)
+        .ok()
+        .and_then(|e| e.value().primitive())
+        .and_then(|v| v.strings())?
+        .into_iter()
+        .map(|s| s.trim_matches([' ', '\0']).to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts)
+    }
+}
+
+fn unexpected(token: DataToken) -> Error {
+    dicom_parser::error::Error::from(DataSetSyntaxError::UnexpectedToken(token)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const EXPLICIT_VR_LE: &str = "1.2.840.10008.1.2.1\0";
+    const MEDIA_STORAGE_DIRECTORY: &str = "1.2.840.10008.1.3.10";
+
+    fn element(group: u16, elem: u16, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&group.to_le_bytes());
+        out.extend_from_slice(&elem.to_le_bytes());
+        out.extend_from_slice(vr);
+        match vr {
+            b"OB" | b"OW" | b"SQ" | b"UN" | b"UT" => {
+                out.extend_from_slice(&[0, 0]);
+                out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            }
+            _ => out.extend_from_slice(&(value.len() as u16).to_le_bytes()),
+        }
+        out.extend_from_slice(value);
+        out
+    }
+
+    fn padded(s: &str, pad: u8) -> Vec<u8> {
+        let mut v = s.as_bytes().to_vec();
+        if v.len() % 2 == 1 {
+            v.push(pad);
+        }
+        v
+    }
+
+    fn meta_group() -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend(element(0x0002, 0x0001, b"OB", &[0, 1]));
+        body.extend(element(
+            0x0002,
+            0x0002,
+            b"UI",
+            &padded(MEDIA_STORAGE_DIRECTORY, 0),
+        ));
+        body.extend(element(0x0002, 0x0003, b"UI", &padded("1.2.3.4", 0)));
+        body.extend(element(0x0002, 0x0010, b"UI", EXPLICIT_VR_LE.as_bytes()));
+        body.extend(element(0x0002, 0x0012, b"UI", &padded("1.2.345", 0)));
+        let mut out = b"DICM".to_vec();
+        out.extend(element(
+            0x0002,
+            0x0000,
+            b"UL",
+            &(body.len() as u32).to_le_bytes(),
+        ));
+        out.extend(body);
+        out
+    }
+
+    /// Encode a directory record item with the given offsets,
+    /// with a defined length or with an item delimiter.
+    fn record(defined: bool, kind: &str, next: u32, lower: u32, file_id: Option<&str>) -> Vec<u8> {
+        let mut body = element(0x0004, 0x1400, b"UL", &next.to_le_bytes());
+        body.extend(element(0x0004, 0x1410, b"US", &0xFFFFu16.to_le_bytes()));
+        body.extend(element(0x0004, 0x1420, b"UL", &lower.to_le_bytes()));
+        body.extend(element(0x0004, 0x1430, b"CS", &padded(kind, b' ')));
+        if let Some(id) = file_id {
+            body.extend(element(0x0004, 0x1500, b"CS", &padded(id, b' ')));
+        }
+        let mut out = vec![0xFE, 0xFF, 0x00, 0xE0];
+        if defined {
+            out.extend_from_slice(&(body.len() as u32).to_le_bytes());
+            out.extend(body);
+        } else {
+            out.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+            out.extend(body);
+            out.extend_from_slice(&[0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0]);
+        }
+        out
+    }
+
+    /// Build a DICOMDIR (without preamble) with one patient, one study,
+    /// one series and two images, where the records are not stored
+    /// in hierarchical order.
+    fn dicomdir_bytes(defined: bool) -> Vec<u8> {
+        let record = |kind, next, lower, file_id| record(defined, kind, next, lower, file_id);
+        let meta = meta_group();
+        let header = |first: u32| {
+            let mut out = element(0x0004, 0x1130, b"CS", &padded("TESTSET", b' '));
+            out.extend(element(0x0004, 0x1200, b"UL", &first.to_le_bytes()));
+            out.extend(element(0x0004, 0x1202, b"UL", &first.to_le_bytes()));
+            out.extend(element(0x0004, 0x1212, b"US", &0u16.to_le_bytes()));
+            out.extend_from_slice(&[0x04, 0x00, 0x20, 0x12]);
+            out.extend_from_slice(b"SQ\0\0\xFF\xFF\xFF\xFF");
+            out
+        };
+        let start = PREAMBLE_LENGTH as u32 + meta.len() as u32 + header(0).len() as u32;
+
+        // storage order: IMAGE 2, PATIENT, IMAGE 1, STUDY, SERIES
+        let sizes = [
+            record("IMAGE", 0, 0, Some("A\\B\\IM2")).len() as u32,
+            record("PATIENT", 0, 0, None).len() as u32,
+            record("IMAGE", 0, 0, Some("A\\B\\IM1")).len() as u32,
+            record("STUDY", 0, 0, None).len() as u32,
+        ];
+        let image2 = start;
+        let patient = image2 + sizes[0];
+        let image1 = patient + sizes[1];
+        let study = image1 + sizes[2];
+        let series = study + sizes[3];
+
+        let mut out = meta;
+        out.extend(header(patient));
+        out.extend(record("IMAGE", 0, 0, Some("A\\B\\IM2")));
+        out.extend(record("PATIENT", 0, study, None));
+        out.extend(record("IMAGE", image2, 0, Some("A\\B\\IM1")));
+        out.extend(record("STUDY", 0, series, None));
+        out.extend(record("SERIES", 0, image1, None));
+        out.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+        out
+    }
+
+    #[test]
+    fn read_record_hierarchy() {
+        for &defined in &[false, true] {
+            check_record_hierarchy(dicomdir_bytes(defined));
+        }
+    }
+
+    fn check_record_hierarchy(bytes: Vec<u8>) {
+        let dicomdir = DicomDir::from_reader(Cursor::new(bytes), "/data").unwrap();
+        assert_eq!(
+            dicomdir.meta().media_storage_sop_class_uid,
+            MEDIA_STORAGE_DIRECTORY
+        );
+        assert_eq!(dicomdir.file_set_id().as_deref(), Some("TESTSET"));
+
+        let patients = dicomdir.records();
+        assert_eq!(patients.len(), 1);
+        assert_eq!(patients[0].record_type().as_deref(), Some("PATIENT"));
+        assert!(patients[0].in_use());
+        assert_eq!(patients[0].referenced_file_id(), None);
+
+        let studies = patients[0].children();
+        assert_eq!(studies.len(), 1);
+        assert_eq!(studies[0].record_type().as_deref(), Some("STUDY"));
+        let series = studies[0].children();
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].record_type().as_deref(), Some("SERIES"));
+
+        let images = series[0].children();
+        assert_eq!(images.len(), 2);
+        assert_eq!(
+            images[0].referenced_file_id(),
+            Some(vec!["A".to_string(), "B".to_string(), "IM1".to_string()])
+        );
+        assert_eq!(
+            images[0].referenced_file_path(),
+            Some(Path::new("/data/A/B/IM1"))
+        );
+        assert_eq!(
+            images[1].referenced_file_path(),
+            Some(Path::new("/data/A/B/IM2"))
+        );
+        assert!(images.iter().all(|r| r.children().is_empty()));
+
+        // the whole sequence is kept in the data set
+        let seq = dicomdir
+            .object()
+            .element(DIRECTORY_RECORD_SEQUENCE)
+            .unwrap();
+        assert_eq!(seq.value().multiplicity(), 5);
+    }
+
+    #[test]
+    fn invalid_offset_is_an_error() {
+        let mut bytes = dicomdir_bytes(false);
+        // corrupt the offset of the first record
+        let meta_len = meta_group().len();
+        let pos = meta_len + element(0x0004, 0x1130, b"CS", b"TESTSET ").len() + 8;
+        bytes[pos..pos + 4].copy_from_slice(&3u32.to_le_bytes());
+        match DicomDir::from_reader(Cursor::new(bytes), "") {
+            Err(Error::InvalidOffset(3)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,970 @@
+//! Creation of DICOM file-sets.
+//!
+//! A file-set is built from a collection of DICOM files: each file is copied
+//! into the output directory under a generated file ID, and a DICOMDIR
+//! describing the PATIENT → STUDY → SERIES → instance hierarchy is written
+//! next to them.
+
+use super::{
+    DicomDir, DicomDirObject, Error, Result, DIRECTORY_RECORD_SEQUENCE, DIRECTORY_RECORD_TYPE,
+    FILE_SET_ID, OFFSET_OF_FIRST_RECORD, OFFSET_OF_LOWER_LEVEL, OFFSET_OF_NEXT_RECORD,
+    PREAMBLE_LENGTH, RECORD_IN_USE_FLAG, REFERENCED_FILE_ID,
+};
+use crate::mem::{InMemDicomObject, InMemElement};
+use crate::open_file;
+use crate::util::{is_text_vr, value_to_bytes, value_to_strings};
+use dicom_core::header::DataElementHeader;
+use dicom_core::value::{PrimitiveValue, Value};
+use dicom_core::{Length, Tag, VR};
+use dicom_dictionary_std::uids::{EXPLICIT_VR_LITTLE_ENDIAN, MEDIA_STORAGE_DIRECTORY_STORAGE};
+use dicom_dictionary_std::StandardDataDictionary;
+use dicom_encoding::encode::Encode;
+use dicom_encoding::text::{DefaultCharacterSetCodec, SpecificCharacterSet, TextCodec};
+use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianEncoder;
+use smallvec::smallvec;
+use std::collections::hash_map::DefaultHasher;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// The implementation class UID written to generated DICOMDIR files.
+const IMPLEMENTATION_CLASS_UID: &str = "2.25.262086406829110419931297894772577063974";
+/// The implementation version name written to generated DICOMDIR files.
+const IMPLEMENTATION_VERSION_NAME: &str = "DICOM-RS 0.1";
+
+/// The name of the DICOMDIR file in the file-set.
+const DICOMDIR_FILE_NAME: &str = "DICOMDIR";
+/// Offset of the Last Directory Record of the Root Directory Entity.
+const OFFSET_OF_LAST_RECORD: Tag = Tag(0x0004, 0x1202);
+/// File-set Consistency Flag.
+const FILE_SET_CONSISTENCY_FLAG: Tag = Tag(0x0004, 0x1212);
+/// Referenced SOP Class UID in File.
+const REFERENCED_SOP_CLASS_UID: Tag = Tag(0x0004, 0x1510);
+/// Referenced SOP Instance UID in File.
+const REFERENCED_SOP_INSTANCE_UID: Tag = Tag(0x0004, 0x1511);
+/// Referenced Transfer Syntax UID in File.
+const REFERENCED_TRANSFER_SYNTAX_UID: Tag = Tag(0x0004, 0x1512);
+
+const SPECIFIC_CHARACTER_SET: Tag = Tag(0x0008, 0x0005);
+const PATIENT_ID: Tag = Tag(0x0010, 0x0020);
+const STUDY_INSTANCE_UID: Tag = Tag(0x0020, 0x000D);
+const SERIES_INSTANCE_UID: Tag = Tag(0x0020, 0x000E);
+
+/// Keys of the PATIENT directory record.
+static PATIENT_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0010, 0x0010), VR::PN), // Patient's Name
+    (PATIENT_ID, VR::LO),
+];
+
+/// Keys of the STUDY directory record.
+static STUDY_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0020), VR::DA), // Study Date
+    (Tag(0x0008, 0x0030), VR::TM), // Study Time
+    (Tag(0x0008, 0x0050), VR::SH), // Accession Number
+    (Tag(0x0008, 0x1030), VR::LO), // Study Description
+    (STUDY_INSTANCE_UID, VR::UI),
+    (Tag(0x0020, 0x0010), VR::SH), // Study ID
+];
+
+/// Keys of the SERIES directory record.
+static SERIES_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0060), VR::CS), // Modality
+    (SERIES_INSTANCE_UID, VR::UI),
+    (Tag(0x0020, 0x0011), VR::IS), // Series Number
+];
+
+/// Keys of the IMAGE directory record.
+static IMAGE_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+];
+
+/// Keys of the SR DOCUMENT directory record.
+static SR_DOCUMENT_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0023), VR::DA), // Content Date
+    (Tag(0x0008, 0x0033), VR::TM), // Content Time
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x0040, 0xA043), VR::SQ), // Concept Name Code Sequence
+    (Tag(0x0040, 0xA491), VR::CS), // Completion Flag
+    (Tag(0x0040, 0xA493), VR::CS), // Verification Flag
+];
+
+/// Keys of the KEY OBJECT DOC directory record.
+static KEY_OBJECT_DOC_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0023), VR::DA), // Content Date
+    (Tag(0x0008, 0x0033), VR::TM), // Content Time
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x0040, 0xA043), VR::SQ), // Concept Name Code Sequence
+];
+
+/// Keys of the ENCAP DOC directory record.
+static ENCAP_DOC_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0023), VR::DA), // Content Date
+    (Tag(0x0008, 0x0033), VR::TM), // Content Time
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x0040, 0xA043), VR::SQ), // Concept Name Code Sequence
+    (Tag(0x0042, 0x0010), VR::ST), // Document Title
+    (Tag(0x0042, 0x0012), VR::LO), // MIME Type of Encapsulated Document
+];
+
+/// Keys of the PRESENTATION directory record.
+static PRESENTATION_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x0070, 0x0080), VR::CS), // Content Label
+    (Tag(0x0070, 0x0081), VR::LO), // Content Description
+    (Tag(0x0070, 0x0082), VR::DA), // Presentation Creation Date
+    (Tag(0x0070, 0x0083), VR::TM), // Presentation Creation Time
+    (Tag(0x0070, 0x0084), VR::PN), // Content Creator's Name
+];
+
+/// Keys of the WAVEFORM and RAW DATA directory records.
+static CONTENT_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0023), VR::DA), // Content Date
+    (Tag(0x0008, 0x0033), VR::TM), // Content Time
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+];
+
+/// Keys of the REGISTRATION, FIDUCIAL, VALUE MAP and SURFACE
+/// directory records.
+static LABELLED_CONTENT_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0023), VR::DA), // Content Date
+    (Tag(0x0008, 0x0033), VR::TM), // Content Time
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x0070, 0x0080), VR::CS), // Content Label
+    (Tag(0x0070, 0x0081), VR::LO), // Content Description
+    (Tag(0x0070, 0x0084), VR::PN), // Content Creator's Name
+];
+
+/// Keys of the SPECTROSCOPY directory record.
+static SPECTROSCOPY_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0008, 0x0008), VR::CS), // Image Type
+    (Tag(0x0008, 0x0023), VR::DA), // Content Date
+    (Tag(0x0008, 0x0033), VR::TM), // Content Time
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x0028, 0x0008), VR::IS), // Number of Frames
+    (Tag(0x0028, 0x0010), VR::US), // Rows
+    (Tag(0x0028, 0x0011), VR::US), // Columns
+    (Tag(0x0028, 0x9001), VR::UL), // Data Point Rows
+    (Tag(0x0028, 0x9002), VR::UL), // Data Point Columns
+];
+
+/// Keys of the RT DOSE directory record.
+static RT_DOSE_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x3004, 0x000A), VR::CS), // Dose Summation Type
+];
+
+/// Keys of the RT STRUCTURE SET directory record.
+static RT_STRUCTURE_SET_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x3006, 0x0002), VR::SH), // Structure Set Label
+    (Tag(0x3006, 0x0008), VR::DA), // Structure Set Date
+    (Tag(0x3006, 0x0009), VR::TM), // Structure Set Time
+];
+
+/// Keys of the RT PLAN directory record.
+static RT_PLAN_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x300A, 0x0002), VR::SH), // RT Plan Label
+    (Tag(0x300A, 0x0006), VR::DA), // RT Plan Date
+    (Tag(0x300A, 0x0007), VR::TM), // RT Plan Time
+];
+
+/// Keys of the RT TREAT RECORD directory record.
+static RT_TREAT_RECORD_KEYS: &[(Tag, VR)] = &[
+    (Tag(0x0020, 0x0013), VR::IS), // Instance Number
+    (Tag(0x3008, 0x0250), VR::DA), // Treatment Date
+    (Tag(0x3008, 0x0251), VR::TM), // Treatment Time
+];
+
+/// The root of the UIDs of storage SOP classes.
+const STORAGE_ROOT: &str = "1.2.840.10008.5.1.4.1.1.";
+
+/// The directory record type of instances of the given SOP class,
+/// as defined in PS3.3 F.5, and the keys of the record.
+/// Instances of unknown SOP classes are recorded as images.
+fn record_type(sop_class_uid: &str) -> (&'static str, &'static [(Tag, VR)]) {
+    let storage = match sop_class_uid.strip_prefix(STORAGE_ROOT) {
+        Some(storage) => storage,
+        None => return ("IMAGE", IMAGE_KEYS),
+    };
+    let is_in =
+        |family: &str| storage.starts_with(family) && storage[family.len()..].starts_with('.');
+    match storage {
+        "88.59" => ("KEY OBJECT DOC", KEY_OBJECT_DOC_KEYS),
+        _ if is_in("88") => ("SR DOCUMENT", SR_DOCUMENT_KEYS),
+        _ if is_in("104") => ("ENCAP DOC", ENCAP_DOC_KEYS),
+        _ if is_in("11") => ("PRESENTATION", PRESENTATION_KEYS),
+        _ if is_in("9") => ("WAVEFORM", CONTENT_KEYS),
+        "4.2" => ("SPECTROSCOPY", SPECTROSCOPY_KEYS),
+        "66" => ("RAW DATA", CONTENT_KEYS),
+        "66.1" | "66.3" => ("REGISTRATION", LABELLED_CONTENT_KEYS),
+        "66.2" => ("FIDUCIAL", LABELLED_CONTENT_KEYS),
+        "66.5" => ("SURFACE", LABELLED_CONTENT_KEYS),
+        "67" => ("VALUE MAP", LABELLED_CONTENT_KEYS),
+        "481.2" => ("RT DOSE", RT_DOSE_KEYS),
+        "481.3" => ("RT STRUCTURE SET", RT_STRUCTURE_SET_KEYS),
+        "481.5" | "481.8" => ("RT PLAN", RT_PLAN_KEYS),
+        "481.4" | "481.6" | "481.7" | "481.9" => ("RT TREAT RECORD", RT_TREAT_RECORD_KEYS),
+        _ => ("IMAGE", IMAGE_KEYS),
+    }
+}
+
+/// A builder for a DICOM file-set.
+///
+/// Each added file is read to collect the attributes of its directory
+/// records, and copied unchanged into the output directory. File IDs are
+/// generated so that they are compatible with ISO 9660: at most 8
+/// components of at most 8 characters each, using only upper case
+/// letters and digits. The resulting hierarchy is
+/// `PNNNNNNN/SNNNNNNN/ENNNNNNN/INNNNNNN`, with numbers assigned in order
+/// of appearance.
+///
+/// Each instance is recorded with the directory record type of its
+/// SOP class (such as `IMAGE`, `SR DOCUMENT`, `KEY OBJECT DOC`,
+/// `PRESENTATION` or `RT PLAN`), following PS3.3 F.5.
+/// Record keys which are missing from an instance are written empty.
+/// Text is encoded in the specific character set of the instance,
+/// which is copied into each record holding extended characters.
+///
+/// # Example
+///
+/// ```no_run
+/// use dicom_object::dicomdir::FileSetBuilder;
+/// # fn foo() -> dicom_object::dicomdir::Result<()> {
+/// let dicomdir = FileSetBuilder::new("PATIENT_CD")
+///     .add_file("in/1.dcm")
+///     .add_file("in/2.dcm")
+///     .build("out")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FileSetBuilder {
+    file_set_id: String,
+    files: Vec<PathBuf>,
+    sop_instance_uid: Option<String>,
+}
+
+impl FileSetBuilder {
+    /// Create a new file-set builder with the given File-set ID.
+    ///
+    /// The ID may have up to 16 characters, which must be upper case
+    /// letters, digits, spaces or underscores. It may also be empty.
+    pub fn new<S: Into<String>>(file_set_id: S) -> Self {
+        FileSetBuilder {
+            file_set_id: file_set_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add a DICOM file to the file-set.
+    pub fn add_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.files.push(path.into());
+        self
+    }
+
+    /// Add multiple DICOM files to the file-set.
+    pub fn add_files<I>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathBuf>,
+    {
+        self.files.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Define the SOP Instance UID of the DICOMDIR.
+    /// If not defined, a UID is derived from the contents of the file-set.
+    pub fn sop_instance_uid<S: Into<String>>(mut self, uid: S) -> Self {
+        self.sop_instance_uid = Some(uid.into());
+        self
+    }
+
+    /// Create the file-set in the given directory, which is created if
+    /// it does not exist. On success, the newly written DICOMDIR is read
+    /// back and returned.
+    pub fn build<P: AsRef<Path>>(self, out_dir: P) -> Result<DicomDir> {
+        let out_dir = out_dir.as_ref();
+        if !is_valid_file_set_id(&self.file_set_id) {
+            return Err(Error::InvalidFileSetId(self.file_set_id));
+        }
+
+        // collect the hierarchy of records
+        let mut patients: Vec<Node> = Vec::new();
+        let mut copies = Vec::with_capacity(self.files.len());
+        let mut hasher = DefaultHasher::new();
+        self.file_set_id.hash(&mut hasher);
+
+        for path in &self.files {
+            let obj = open_file(path).map_err(|e| Error::ReadInstance(path.clone(), e))?;
+            let patient_id = string_value(&obj, PATIENT_ID).unwrap_or_default();
+            let study_uid = string_value(&obj, STUDY_INSTANCE_UID)
+                .ok_or_else(|| Error::MissingAttribute(path.clone(), STUDY_INSTANCE_UID))?;
+            let series_uid = string_value(&obj, SERIES_INSTANCE_UID)
+                .ok_or_else(|| Error::MissingAttribute(path.clone(), SERIES_INSTANCE_UID))?;
+            let meta = obj.meta();
+            let sop_class_uid = trim_uid(&meta.media_storage_sop_class_uid);
+            let sop_instance_uid = trim_uid(&meta.media_storage_sop_instance_uid);
+            let transfer_syntax = trim_uid(&meta.transfer_syntax);
+            sop_instance_uid.hash(&mut hasher);
+
+            let (p, patient) = child_by_key(&mut patients, &patient_id, || {
+                new_record_from(&obj, "PATIENT", PATIENT_KEYS)
+            });
+            let (s, study) = child_by_key(&mut patient.children, &study_uid, || {
+                new_record_from(&obj, "STUDY", STUDY_KEYS)
+            });
+            let (e, series) = child_by_key(&mut study.children, &series_uid, || {
+                new_record_from(&obj, "SERIES", SERIES_KEYS)
+            });
+
+            let file_id = vec![
+                format!("P{:07}", p + 1),
+                format!("S{:07}", s + 1),
+                format!("E{:07}", e + 1),
+                format!("I{:07}", series.children.len() + 1),
+            ];
+
+            let (record_type, keys) = record_type(sop_class_uid);
+            let mut record = new_record_from(&obj, record_type, keys);
+            record.put(InMemElement::new(
+                REFERENCED_FILE_ID,
+                VR::CS,
+                Value::Primitive(PrimitiveValue::Strs(file_id.iter().cloned().collect())),
+            ));
+            put_str(&mut record, REFERENCED_SOP_CLASS_UID, VR::UI, sop_class_uid);
+            put_str(
+                &mut record,
+                REFERENCED_SOP_INSTANCE_UID,
+                VR::UI,
+                sop_instance_uid,
+            );
+            put_str(
+                &mut record,
+                REFERENCED_TRANSFER_SYNTAX_UID,
+                VR::UI,
+                transfer_syntax,
+            );
+            series.children.push(Node {
+                key: sop_instance_uid.to_string(),
+                record,
+                children: Vec::new(),
+            });
+
+            copies.push((path, file_id));
+        }
+
+        // copy the instances
+        for (path, file_id) in copies {
+            let target = file_id
+                .iter()
+                .fold(out_dir.to_path_buf(), |path, part| path.join(part));
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(path, target)?;
+        }
+
+        // write the DICOMDIR
+        let sop_instance_uid = self
+            .sop_instance_uid
+            .unwrap_or_else(|| generate_uid(hasher.finish()));
+        let dicomdir_path = out_dir.join(DICOMDIR_FILE_NAME);
+        fs::create_dir_all(out_dir)?;
+        let mut file = File::create(&dicomdir_path)?;
+        file.write_all(&encode_dicomdir(
+            &self.file_set_id,
+            &sop_instance_uid,
+            patients,
+        )?)?;
+        file.flush()?;
+        drop(file);
+
+        DicomDir::open(dicomdir_path)
+    }
+}
+
+/// A directory record in the making, with its lower-level records.
+struct Node {
+    key: String,
+    record: DicomDirObject,
+    children: Vec<Node>,
+}
+
+/// Retrieve the index and node with the given key,
+/// creating a new node if none exists.
+fn child_by_key<'a, F>(nodes: &'a mut Vec<Node>, key: &str, record: F) -> (usize, &'a mut Node)
+where
+    F: FnOnce() -> DicomDirObject,
+{
+    let i = match nodes.iter().position(|n| n.key == key) {
+        Some(i) => i,
+        None => {
+            nodes.push(Node {
+                key: key.to_string(),
+                record: record(),
+                children: Vec::new(),
+            });
+            nodes.len() - 1
+        }
+    };
+    (i, &mut nodes[i])
+}
+
+/// A directory record in its final order, with links to other records
+/// by index.
+struct FlatRecord {
+    record: DicomDirObject,
+    next: Option<usize>,
+    lower: Option<usize>,
+}
+
+/// Lay out the records in depth-first order, returning the index of the
+/// first record of the given level.
+fn flatten(nodes: Vec<Node>, out: &mut Vec<FlatRecord>) -> Option<usize> {
+    let mut previous: Option<usize> = None;
+    let mut first = None;
+    for node in nodes {
+        let index = out.len();
+        out.push(FlatRecord {
+            record: node.record,
+            next: None,
+            lower: None,
+        });
+        out[index].lower = flatten(node.children, out);
+        match previous {
+            Some(p) => out[p].next = Some(index),
+            None => first = Some(index),
+        }
+        previous = Some(index);
+    }
+    first
+}
+
+/// Encode the full DICOMDIR file, preamble included.
+fn encode_dicomdir(
+    file_set_id: &str,
+    sop_instance_uid: &str,
+    patients: Vec<Node>,
+) -> Result<Vec<u8>> {
+    let mut records = Vec::new();
+    let first = flatten(patients, &mut records);
+    let mut last = first;
+    while let Some(next) = last.and_then(|i| records[i].next) {
+        last = Some(next);
+    }
+
+    let meta = encode_meta_group(MEDIA_STORAGE_DIRECTORY_STORAGE, sop_instance_uid)?;
+
+    // the offsets are fixed size, so the records can be measured first
+    let lengths = records
+        .iter()
+        .map(|r| encode_record(&r.record, 0, 0).map(|bytes| bytes.len() as u32))
+        .collect::<Result<Vec<_>>>()?;
+    let header_len = encode_header(file_set_id, 0, 0)?.len() as u32;
+    let mut offsets = Vec::with_capacity(records.len());
+    let mut offset = PREAMBLE_LENGTH as u32 + meta.len() as u32 + header_len;
+    for len in lengths {
+        offsets.push(offset);
+        offset += len;
+    }
+    let offset_of = |i: Option<usize>| i.map(|i| offsets[i]).unwrap_or(0);
+
+    let mut out = vec![0u8; PREAMBLE_LENGTH as usize];
+    out.extend(meta);
+    out.extend(encode_header(
+        file_set_id,
+        offset_of(first),
+        offset_of(last),
+    )?);
+    for r in &records {
+        out.extend(encode_record(
+            &r.record,
+            offset_of(r.next),
+            offset_of(r.lower),
+        )?);
+    }
+    ExplicitVRLittleEndianEncoder::default().encode_sequence_delimiter(&mut out)?;
+    Ok(out)
+}
+
+/// Encode the file meta group, including the `DICM` magic code.
+fn encode_meta_group(sop_class_uid: &str, sop_instance_uid: &str) -> Result<Vec<u8>> {
+    let mut meta = InMemDicomObject::new_empty_with_dict(StandardDataDictionary);
+    meta.put(InMemElement::new(
+        Tag(0x0002, 0x0001),
+        VR::OB,
+        Value::Primitive(PrimitiveValue::U8(smallvec![0, 1])),
+    ));
+    put_str(&mut meta, Tag(0x0002, 0x0002), VR::UI, sop_class_uid);
+    put_str(&mut meta, Tag(0x0002, 0x0003), VR::UI, sop_instance_uid);
+    put_str(
+        &mut meta,
+        Tag(0x0002, 0x0010),
+        VR::UI,
+        EXPLICIT_VR_LITTLE_ENDIAN,
+    );
+    put_str(
+        &mut meta,
+        Tag(0x0002, 0x0012),
+        VR::UI,
+        IMPLEMENTATION_CLASS_UID,
+    );
+    put_str(
+        &mut meta,
+        Tag(0x0002, 0x0013),
+        VR::SH,
+        IMPLEMENTATION_VERSION_NAME,
+    );
+    let mut body = Vec::new();
+    encode_elements(&mut body, &meta, SpecificCharacterSet::Default)?;
+
+    let mut group_length = InMemDicomObject::new_empty_with_dict(StandardDataDictionary);
+    group_length.put(InMemElement::new(
+        Tag(0x0002, 0x0000),
+        VR::UL,
+        Value::Primitive(PrimitiveValue::U32(smallvec![body.len() as u32])),
+    ));
+    let mut out = b"DICM".to_vec();
+    encode_elements(&mut out, &group_length, SpecificCharacterSet::Default)?;
+    out.extend(body);
+    Ok(out)
+}
+
+/// Encode the DICOMDIR attributes preceding the directory records,
+/// up to the first item of the Directory Record Sequence.
+fn encode_header(file_set_id: &str, first: u32, last: u32) -> Result<Vec<u8>> {
+    let mut obj = InMemDicomObject::new_empty_with_dict(StandardDataDictionary);
+    put_str(&mut obj, FILE_SET_ID, VR::CS, file_set_id);
+    put_u32(&mut obj, OFFSET_OF_FIRST_RECORD, first);
+    put_u32(&mut obj, OFFSET_OF_LAST_RECORD, last);
+    obj.put(InMemElement::new(
+        FILE_SET_CONSISTENCY_FLAG,
+        VR::US,
+        Value::Primitive(PrimitiveValue::U16(smallvec![0])),
+    ));
+    let mut out = Vec::new();
+    encode_elements(&mut out, &obj, SpecificCharacterSet::Default)?;
+    ExplicitVRLittleEndianEncoder::default().encode_element_header(
+        &mut out,
+        DataElementHeader::new(DIRECTORY_RECORD_SEQUENCE, VR::SQ, Length::UNDEFINED),
+    )?;
+    Ok(out)
+}
+
+/// Encode a directory record as an item of undefined length.
+fn encode_record(record: &DicomDirObject, next: u32, lower: u32) -> Result<Vec<u8>> {
+    let mut record = record.clone();
+    put_u32(&mut record, OFFSET_OF_NEXT_RECORD, next);
+    put_u32(&mut record, OFFSET_OF_LOWER_LEVEL, lower);
+    let mut out = Vec::new();
+    encode_item(&mut out, &record, SpecificCharacterSet::Default)?;
+    Ok(out)
+}
+
+fn encode_item(
+    to: &mut Vec<u8>,
+    obj: &DicomDirObject,
+    charset: SpecificCharacterSet,
+) -> Result<()> {
+    let encoder = ExplicitVRLittleEndianEncoder::default();
+    encoder.encode_item_header(to, Length::UNDEFINED.0)?;
+    encode_elements(to, obj, charset)?;
+    encoder.encode_item_delimiter(to)?;
+    Ok(())
+}
+
+/// Encode all elements of an object in Explicit VR Little Endian.
+/// Sequences and items are written with undefined length.
+/// Text is encoded in the object's specific character set,
+/// or in the given one if the object does not define it.
+fn encode_elements(
+    to: &mut Vec<u8>,
+    obj: &DicomDirObject,
+    charset: SpecificCharacterSet,
+) -> Result<()> {
+    let encoder = ExplicitVRLittleEndianEncoder::default();
+    let charset = character_set_of(obj).unwrap_or(charset);
+    let codec = charset
+        .get_codec()
+        .unwrap_or_else(|| Box::new(DefaultCharacterSetCodec));
+    for elem in obj {
+        let tag = elem.header().tag;
+        match elem.value() {
+            Value::Sequence { items, .. } => {
+                encoder.encode_element_header(
+                    to,
+                    DataElementHeader::new(tag, VR::SQ, Length::UNDEFINED),
+                )?;
+                for item in items {
+                    encode_item(to, item, charset)?;
+                }
+                encoder.encode_sequence_delimiter(to)?;
+            }
+            Value::Primitive(value) => {
+                let vr = elem.vr();
+                let mut bytes = if is_text_vr(vr) {
+                    let mut values = value_to_strings(vr, value);
+                    if vr == VR::UI {
+                        while values.last().map(String::is_empty).unwrap_or(false) {
+                            values.pop();
+                        }
+                    }
+                    codec.encode(&values.join("\\"))?
+                } else {
+                    value_to_bytes(value)
+                };
+                if bytes.len() % 2 == 1 {
+                    bytes.push(if vr == VR::UI || !is_text_vr(vr) {
+                        0
+                    } else {
+                        b' '
+                    });
+                }
+                encoder.encode_element_header(
+                    to,
+                    DataElementHeader::new(tag, vr, Length(bytes.len() as u32)),
+                )?;
+                to.extend(bytes);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Create a directory record with the given type and default attributes.
+fn new_record(record_type: &str) -> DicomDirObject {
+    let mut record = InMemDicomObject::new_empty_with_dict(StandardDataDictionary);
+    put_u32(&mut record, OFFSET_OF_NEXT_RECORD, 0);
+    record.put(InMemElement::new(
+        RECORD_IN_USE_FLAG,
+        VR::US,
+        Value::Primitive(PrimitiveValue::U16(smallvec![0xFFFF])),
+    ));
+    put_u32(&mut record, OFFSET_OF_LOWER_LEVEL, 0);
+    put_str(&mut record, DIRECTORY_RECORD_TYPE, VR::CS, record_type);
+    record
+}
+
+/// Create a directory record of the given type with the given keys
+/// of an instance. The specific character set of the instance is copied
+/// if the record holds extended characters.
+fn new_record_from(src: &DicomDirObject, record_type: &str, keys: &[(Tag, VR)]) -> DicomDirObject {
+    let mut record = new_record(record_type);
+    copy_keys(src, &mut record, keys);
+    if has_extended_characters(&record) && src.element(SPECIFIC_CHARACTER_SET).is_ok() {
+        copy_keys(src, &mut record, &[(SPECIFIC_CHARACTER_SET, VR::CS)]);
+    }
+    record
+}
+
+/// Whether any text in the object, including its sequence items,
+/// has characters beyond the default character repertoire.
+fn has_extended_characters(obj: &DicomDirObject) -> bool {
+    obj.into_iter().any(|elem| match elem.value() {
+        Value::Primitive(value) => {
+            is_text_vr(elem.vr())
+                && value_to_strings(elem.vr(), value)
+                    .iter()
+                    .any(|s| !s.is_ascii())
+        }
+        Value::Sequence { items, .. } => items.iter().any(has_extended_characters),
+    })
+}
+
+/// The specific character set defined in the object, if any.
+/// Only the first value is considered, and unsupported character sets
+/// fall back to the default one.
+fn character_set_of(obj: &DicomDirObject) -> Option<SpecificCharacterSet> {
+    let value = obj
+        .element(SPECIFIC_CHARACTER_SET)
+        .ok()?
+        .value()
+        .primitive()?;
+    let code = value_to_strings(VR::CS, value).into_iter().next()?;
+    Some(SpecificCharacterSet::from_code(code.trim()).unwrap_or_default())
+}
+
+/// Copy the given attributes from an instance into a directory record.
+/// Attributes missing from the instance are added with an empty value.
+fn copy_keys(src: &DicomDirObject, record: &mut DicomDirObject, keys: &[(Tag, VR)]) {
+    for &(tag, vr) in keys {
+        let elem = match src.element(tag) {
+            Ok(elem) => elem.clone(),
+            Err(_) if vr == VR::SQ => InMemElement::new(
+                tag,
+                vr,
+                Value::Sequence {
+                    items: smallvec![],
+                    size: Length::UNDEFINED,
+                },
+            ),
+            Err(_) => InMemElement::new(tag, vr, Value::Primitive(PrimitiveValue::Empty)),
+        };
+        record.put(elem);
+    }
+}
+
+fn put_str(obj: &mut DicomDirObject, tag: Tag, vr: VR, value: &str) {
+    obj.put(InMemElement::new(
+        tag,
+        vr,
+        Value::Primitive(PrimitiveValue::Str(value.to_string())),
+    ));
+}
+
+fn put_u32(obj: &mut DicomDirObject, tag: Tag, value: u32) {
+    obj.put(InMemElement::new(
+        tag,
+        VR::UL,
+        Value::Primitive(PrimitiveValue::U32(smallvec![value])),
+    ));
+}
+
+fn string_value(obj: &DicomDirObject, tag: Tag) -> Option<String> {
+    obj.element(tag)
+        .ok()
+        .and_then(|e| e.value().primitive())
+        .and_then(|v| v.string())
+        .map(|s| s.trim_end_matches([' ', '\0']).to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn trim_uid(uid: &str) -> &str {
+    uid.trim_end_matches([' ', '\0'])
+}
+
+/// Check whether the text is a valid File-set ID.
+fn is_valid_file_set_id(id: &str) -> bool {
+    id.len() <= 16
+        && id
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == ' ' || c == '_')
+}
+
+/// Generate a UID under the `2.25` root from a content hash
+/// and the current time.
+fn generate_uid(seed: u64) -> String {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    if let Ok(elapsed) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        elapsed.as_nanos().hash(&mut hasher);
+    }
+    let high = hasher.finish();
+    let value = (u128::from(high) << 64) | u128::from(seed);
+    format!("2.25.{}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::string_of;
+    use super::*;
+
+    #[test]
+    fn file_set_ids() {
+        assert!(is_valid_file_set_id(""));
+        assert!(is_valid_file_set_id("PATIENT_CD 01"));
+        assert!(!is_valid_file_set_id("patient"));
+        assert!(!is_valid_file_set_id("PATIENT-CD"));
+        assert!(!is_valid_file_set_id("ABCDEFGHIJKLMNOPQ"));
+    }
+
+    #[test]
+    fn generated_dicomdir_can_be_read() {
+        let study = |uid: &str, series: Vec<Node>| Node {
+            key: uid.to_string(),
+            record: new_record("STUDY"),
+            children: series,
+        };
+        let series = |uid: &str, images: Vec<Node>| Node {
+            key: uid.to_string(),
+            record: new_record("SERIES"),
+            children: images,
+        };
+        let image = |id: &str| {
+            let mut record = new_record("IMAGE");
+            record.put(InMemElement::new(
+                REFERENCED_FILE_ID,
+                VR::CS,
+                Value::Primitive(PrimitiveValue::Strs(smallvec![
+                    "P0000001".to_string(),
+                    id.to_string()
+                ])),
+            ));
+            put_str(&mut record, REFERENCED_SOP_INSTANCE_UID, VR::UI, "1.2.3");
+            Node {
+                key: id.to_string(),
+                record,
+                children: Vec::new(),
+            }
+        };
+        let patients = vec![
+            Node {
+                key: "1".to_string(),
+                record: new_record("PATIENT"),
+                children: vec![study(
+                    "1.1",
+                    vec![
+                        series("1.1.1", vec![image("I0000001"), image("I0000002")]),
+                        series("1.1.2", vec![image("I0000003")]),
+                    ],
+                )],
+            },
+            Node {
+                key: "2".to_string(),
+                record: new_record("PATIENT"),
+                children: vec![],
+            },
+        ];
+
+        let bytes = encode_dicomdir("TEST", "2.25.1", patients).unwrap();
+        let dicomdir =
+            DicomDir::from_reader(std::io::Cursor::new(bytes[128..].to_vec()), "/cd").unwrap();
+        assert_eq!(dicomdir.meta().transfer_syntax, "1.2.840.10008.1.2.1\0");
+        assert_eq!(dicomdir.meta().media_storage_sop_instance_uid, "2.25.1");
+        assert_eq!(dicomdir.file_set_id().as_deref(), Some("TEST"));
+
+        let patients = dicomdir.records();
+        assert_eq!(patients.len(), 2);
+        assert!(patients[1].children().is_empty());
+        let series = patients[0].children()[0].children();
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].children().len(), 2);
+        assert_eq!(
+            series[1].children()[0].referenced_file_path(),
+            Some(Path::new("/cd/P0000001/I0000003"))
+        );
+
+        // the last root record offset points to the second patient
+        let last = dicomdir
+            .object()
+            .element(OFFSET_OF_LAST_RECORD)
+            .unwrap()
+            .value()
+            .primitive()
+            .and_then(|v| v.uint32());
+        assert_eq!(last, Some(patients[1].offset()));
+    }
+
+    fn write_instance(path: &Path, patient: &str, study: &str, series: &str, sop: &str) {
+        let mut obj = InMemDicomObject::new_empty_with_dict(StandardDataDictionary);
+        put_str(
+            &mut obj,
+            Tag(0x0008, 0x0016),
+            VR::UI,
+            "1.2.840.10008.5.1.4.1.1.7",
+        );
+        put_str(&mut obj, Tag(0x0008, 0x0018), VR::UI, sop);
+        put_str(&mut obj, Tag(0x0008, 0x0060), VR::CS, "OT");
+        put_str(&mut obj, Tag(0x0010, 0x0010), VR::PN, "Doe^John");
+        put_str(&mut obj, PATIENT_ID, VR::LO, patient);
+        put_str(&mut obj, STUDY_INSTANCE_UID, VR::UI, study);
+        put_str(&mut obj, SERIES_INSTANCE_UID, VR::UI, series);
+        let mut bytes = vec![0u8; PREAMBLE_LENGTH as usize];
+        bytes.extend(encode_meta_group("1.2.840.10008.5.1.4.1.1.7", sop).unwrap());
+        encode_elements(&mut bytes, &obj, SpecificCharacterSet::Default).unwrap();
+        fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn record_types_of_sop_classes() {
+        let type_of = |uid: &str| record_type(uid).0;
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.2"), "IMAGE");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.7"), "IMAGE");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.88.22"), "SR DOCUMENT");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.88.59"), "KEY OBJECT DOC");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.104.1"), "ENCAP DOC");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.11.1"), "PRESENTATION");
+        // not to be confused with the presentation state family
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.1.1"), "IMAGE");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.9.1.1"), "WAVEFORM");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.4.2"), "SPECTROSCOPY");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.66"), "RAW DATA");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.66.1"), "REGISTRATION");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.66.2"), "FIDUCIAL");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.481.1"), "IMAGE");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.481.2"), "RT DOSE");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.481.3"), "RT STRUCTURE SET");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.481.5"), "RT PLAN");
+        assert_eq!(type_of("1.2.840.10008.5.1.4.1.1.481.4"), "RT TREAT RECORD");
+        assert_eq!(type_of("1.2.3.4"), "IMAGE");
+    }
+
+    #[test]
+    fn records_are_encoded_in_their_character_set() {
+        let mut obj = InMemDicomObject::new_empty_with_dict(StandardDataDictionary);
+        put_str(&mut obj, SPECIFIC_CHARACTER_SET, VR::CS, "ISO_IR 100");
+        put_str(&mut obj, Tag(0x0010, 0x0010), VR::PN, "Müller");
+        put_str(&mut obj, Tag(0x0008, 0x1030), VR::LO, "Schädel");
+        put_str(&mut obj, Tag(0x0008, 0x103E), VR::LO, "AXIAL");
+
+        let patient = new_record_from(&obj, "PATIENT", PATIENT_KEYS);
+        let study = new_record_from(&obj, "STUDY", STUDY_KEYS);
+        let series = new_record_from(&obj, "SERIES", SERIES_KEYS);
+        assert!(patient.element(SPECIFIC_CHARACTER_SET).is_ok());
+        assert!(study.element(SPECIFIC_CHARACTER_SET).is_ok());
+        // plain ASCII records need no character set
+        assert!(series.element(SPECIFIC_CHARACTER_SET).is_err());
+
+        let mut bytes = Vec::new();
+        encode_elements(&mut bytes, &patient, SpecificCharacterSet::Default).unwrap();
+        assert!(bytes.windows(6).any(|w| w == b"M\xFCller"));
+
+        let mut obj = InMemDicomObject::new_empty_with_dict(StandardDataDictionary);
+        put_str(&mut obj, SPECIFIC_CHARACTER_SET, VR::CS, "ISO_IR 192");
+        put_str(&mut obj, Tag(0x0010, 0x0010), VR::PN, "Müller");
+        let patient = new_record_from(&obj, "PATIENT", PATIENT_KEYS);
+        let mut bytes = Vec::new();
+        encode_elements(&mut bytes, &patient, SpecificCharacterSet::Default).unwrap();
+        assert!(bytes.windows(7).any(|w| w == "Müller".as_bytes()));
+    }
+
+    #[test]
+    fn build_file_set() {
+        let dir = std::env::temp_dir().join(format!("dicom-rs-fileset-{}", std::process::id()));
+        let in_dir = dir.join("in");
+        let out_dir = dir.join("out");
+        fs::create_dir_all(&in_dir).unwrap();
+        write_instance(&in_dir.join("a.dcm"), "P1", "1.2.1", "1.2.1.1", "1.2.1.1.1");
+        write_instance(&in_dir.join("b.dcm"), "P2", "1.2.2", "1.2.2.1", "1.2.2.1.1");
+        write_instance(&in_dir.join("c.dcm"), "P1", "1.2.1", "1.2.1.1", "1.2.1.1.2");
+
+        let dicomdir = FileSetBuilder::new("MY_CD")
+            .add_files(vec![
+                in_dir.join("a.dcm"),
+                in_dir.join("b.dcm"),
+                in_dir.join("c.dcm"),
+            ])
+            .build(&out_dir)
+            .unwrap();
+
+        assert_eq!(dicomdir.file_set_id().as_deref(), Some("MY_CD"));
+        let patients = dicomdir.records();
+        assert_eq!(patients.len(), 2);
+        let images = patients[0].children()[0].children()[0].children();
+        assert_eq!(images.len(), 2);
+        assert_eq!(
+            images[1].referenced_file_id(),
+            Some(vec![
+                "P0000001".to_string(),
+                "S0000001".to_string(),
+                "E0000001".to_string(),
+                "I0000002".to_string(),
+            ])
+        );
+        let copied = images[1].referenced_file_path().unwrap();
+        assert_eq!(
+            fs::read(copied).unwrap(),
+            fs::read(in_dir.join("c.dcm")).unwrap()
+        );
+        let series = &patients[0].children()[0].children()[0];
+        assert_eq!(
+            string_of(series.object(), Tag(0x0008, 0x0060)).as_deref(),
+            Some("OT")
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
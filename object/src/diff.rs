@@ -0,0 +1,491 @@
+//! This module implements a structural comparison between two DICOM objects.
+//!
+//! Objects are compared element by element, in tag order, recursing into
+//! the items of sequences. Each difference is reported with the path to
+//! the element, as an addition, a removal or a change. The elements of
+//! items only present in one of the sequences are reported as added or
+//! removed, with the index of the item in their path.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use dicom_object::open_file;
+//! # use dicom_object::diff::{diff, DiffOptions};
+//! # use dicom_object::Tag;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let before = open_file("before.dcm")?;
+//! let after = open_file("after.dcm")?;
+//! let options = DiffOptions::new()
+//!     .ignore_tag(Tag(0x0008, 0x0018))
+//!     .ignore_padding(true)
+//!     .pixel_data_by_hash(true);
+//! for difference in diff(&before, &after, &options) {
+//!     println!("{}", difference);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::iod::PathSegment;
+use crate::mem::{InMemDicomObject, InMemElement};
+use crate::util::{value_to_bytes, value_to_strings};
+use dicom_core::dictionary::DataDictionary;
+use dicom_core::header::Header;
+use dicom_core::value::{PrimitiveValue, Value};
+use dicom_core::{Tag, VR};
+use itertools::{EitherOrBoth, Itertools};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The attributes holding pixel data, which can be compared by hash.
+const PIXEL_DATA_TAGS: [Tag; 3] = [
+    Tag(0x7FE0, 0x0008),
+    Tag(0x7FE0, 0x0009),
+    Tag(0x7FE0, 0x0010),
+];
+
+/// Options for comparing DICOM objects.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DiffOptions {
+    ignored_tags: BTreeSet<Tag>,
+    ignore_padding: bool,
+    pixel_data_by_hash: bool,
+}
+
+impl DiffOptions {
+    /// Create the default comparison options: no tags are ignored,
+    /// and values must match exactly.
+    pub fn new() -> Self {
+        DiffOptions::default()
+    }
+
+    /// Ignore the elements with the given tag, at any nesting level.
+    pub fn ignore_tag(mut self, tag: Tag) -> Self {
+        self.ignored_tags.insert(tag);
+        self
+    }
+
+    /// Ignore the elements with any of the given tags, at any nesting level.
+    pub fn ignore_tags<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        self.ignored_tags.extend(tags);
+        self
+    }
+
+    /// Whether to ignore differences in trailing padding
+    /// (spaces and null characters) of text values.
+    pub fn ignore_padding(mut self, ignore: bool) -> Self {
+        self.ignore_padding = ignore;
+        self
+    }
+
+    /// Whether to compare pixel data by the SHA-256 digest of its content,
+    /// reporting the digest instead of the full value.
+    pub fn pixel_data_by_hash(mut self, by_hash: bool) -> Self {
+        self.pixel_data_by_hash = by_hash;
+        self
+    }
+}
+
+/// A summary of an element on one side of a difference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementSummary {
+    /// The element VR.
+    pub vr: VR,
+    /// A textual representation of the value: the values separated by
+    /// backslashes, the number of items of a sequence, or the SHA-256 digest
+    /// of the content (as `sha256:<hex>`) when comparing pixel data by hash.
+    pub value: String,
+}
+
+impl fmt::Display for ElementSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.vr, self.value)
+    }
+}
+
+/// The kind of difference found in an element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DifferenceKind {
+    /// The element is only present in the second object.
+    Added(ElementSummary),
+    /// The element is only present in the first object.
+    Removed(ElementSummary),
+    /// The element is present in both objects with a different VR or value.
+    /// Sequences are reported as changed when their number of items differs,
+    /// in addition to the elements of the extra items.
+    Changed(ElementSummary, ElementSummary),
+}
+
+/// A difference between two objects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// The sequence items leading to the element,
+    /// empty if the element is in the root data set.
+    pub path: Vec<PathSegment>,
+    /// The element tag.
+    pub tag: Tag,
+    /// The kind of difference.
+    pub kind: DifferenceKind,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = match self.kind {
+            DifferenceKind::Added(_) => '+',
+            DifferenceKind::Removed(_) => '-',
+            DifferenceKind::Changed(..) => '~',
+        };
+        write!(f, "{} ", sign)?;
+        for segment in &self.path {
+            write!(f, "{}[{}].", segment.tag, segment.item)?;
+        }
+        write!(f, "{} ", self.tag)?;
+        match &self.kind {
+            DifferenceKind::Added(e) | DifferenceKind::Removed(e) => write!(f, "{}", e),
+            DifferenceKind::Changed(left, right) => write!(f, "{} -> {}", left, right),
+        }
+    }
+}
+
+/// Compare two DICOM objects, returning the differences found
+/// from `left` to `right`.
+pub fn diff<D1, D2>(
+    left: &InMemDicomObject<D1>,
+    right: &InMemDicomObject<D2>,
+    options: &DiffOptions,
+) -> Vec<Difference>
+where
+    D1: DataDictionary,
+    D1: Clone,
+    D2: DataDictionary,
+    D2: Clone,
+{
+    let mut differences = Vec::new();
+    diff_objects(left, right, options, &mut Vec::new(), &mut differences);
+    differences
+}
+
+fn diff_objects<D1, D2>(
+    left: &InMemDicomObject<D1>,
+    right: &InMemDicomObject<D2>,
+    options: &DiffOptions,
+    path: &mut Vec<PathSegment>,
+    differences: &mut Vec<Difference>,
+) where
+    D1: DataDictionary,
+    D1: Clone,
+    D2: DataDictionary,
+    D2: Clone,
+{
+    let pairs = left
+        .into_iter()
+        .merge_join_by(right, |l, r| l.tag().cmp(&r.tag()))
+        .filter(|pair| {
+            let tag = match pair {
+                EitherOrBoth::Both(e, _) | EitherOrBoth::Left(e) => e.tag(),
+                EitherOrBoth::Right(e) => e.tag(),
+            };
+            !options.ignored_tags.contains(&tag)
+        });
+
+    for pair in pairs {
+        match pair {
+            EitherOrBoth::Left(l) => differences.push(Difference {
+                path: path.clone(),
+                tag: l.tag(),
+                kind: DifferenceKind::Removed(summary(l, options)),
+            }),
+            EitherOrBoth::Right(r) => differences.push(Difference {
+                path: path.clone(),
+                tag: r.tag(),
+                kind: DifferenceKind::Added(summary(r, options)),
+            }),
+            EitherOrBoth::Both(l, r) => {
+                if !elements_equal(l, r, options) {
+                    differences.push(Difference {
+                        path: path.clone(),
+                        tag: l.tag(),
+                        kind: DifferenceKind::Changed(summary(l, options), summary(r, options)),
+                    });
+                }
+                if let (Some(left_items), Some(right_items)) = (l.value().item(), r.value().item())
+                {
+                    for (i, items) in left_items.iter().zip_longest(right_items).enumerate() {
+                        path.push(PathSegment {
+                            tag: l.tag(),
+                            item: i,
+                        });
+                        match items {
+                            EitherOrBoth::Both(li, ri) => {
+                                diff_objects(li, ri, options, path, differences)
+                            }
+                            EitherOrBoth::Left(li) => {
+                                let empty =
+                                    InMemDicomObject::new_empty_with_dict(li.dict().clone());
+                                diff_objects(li, &empty, options, path, differences)
+                            }
+                            EitherOrBoth::Right(ri) => {
+                                let empty =
+                                    InMemDicomObject::new_empty_with_dict(ri.dict().clone());
+                                diff_objects(&empty, ri, options, path, differences)
+                            }
+                        }
+                        path.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_pixel_data(tag: Tag) -> bool {
+    PIXEL_DATA_TAGS.contains(&tag)
+}
+
+fn elements_equal<D1, D2>(
+    left: &InMemElement<D1>,
+    right: &InMemElement<D2>,
+    options: &DiffOptions,
+) -> bool {
+    if left.vr() != right.vr() {
+        return false;
+    }
+    match (left.value(), right.value()) {
+        (Value::Sequence { items: l, .. }, Value::Sequence { items: r, .. }) => l.len() == r.len(),
+        (Value::Primitive(l), Value::Primitive(r)) => {
+            if options.pixel_data_by_hash && is_pixel_data(left.tag()) {
+                hash_value(l) == hash_value(r)
+            } else if options.ignore_padding {
+                match (trimmed_strings(left.vr(), l), trimmed_strings(left.vr(), r)) {
+                    (Some(l), Some(r)) => l == r,
+                    _ => l == r,
+                }
+            } else {
+                l == r
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The text values of a primitive value, without trailing padding,
+/// if it is a textual value.
+fn trimmed_strings(vr: VR, value: &PrimitiveValue) -> Option<Vec<String>> {
+    match value {
+        PrimitiveValue::Str(_) | PrimitiveValue::Strs(_) => Some(value_to_strings(vr, value)),
+        _ => None,
+    }
+}
+
+/// The SHA-256 digest of the value's bytes, in hexadecimal.
+fn hash_value(value: &PrimitiveValue) -> String {
+    Sha256::digest(value_to_bytes(value))
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn summary<D>(elem: &InMemElement<D>, options: &DiffOptions) -> ElementSummary {
+    let value = match elem.value() {
+        Value::Sequence { items, .. } => format!("{} items", items.len()),
+        Value::Primitive(v) if options.pixel_data_by_hash && is_pixel_data(elem.tag()) => {
+            format!("sha256:{}", hash_value(v))
+        }
+        Value::Primitive(PrimitiveValue::Str(s)) => s.clone(),
+        Value::Primitive(PrimitiveValue::Strs(c)) => c.join("\\"),
+        Value::Primitive(v) => value_to_strings(elem.vr(), v).join("\\"),
+    };
+    ElementSummary {
+        vr: elem.vr(),
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::put_strs;
+    use dicom_core::{DataElement, Length};
+    use dicom_dictionary_std::StandardDataDictionary;
+    use smallvec::smallvec;
+
+    type Obj = InMemDicomObject<StandardDataDictionary>;
+
+    fn put_items(obj: &mut Obj, tag: Tag, items: Vec<Obj>) {
+        obj.put(DataElement::new(
+            tag,
+            VR::SQ,
+            Value::Sequence {
+                items: items.into_iter().collect(),
+                size: Length::UNDEFINED,
+            },
+        ));
+    }
+
+    fn base_object() -> Obj {
+        let mut obj = Obj::create_empty();
+        put_strs(&mut obj, Tag(0x0008, 0x0018), VR::UI, &["1.2.3.4"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0060), VR::CS, &["CT"]);
+        let mut item = Obj::create_empty();
+        put_strs(&mut item, Tag(0x0020, 0x000E), VR::UI, &["1.2.3.4.5"]);
+        put_items(&mut obj, Tag(0x0008, 0x1115), vec![item]);
+        obj.put(DataElement::new(
+            Tag(0x7FE0, 0x0010),
+            VR::OW,
+            PrimitiveValue::U16(smallvec![1, 2, 3, 4]).into(),
+        ));
+        obj
+    }
+
+    #[test]
+    fn identical_objects_have_no_differences() {
+        let obj = base_object();
+        assert_eq!(diff(&obj, &obj.clone(), &DiffOptions::new()), vec![]);
+    }
+
+    #[test]
+    fn added_removed_and_changed() {
+        let mut left = base_object();
+        put_strs(&mut left, Tag(0x0010, 0x0010), VR::PN, &["Doe^John"]);
+        let mut right = base_object();
+        right.put(DataElement::empty(Tag(0x0008, 0x0050), VR::SH));
+        put_strs(&mut right, Tag(0x0008, 0x0060), VR::CS, &["MR"]);
+        let mut item = Obj::create_empty();
+        put_strs(&mut item, Tag(0x0020, 0x000E), VR::UI, &["1.2.3.4.6"]);
+        put_items(&mut right, Tag(0x0008, 0x1115), vec![item]);
+
+        let differences = diff(&left, &right, &DiffOptions::new());
+        let lines: Vec<_> = differences.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "+ (0008,0050) SH ",
+                "~ (0008,0060) CS CT -> CS MR",
+                "~ (0008,1115)[0].(0020,000E) UI 1.2.3.4.5 -> UI 1.2.3.4.6",
+                "- (0010,0010) PN Doe^John",
+            ]
+        );
+        assert_eq!(
+            differences[2].path,
+            vec![PathSegment {
+                tag: Tag(0x0008, 0x1115),
+                item: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn extra_items_are_added_or_removed() {
+        let left = base_object();
+        let mut right = base_object();
+        let mut first = Obj::create_empty();
+        put_strs(&mut first, Tag(0x0020, 0x000E), VR::UI, &["1.2.3.4.5"]);
+        let mut second = Obj::create_empty();
+        put_strs(&mut second, Tag(0x0020, 0x000E), VR::UI, &["1.2.3.4.6"]);
+        put_strs(&mut second, Tag(0x0020, 0x0011), VR::IS, &["2"]);
+        put_items(&mut right, Tag(0x0008, 0x1115), vec![first, second]);
+
+        let lines: Vec<_> = diff(&left, &right, &DiffOptions::new())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "~ (0008,1115) SQ 1 items -> SQ 2 items",
+                "+ (0008,1115)[1].(0020,000E) UI 1.2.3.4.6",
+                "+ (0008,1115)[1].(0020,0011) IS 2",
+            ]
+        );
+
+        let lines: Vec<_> = diff(&right, &left, &DiffOptions::new())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "~ (0008,1115) SQ 2 items -> SQ 1 items",
+                "- (0008,1115)[1].(0020,000E) UI 1.2.3.4.6",
+                "- (0008,1115)[1].(0020,0011) IS 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn ignored_tags_and_padding() {
+        let mut left = base_object();
+        put_strs(&mut left, Tag(0x0010, 0x0010), VR::PN, &["Doe^John"]);
+        let mut right = base_object();
+        put_strs(&mut right, Tag(0x0008, 0x0018), VR::UI, &["9.8.7"]);
+        put_strs(&mut right, Tag(0x0010, 0x0010), VR::PN, &["Doe^John "]);
+
+        let options = DiffOptions::new().ignore_tag(Tag(0x0008, 0x0018));
+        assert_eq!(diff(&left, &right, &options).len(), 1);
+
+        let options = options.ignore_padding(true);
+        assert_eq!(diff(&left, &right, &options), vec![]);
+    }
+
+    #[test]
+    fn padding_is_ignored_in_text_only() {
+        let mut left = base_object();
+        left.put(DataElement::new(
+            Tag(0x0028, 0x0010),
+            VR::US,
+            PrimitiveValue::U16(smallvec![512]).into(),
+        ));
+        left.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::F64(smallvec![0.5, 0.25]).into(),
+        ));
+        let mut right = base_object();
+        right.put(DataElement::new(
+            Tag(0x0028, 0x0010),
+            VR::US,
+            PrimitiveValue::U16(smallvec![256]).into(),
+        ));
+        right.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::F64(smallvec![0.5, 0.5]).into(),
+        ));
+
+        let options = DiffOptions::new().ignore_padding(true);
+        let tags: Vec<_> = diff(&left, &right, &options)
+            .iter()
+            .map(|d| d.tag)
+            .collect();
+        assert_eq!(tags, vec![Tag(0x0028, 0x0010), Tag(0x0028, 0x0030)]);
+    }
+
+    #[test]
+    fn pixel_data_by_hash() {
+        let left = base_object();
+        let mut right = base_object();
+        right.put(DataElement::new(
+            Tag(0x7FE0, 0x0010),
+            VR::OW,
+            PrimitiveValue::U16(smallvec![1, 2, 3, 5]).into(),
+        ));
+
+        let options = DiffOptions::new().pixel_data_by_hash(true);
+        let differences = diff(&left, &right, &options);
+        assert_eq!(differences.len(), 1);
+        match &differences[0].kind {
+            DifferenceKind::Changed(l, r) => {
+                assert_eq!(
+                    l.value,
+                    "sha256:ea99f710d9d0b8ba192295c969a63ed7ce8fc5743da20d2057fa2b6d2c404bfb"
+                );
+                assert!(r.value.starts_with("sha256:"));
+                assert_ne!(l.value, r.value);
+            }
+            kind => panic!("unexpected difference {:?}", kind),
+        }
+    }
+}
@@ -2,6 +2,8 @@ use crate::DefaultDicomObject;
 use dicom_parser::error::Result;
 use std::io::Read;
 use std::path::Path;
+#[cfg(feature = "async")]
+use tokio::io::AsyncRead;
 
 /// Create a DICOM object by reading from a byte source.
 ///
@@ -9,7 +11,7 @@ use std::path::Path;
 /// preamble: file meta group, followed by the rest of the data set.
 pub fn from_reader<F>(file: F) -> Result<DefaultDicomObject>
 where
-    F: Read,
+    F: Read + 'static,
 {
     DefaultDicomObject::from_reader(file)
 }
@@ -24,3 +26,27 @@ where
 {
     DefaultDicomObject::open_file(path)
 }
+
+/// Create a DICOM object by reading from an asynchronous byte source.
+///
+/// This function assumes the standard file encoding structure without the
+/// preamble: file meta group, followed by the rest of the data set.
+#[cfg(feature = "async")]
+pub async fn from_async_reader<F>(file: F) -> Result<DefaultDicomObject>
+where
+    F: AsyncRead + Unpin,
+{
+    DefaultDicomObject::from_async_reader(file).await
+}
+
+/// Create a DICOM object by reading from a file asynchronously.
+///
+/// This function assumes the standard file encoding structure: 128-byte
+/// preamble, file meta group, and the rest of the data set.
+#[cfg(feature = "async")]
+pub async fn open_file_async<P>(path: P) -> Result<DefaultDicomObject>
+where
+    P: AsRef<Path>,
+{
+    DefaultDicomObject::open_file_async(path).await
+}
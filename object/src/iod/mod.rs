@@ -0,0 +1,561 @@
+//! This module implements the validation of DICOM objects against
+//! information object definitions (IODs), as described in [PS3.3][1].
+//!
+//! An IOD is composed of modules, each with a usage requirement
+//! (mandatory, conditional or user optional). Each module defines a set of
+//! attributes, each with an attribute type (1, 1C, 2, 2C or 3), and
+//! possibly the attributes expected in the items of a sequence.
+//! Validating an object produces a [`ValidationReport`] with one
+//! [`Finding`] for each unfulfilled requirement.
+//!
+//! The definitions in [`modules`] cover the main modules of the
+//! CT, MR, CR, DX, US, SC, SR and enhanced multi-frame (CT and MR) IODs.
+//! Conditions which cannot be decided from the object alone are not
+//! evaluated.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use dicom_object::open_file;
+//! # use dicom_object::iod;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let obj = open_file("0001.dcm")?;
+//! match iod::validate(&obj) {
+//!     Some(report) => {
+//!         for finding in &report.findings {
+//!             println!("{}", finding);
+//!         }
+//!     }
+//!     None => println!("Unsupported SOP class"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [1]: http://dicom.nema.org/medical/dicom/current/output/chtml/part03/PS3.3.html
+use crate::mem::{InMemDicomObject, InMemElement};
+use crate::util::value_to_strings;
+use dicom_core::dictionary::DataDictionary;
+use dicom_core::value::{PrimitiveValue, Value};
+use dicom_core::Tag;
+use dicom_dictionary_std::StandardDataDictionary;
+use std::fmt;
+
+pub mod modules;
+
+/// The SOP Class UID attribute, used to identify the object's IOD.
+const SOP_CLASS_UID: Tag = Tag(0x0008, 0x0016);
+
+/// An attribute type, as defined in PS3.5 section 7.4.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttributeType {
+    /// Required attribute, which must have a non-empty value.
+    Type1,
+    /// Conditionally required attribute, which must have a non-empty value
+    /// when the condition is met.
+    Type1C,
+    /// Required attribute, which may be empty.
+    Type2,
+    /// Conditionally required attribute, which may be empty.
+    Type2C,
+    /// Optional attribute.
+    Type3,
+}
+
+impl fmt::Display for AttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let txt = match self {
+            AttributeType::Type1 => "1",
+            AttributeType::Type1C => "1C",
+            AttributeType::Type2 => "2",
+            AttributeType::Type2C => "2C",
+            AttributeType::Type3 => "3",
+        };
+        f.write_str(txt)
+    }
+}
+
+/// A condition over the attributes of a data set (or sequence item),
+/// used by conditional attributes and conditional modules.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Condition {
+    /// The attribute is present.
+    Present(Tag),
+    /// The attribute is absent.
+    Absent(Tag),
+    /// The attribute is present and one of its values
+    /// is equal to one of the given values.
+    ValueIn(Tag, &'static [&'static str]),
+    /// The inner condition is not met.
+    Not(&'static Condition),
+    /// All of the inner conditions are met.
+    All(&'static [Condition]),
+    /// At least one of the inner conditions is met.
+    Any(&'static [Condition]),
+}
+
+impl Condition {
+    /// Evaluate this condition against the given object.
+    pub fn eval<D>(&self, obj: &InMemDicomObject<D>) -> bool
+    where
+        D: DataDictionary,
+        D: Clone,
+    {
+        match self {
+            Condition::Present(tag) => obj.element(*tag).is_ok(),
+            Condition::Absent(tag) => obj.element(*tag).is_err(),
+            Condition::ValueIn(tag, values) => match obj.element(*tag) {
+                Ok(elem) => match elem.value() {
+                    Value::Primitive(v) => value_to_strings(elem.vr(), v)
+                        .iter()
+                        .any(|v| values.contains(&v.trim())),
+                    Value::Sequence { .. } => false,
+                },
+                Err(_) => false,
+            },
+            Condition::Not(c) => !c.eval(obj),
+            Condition::All(cs) => cs.iter().all(|c| c.eval(obj)),
+            Condition::Any(cs) => cs.iter().any(|c| c.eval(obj)),
+        }
+    }
+}
+
+/// The definition of an attribute in a module.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AttributeDefinition {
+    /// The attribute tag.
+    pub tag: Tag,
+    /// The attribute type.
+    pub attribute_type: AttributeType,
+    /// The condition of a type 1C or 2C attribute. Conditional attributes
+    /// without a condition are never required.
+    pub condition: Option<Condition>,
+    /// The attributes expected in each item, if this is a sequence.
+    pub items: &'static [AttributeDefinition],
+}
+
+impl AttributeDefinition {
+    /// Create a new attribute definition without a condition.
+    pub const fn new(tag: Tag, attribute_type: AttributeType) -> Self {
+        AttributeDefinition {
+            tag,
+            attribute_type,
+            condition: None,
+            items: &[],
+        }
+    }
+
+    /// Set the condition under which this attribute is required.
+    pub const fn when(self, condition: Condition) -> Self {
+        AttributeDefinition {
+            condition: Some(condition),
+            ..self
+        }
+    }
+
+    /// Set the attributes expected in each item of this sequence.
+    pub const fn with_items(self, items: &'static [AttributeDefinition]) -> Self {
+        AttributeDefinition { items, ..self }
+    }
+
+    /// The attribute keyword, as found in the standard data dictionary.
+    pub fn keyword(&self) -> &'static str {
+        StandardDataDictionary
+            .by_tag(self.tag)
+            .map_or("", |e| e.alias)
+    }
+}
+
+/// The definition of a module.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ModuleDefinition {
+    /// The module name.
+    pub name: &'static str,
+    /// The attributes of the module.
+    pub attributes: &'static [AttributeDefinition],
+}
+
+/// The usage requirement of a module in an IOD.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ModuleUsage {
+    /// The module is mandatory.
+    Mandatory,
+    /// The module is mandatory when the condition is met,
+    /// and user optional otherwise.
+    Conditional(Condition),
+    /// The module is user optional. It is only validated if
+    /// at least one of its attributes is present.
+    UserOptional,
+}
+
+/// A module and its usage in an IOD.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IodModule {
+    /// The module definition.
+    pub module: &'static ModuleDefinition,
+    /// The usage requirement of the module.
+    pub usage: ModuleUsage,
+}
+
+/// The definition of an information object.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IodDefinition {
+    /// The IOD name.
+    pub name: &'static str,
+    /// The SOP classes using this IOD.
+    pub sop_class_uids: &'static [&'static str],
+    /// The modules of this IOD.
+    pub modules: &'static [IodModule],
+}
+
+impl IodDefinition {
+    /// Validate the given object against this IOD.
+    pub fn validate<D>(&self, obj: &InMemDicomObject<D>) -> ValidationReport
+    where
+        D: DataDictionary,
+        D: Clone,
+    {
+        let mut findings = Vec::new();
+        for m in self.modules {
+            let required = match &m.usage {
+                ModuleUsage::Mandatory => true,
+                ModuleUsage::Conditional(c) => c.eval(obj),
+                ModuleUsage::UserOptional => false,
+            };
+            let present = m
+                .module
+                .attributes
+                .iter()
+                .any(|a| obj.element(a.tag).is_ok());
+            if required || present {
+                check_attributes(
+                    obj,
+                    m.module.name,
+                    m.module.attributes,
+                    &mut Vec::new(),
+                    &mut findings,
+                );
+            }
+        }
+        ValidationReport {
+            iod: self.name,
+            findings,
+        }
+    }
+}
+
+/// Retrieve the IOD definition for the given SOP class UID, if it is
+/// known. Trailing padding in the UID is ignored.
+pub fn iod_for_sop_class(uid: &str) -> Option<&'static IodDefinition> {
+    let uid = uid.trim_end_matches([' ', '\0']);
+    modules::IODS
+        .iter()
+        .cloned()
+        .find(|iod| iod.sop_class_uids.contains(&uid))
+}
+
+/// Validate the given object against the IOD of its SOP class.
+///
+/// Returns `None` if the object has no SOP Class UID or if the SOP class
+/// is not supported.
+pub fn validate<D>(obj: &InMemDicomObject<D>) -> Option<ValidationReport>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    let uid = obj.element(SOP_CLASS_UID).ok()?.to_str().ok()?;
+    let iod = iod_for_sop_class(&uid)?;
+    Some(iod.validate(obj))
+}
+
+/// The outcome of validating an object against an IOD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationReport {
+    /// The name of the IOD used for validation.
+    pub iod: &'static str,
+    /// The problems found.
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    /// Whether no problems were found.
+    pub fn is_valid(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+/// The kind of problem found in a validation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FindingKind {
+    /// A type 1 attribute is missing.
+    MissingType1,
+    /// A type 1 attribute is present but empty.
+    EmptyType1,
+    /// A type 2 attribute is missing.
+    MissingType2,
+    /// The condition of a type 1C or 2C attribute is met,
+    /// but the attribute is missing (or empty, if type 1C).
+    UnsatisfiedCondition,
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let txt = match self {
+            FindingKind::MissingType1 => "missing type 1 attribute",
+            FindingKind::EmptyType1 => "empty type 1 attribute",
+            FindingKind::MissingType2 => "missing type 2 attribute",
+            FindingKind::UnsatisfiedCondition => "unsatisfied conditional attribute",
+        };
+        f.write_str(txt)
+    }
+}
+
+/// A step in the path to a nested attribute:
+/// a sequence attribute and the index of an item in it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PathSegment {
+    /// The sequence attribute tag.
+    pub tag: Tag,
+    /// The item index, starting at 0.
+    pub item: usize,
+}
+
+/// A problem found in a validation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    /// The sequence items leading to the attribute,
+    /// empty if the attribute is in the root data set.
+    pub path: Vec<PathSegment>,
+    /// The attribute tag.
+    pub tag: Tag,
+    /// The attribute keyword.
+    pub name: &'static str,
+    /// The attribute type.
+    pub attribute_type: AttributeType,
+    /// The module defining the attribute.
+    pub module: &'static str,
+    /// The kind of problem.
+    pub kind: FindingKind,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for segment in &self.path {
+            write!(f, "{}[{}].", segment.tag, segment.item)?;
+        }
+        write!(
+            f,
+            "{} {}: {} ({})",
+            self.tag, self.name, self.kind, self.module
+        )
+    }
+}
+
+fn check_attributes<D>(
+    obj: &InMemDicomObject<D>,
+    module: &'static str,
+    attributes: &'static [AttributeDefinition],
+    path: &mut Vec<PathSegment>,
+    findings: &mut Vec<Finding>,
+) where
+    D: DataDictionary,
+    D: Clone,
+{
+    for attr in attributes {
+        let elem = obj.element(attr.tag).ok();
+        let condition_met = attr.condition.map(|c| c.eval(obj)).unwrap_or(false);
+        let kind = match (attr.attribute_type, elem) {
+            (AttributeType::Type1, None) => Some(FindingKind::MissingType1),
+            (AttributeType::Type1, Some(e)) if is_empty(e) => Some(FindingKind::EmptyType1),
+            (AttributeType::Type2, None) => Some(FindingKind::MissingType2),
+            (AttributeType::Type1C, None) | (AttributeType::Type2C, None) if condition_met => {
+                Some(FindingKind::UnsatisfiedCondition)
+            }
+            (AttributeType::Type1C, Some(e)) if condition_met && is_empty(e) => {
+                Some(FindingKind::UnsatisfiedCondition)
+            }
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            findings.push(Finding {
+                path: path.clone(),
+                tag: attr.tag,
+                name: attr.keyword(),
+                attribute_type: attr.attribute_type,
+                module,
+                kind,
+            });
+        }
+
+        if attr.items.is_empty() {
+            continue;
+        }
+        if let Some(items) = elem.and_then(|e| e.value().item()) {
+            for (i, item) in items.iter().enumerate() {
+                path.push(PathSegment {
+                    tag: attr.tag,
+                    item: i,
+                });
+                check_attributes(item, module, attr.items, path, findings);
+                path.pop();
+            }
+        }
+    }
+}
+
+/// Whether the element has no value, or only blank values.
+fn is_empty<D>(elem: &InMemElement<D>) -> bool {
+    match elem.value() {
+        Value::Primitive(PrimitiveValue::Str(s)) => s.trim_matches([' ', '\0']).is_empty(),
+        Value::Primitive(PrimitiveValue::Strs(c)) => {
+            c.iter().all(|s| s.trim_matches([' ', '\0']).is_empty())
+        }
+        Value::Primitive(v) => v.multiplicity() == 0,
+        Value::Sequence { items, .. } => items.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::put_strs;
+    use dicom_core::{DataElement, PrimitiveValue, VR};
+    use dicom_dictionary_std::StandardDataDictionary;
+    use smallvec::smallvec;
+
+    type Obj = InMemDicomObject<StandardDataDictionary>;
+
+    fn sc_object() -> Obj {
+        let mut obj = InMemDicomObject::create_empty();
+        put_strs(
+            &mut obj,
+            Tag(0x0008, 0x0016),
+            VR::UI,
+            &["1.2.840.10008.5.1.4.1.1.7"],
+        );
+        put_strs(&mut obj, Tag(0x0008, 0x0018), VR::UI, &["1.2.3.4.5"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0020), VR::DA, &["20190101"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0030), VR::TM, &["120000"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0050), VR::SH, &[""]);
+        put_strs(&mut obj, Tag(0x0008, 0x0060), VR::CS, &["OT"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0064), VR::CS, &["WSD"]);
+        put_strs(&mut obj, Tag(0x0008, 0x0090), VR::PN, &[""]);
+        put_strs(&mut obj, Tag(0x0010, 0x0010), VR::PN, &["Doe^John"]);
+        put_strs(&mut obj, Tag(0x0010, 0x0020), VR::LO, &["1234"]);
+        put_strs(&mut obj, Tag(0x0010, 0x0030), VR::DA, &[""]);
+        put_strs(&mut obj, Tag(0x0010, 0x0040), VR::CS, &["O"]);
+        put_strs(&mut obj, Tag(0x0020, 0x000D), VR::UI, &["1.2.3.4"]);
+        put_strs(&mut obj, Tag(0x0020, 0x000E), VR::UI, &["1.2.3.4.1"]);
+        put_strs(&mut obj, Tag(0x0020, 0x0010), VR::SH, &["1"]);
+        put_strs(&mut obj, Tag(0x0020, 0x0011), VR::IS, &["1"]);
+        put_strs(&mut obj, Tag(0x0020, 0x0013), VR::IS, &["1"]);
+        put_strs(&mut obj, Tag(0x0020, 0x0020), VR::CS, &[""]);
+        put_strs(&mut obj, Tag(0x0028, 0x0004), VR::CS, &["MONOCHROME2"]);
+        for &(e, v) in &[
+            (0x0002, 1),
+            (0x0010, 2),
+            (0x0011, 2),
+            (0x0100, 8),
+            (0x0101, 8),
+            (0x0102, 7),
+            (0x0103, 0),
+        ] {
+            obj.put(DataElement::new(
+                Tag(0x0028, e),
+                VR::US,
+                PrimitiveValue::U16(smallvec![v]).into(),
+            ));
+        }
+        obj.put(DataElement::new(
+            Tag(0x7FE0, 0x0010),
+            VR::OB,
+            PrimitiveValue::U8(smallvec![0; 4]).into(),
+        ));
+        obj
+    }
+
+    #[test]
+    fn valid_secondary_capture() {
+        let obj = sc_object();
+        let report = validate(&obj).unwrap();
+        assert_eq!(report.iod, "Secondary Capture Image");
+        assert_eq!(report.findings, vec![]);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn missing_and_empty_attributes() {
+        let mut obj = sc_object();
+        obj.put(DataElement::empty(Tag(0x0008, 0x0064), VR::CS));
+        // a UID with only its padding is empty
+        put_strs(&mut obj, Tag(0x0020, 0x000D), VR::UI, &["\0"]);
+        // RGB image without planar configuration
+        obj.put(DataElement::new(
+            Tag(0x0028, 0x0002),
+            VR::US,
+            PrimitiveValue::U16(smallvec![3]).into(),
+        ));
+
+        let report = validate(&obj).unwrap();
+        let kinds: Vec<_> = report.findings.iter().map(|f| (f.tag, f.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (Tag(0x0020, 0x000D), FindingKind::EmptyType1),
+                (Tag(0x0008, 0x0064), FindingKind::EmptyType1),
+                (Tag(0x0028, 0x0006), FindingKind::UnsatisfiedCondition),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_findings_have_paths() {
+        let mut obj = InMemDicomObject::create_empty();
+        put_strs(&mut obj, Tag(0x0040, 0xA493), VR::CS, &["VERIFIED"]);
+        let mut observer = InMemDicomObject::create_empty();
+        put_strs(&mut observer, Tag(0x0040, 0xA075), VR::PN, &["Smith^Jane"]);
+        obj.put(DataElement::new(
+            Tag(0x0040, 0xA073),
+            VR::SQ,
+            Value::Sequence {
+                items: smallvec![observer],
+                size: dicom_core::Length::UNDEFINED,
+            },
+        ));
+
+        let mut findings = Vec::new();
+        check_attributes(
+            &obj,
+            modules::SR_DOCUMENT_GENERAL.name,
+            modules::SR_DOCUMENT_GENERAL.attributes,
+            &mut Vec::new(),
+            &mut findings,
+        );
+        let finding = findings
+            .iter()
+            .find(|f| f.tag == Tag(0x0040, 0xA030))
+            .unwrap();
+        assert_eq!(finding.kind, FindingKind::MissingType1);
+        assert_eq!(
+            finding.path,
+            vec![PathSegment {
+                tag: Tag(0x0040, 0xA073),
+                item: 0
+            }]
+        );
+        assert_eq!(
+            finding.to_string(),
+            "(0040,A073)[0].(0040,A030) VerificationDateTime: \
+             missing type 1 attribute (SR Document General)"
+        );
+    }
+
+    #[test]
+    fn unknown_sop_class() {
+        let mut obj = InMemDicomObject::create_empty();
+        assert!(validate(&obj).is_none());
+        put_strs(&mut obj, Tag(0x0008, 0x0016), VR::UI, &["1.2.3"]);
+        assert!(validate(&obj).is_none());
+    }
+}
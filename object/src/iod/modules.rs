@@ -0,0 +1,605 @@
+//! Definitions of the modules and IODs supported for validation.
+//!
+//! The attributes listed here are taken from PS3.3. Type 3 attributes are
+//! only listed where they help detect the presence of a user optional module.
+use super::AttributeType::*;
+use super::Condition::*;
+use super::ModuleUsage::*;
+use super::{AttributeDefinition as A, IodDefinition, IodModule, ModuleDefinition};
+use dicom_dictionary_std::tags;
+
+/// Patient Module (C.7.1.1)
+pub static PATIENT: ModuleDefinition = ModuleDefinition {
+    name: "Patient",
+    attributes: &[
+        A::new(tags::PATIENT_NAME, Type2),
+        A::new(tags::PATIENT_ID, Type2),
+        A::new(tags::PATIENT_BIRTH_DATE, Type2),
+        A::new(tags::PATIENT_SEX, Type2),
+    ],
+};
+
+/// General Study Module (C.7.2.1)
+pub static GENERAL_STUDY: ModuleDefinition = ModuleDefinition {
+    name: "General Study",
+    attributes: &[
+        A::new(tags::STUDY_INSTANCE_UID, Type1),
+        A::new(tags::STUDY_DATE, Type2),
+        A::new(tags::STUDY_TIME, Type2),
+        A::new(tags::REFERRING_PHYSICIAN_NAME, Type2),
+        A::new(tags::STUDY_ID, Type2),
+        A::new(tags::ACCESSION_NUMBER, Type2),
+        A::new(tags::STUDY_DESCRIPTION, Type3),
+    ],
+};
+
+/// General Series Module (C.7.3.1)
+pub static GENERAL_SERIES: ModuleDefinition = ModuleDefinition {
+    name: "General Series",
+    attributes: &[
+        A::new(tags::MODALITY, Type1),
+        A::new(tags::SERIES_INSTANCE_UID, Type1),
+        A::new(tags::SERIES_NUMBER, Type2),
+        A::new(tags::PATIENT_POSITION, Type2C).when(All(&[
+            Absent(tags::PATIENT_ORIENTATION_CODE_SEQUENCE),
+            ValueIn(tags::MODALITY, &["CT", "MR"]),
+        ])),
+        A::new(tags::SERIES_DESCRIPTION, Type3),
+    ],
+};
+
+/// Frame of Reference Module (C.7.4.1)
+pub static FRAME_OF_REFERENCE: ModuleDefinition = ModuleDefinition {
+    name: "Frame of Reference",
+    attributes: &[
+        A::new(tags::FRAME_OF_REFERENCE_UID, Type1),
+        A::new(tags::POSITION_REFERENCE_INDICATOR, Type2),
+    ],
+};
+
+/// General Equipment Module (C.7.5.1)
+pub static GENERAL_EQUIPMENT: ModuleDefinition = ModuleDefinition {
+    name: "General Equipment",
+    attributes: &[
+        A::new(tags::MANUFACTURER, Type2),
+        A::new(tags::INSTITUTION_NAME, Type3),
+        A::new(tags::STATION_NAME, Type3),
+        A::new(tags::MANUFACTURER_MODEL_NAME, Type3),
+        A::new(tags::SOFTWARE_VERSIONS, Type3),
+    ],
+};
+
+/// Enhanced General Equipment Module (C.7.5.2)
+pub static ENHANCED_GENERAL_EQUIPMENT: ModuleDefinition = ModuleDefinition {
+    name: "Enhanced General Equipment",
+    attributes: &[
+        A::new(tags::MANUFACTURER, Type1),
+        A::new(tags::MANUFACTURER_MODEL_NAME, Type1),
+        A::new(tags::DEVICE_SERIAL_NUMBER, Type1),
+        A::new(tags::SOFTWARE_VERSIONS, Type1),
+    ],
+};
+
+/// General Image Module (C.7.6.1)
+pub static GENERAL_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "General Image",
+    attributes: &[
+        A::new(tags::INSTANCE_NUMBER, Type2),
+        A::new(tags::PATIENT_ORIENTATION, Type2C).when(Absent(tags::IMAGE_ORIENTATION_PATIENT)),
+        A::new(tags::IMAGE_TYPE, Type3),
+    ],
+};
+
+/// Image Plane Module (C.7.6.2)
+pub static IMAGE_PLANE: ModuleDefinition = ModuleDefinition {
+    name: "Image Plane",
+    attributes: &[
+        A::new(tags::PIXEL_SPACING, Type1),
+        A::new(tags::IMAGE_ORIENTATION_PATIENT, Type1),
+        A::new(tags::IMAGE_POSITION_PATIENT, Type1),
+        A::new(tags::SLICE_THICKNESS, Type2),
+        A::new(tags::SLICE_LOCATION, Type3),
+    ],
+};
+
+const PALETTE_COLOR: super::Condition =
+    ValueIn(tags::PHOTOMETRIC_INTERPRETATION, &["PALETTE COLOR"]);
+
+/// Image Pixel Module (C.7.6.3)
+pub static IMAGE_PIXEL: ModuleDefinition = ModuleDefinition {
+    name: "Image Pixel",
+    attributes: &[
+        A::new(tags::SAMPLES_PER_PIXEL, Type1),
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::ROWS, Type1),
+        A::new(tags::COLUMNS, Type1),
+        A::new(tags::BITS_ALLOCATED, Type1),
+        A::new(tags::BITS_STORED, Type1),
+        A::new(tags::HIGH_BIT, Type1),
+        A::new(tags::PIXEL_REPRESENTATION, Type1),
+        A::new(tags::PLANAR_CONFIGURATION, Type1C).when(All(&[
+            Present(tags::SAMPLES_PER_PIXEL),
+            Not(&ValueIn(tags::SAMPLES_PER_PIXEL, &["1"])),
+        ])),
+        A::new(tags::PIXEL_DATA, Type1C).when(All(&[
+            Absent(tags::FLOAT_PIXEL_DATA),
+            Absent(tags::DOUBLE_FLOAT_PIXEL_DATA),
+        ])),
+        A::new(tags::RED_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR, Type1C).when(PALETTE_COLOR),
+        A::new(tags::GREEN_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR, Type1C).when(PALETTE_COLOR),
+        A::new(tags::BLUE_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR, Type1C).when(PALETTE_COLOR),
+        A::new(tags::RED_PALETTE_COLOR_LOOKUP_TABLE_DATA, Type1C).when(PALETTE_COLOR),
+        A::new(tags::GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA, Type1C).when(PALETTE_COLOR),
+        A::new(tags::BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA, Type1C).when(PALETTE_COLOR),
+    ],
+};
+
+/// Multi-frame Module (C.7.6.6)
+pub static MULTI_FRAME: ModuleDefinition = ModuleDefinition {
+    name: "Multi-frame",
+    attributes: &[
+        A::new(tags::NUMBER_OF_FRAMES, Type1),
+        A::new(tags::FRAME_INCREMENT_POINTER, Type1),
+    ],
+};
+
+/// CT Image Module (C.8.2.1)
+pub static CT_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "CT Image",
+    attributes: &[
+        A::new(tags::IMAGE_TYPE, Type1),
+        A::new(tags::SAMPLES_PER_PIXEL, Type1),
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::BITS_ALLOCATED, Type1),
+        A::new(tags::BITS_STORED, Type1),
+        A::new(tags::HIGH_BIT, Type1),
+        A::new(tags::RESCALE_INTERCEPT, Type1),
+        A::new(tags::RESCALE_SLOPE, Type1),
+        A::new(tags::KVP, Type2),
+        A::new(tags::ACQUISITION_NUMBER, Type2),
+    ],
+};
+
+/// MR Image Module (C.8.3.1)
+pub static MR_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "MR Image",
+    attributes: &[
+        A::new(tags::IMAGE_TYPE, Type1),
+        A::new(tags::SAMPLES_PER_PIXEL, Type1),
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::BITS_ALLOCATED, Type1),
+        A::new(tags::SCANNING_SEQUENCE, Type1),
+        A::new(tags::SEQUENCE_VARIANT, Type1),
+        A::new(tags::SCAN_OPTIONS, Type2),
+        A::new(tags::MR_ACQUISITION_TYPE, Type2),
+        A::new(tags::REPETITION_TIME, Type2C).when(Not(&ValueIn(tags::SCANNING_SEQUENCE, &["EP"]))),
+        A::new(tags::ECHO_TIME, Type2),
+        A::new(tags::ECHO_TRAIN_LENGTH, Type2),
+        A::new(tags::INVERSION_TIME, Type2C).when(ValueIn(tags::SCANNING_SEQUENCE, &["IR"])),
+        A::new(tags::MAGNETIC_FIELD_STRENGTH, Type3),
+    ],
+};
+
+/// CR Series Module (C.8.1.1)
+pub static CR_SERIES: ModuleDefinition = ModuleDefinition {
+    name: "CR Series",
+    attributes: &[
+        A::new(tags::BODY_PART_EXAMINED, Type2),
+        A::new(tags::VIEW_POSITION, Type2),
+    ],
+};
+
+/// CR Image Module (C.8.1.2)
+pub static CR_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "CR Image",
+    attributes: &[
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::KVP, Type3),
+        A::new(tags::IMAGER_PIXEL_SPACING, Type3),
+    ],
+};
+
+/// DX Series Module (C.8.11.1)
+pub static DX_SERIES: ModuleDefinition = ModuleDefinition {
+    name: "DX Series",
+    attributes: &[
+        A::new(tags::MODALITY, Type1),
+        A::new(tags::PRESENTATION_INTENT_TYPE, Type1),
+    ],
+};
+
+/// DX Anatomy Imaged Module (C.8.11.2)
+pub static DX_ANATOMY_IMAGED: ModuleDefinition = ModuleDefinition {
+    name: "DX Anatomy Imaged",
+    attributes: &[A::new(tags::IMAGE_LATERALITY, Type1)],
+};
+
+const FOR_PRESENTATION: super::Condition =
+    ValueIn(tags::PRESENTATION_INTENT_TYPE, &["FOR PRESENTATION"]);
+
+/// DX Image Module (C.8.11.3)
+pub static DX_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "DX Image",
+    attributes: &[
+        A::new(tags::IMAGE_TYPE, Type1),
+        A::new(tags::SAMPLES_PER_PIXEL, Type1),
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::BITS_ALLOCATED, Type1),
+        A::new(tags::BITS_STORED, Type1),
+        A::new(tags::HIGH_BIT, Type1),
+        A::new(tags::PIXEL_REPRESENTATION, Type1),
+        A::new(tags::PIXEL_INTENSITY_RELATIONSHIP, Type1),
+        A::new(tags::PIXEL_INTENSITY_RELATIONSHIP_SIGN, Type1),
+        A::new(tags::RESCALE_INTERCEPT, Type1),
+        A::new(tags::RESCALE_SLOPE, Type1),
+        A::new(tags::RESCALE_TYPE, Type1),
+        A::new(tags::PRESENTATION_LUT_SHAPE, Type1C).when(FOR_PRESENTATION),
+        A::new(tags::LOSSY_IMAGE_COMPRESSION, Type1),
+        A::new(tags::BURNED_IN_ANNOTATION, Type1),
+        A::new(tags::WINDOW_CENTER, Type1C)
+            .when(All(&[FOR_PRESENTATION, Absent(tags::VOILUT_SEQUENCE)])),
+        A::new(tags::WINDOW_WIDTH, Type1C)
+            .when(All(&[FOR_PRESENTATION, Absent(tags::VOILUT_SEQUENCE)])),
+    ],
+};
+
+/// DX Detector Module (C.8.11.4)
+pub static DX_DETECTOR: ModuleDefinition = ModuleDefinition {
+    name: "DX Detector",
+    attributes: &[
+        A::new(tags::DETECTOR_TYPE, Type2),
+        A::new(tags::IMAGER_PIXEL_SPACING, Type1),
+    ],
+};
+
+/// US Image Module (C.8.5.6)
+pub static US_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "US Image",
+    attributes: &[
+        A::new(tags::SAMPLES_PER_PIXEL, Type1),
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::BITS_ALLOCATED, Type1),
+        A::new(tags::BITS_STORED, Type1),
+        A::new(tags::HIGH_BIT, Type1),
+        A::new(tags::PLANAR_CONFIGURATION, Type1C).when(All(&[
+            Present(tags::SAMPLES_PER_PIXEL),
+            Not(&ValueIn(tags::SAMPLES_PER_PIXEL, &["1"])),
+        ])),
+        A::new(tags::PIXEL_REPRESENTATION, Type1),
+        A::new(tags::IMAGE_TYPE, Type2),
+    ],
+};
+
+/// SC Equipment Module (C.8.6.1)
+pub static SC_EQUIPMENT: ModuleDefinition = ModuleDefinition {
+    name: "SC Equipment",
+    attributes: &[
+        A::new(tags::CONVERSION_TYPE, Type1),
+        A::new(tags::MODALITY, Type3),
+    ],
+};
+
+/// SC Image Module (C.8.6.2)
+pub static SC_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "SC Image",
+    attributes: &[
+        A::new(tags::DATE_OF_SECONDARY_CAPTURE, Type3),
+        A::new(tags::TIME_OF_SECONDARY_CAPTURE, Type3),
+    ],
+};
+
+/// SR Document Series Module (C.17.1)
+pub static SR_DOCUMENT_SERIES: ModuleDefinition = ModuleDefinition {
+    name: "SR Document Series",
+    attributes: &[
+        A::new(tags::MODALITY, Type1),
+        A::new(tags::SERIES_INSTANCE_UID, Type1),
+        A::new(tags::SERIES_NUMBER, Type1),
+        A::new(tags::REFERENCED_PERFORMED_PROCEDURE_STEP_SEQUENCE, Type2),
+    ],
+};
+
+/// SR Document General Module (C.17.2)
+pub static SR_DOCUMENT_GENERAL: ModuleDefinition = ModuleDefinition {
+    name: "SR Document General",
+    attributes: &[
+        A::new(tags::INSTANCE_NUMBER, Type1),
+        A::new(tags::COMPLETION_FLAG, Type1),
+        A::new(tags::VERIFICATION_FLAG, Type1),
+        A::new(tags::CONTENT_DATE, Type1),
+        A::new(tags::CONTENT_TIME, Type1),
+        A::new(tags::VERIFYING_OBSERVER_SEQUENCE, Type1C)
+            .when(ValueIn(tags::VERIFICATION_FLAG, &["VERIFIED"]))
+            .with_items(&[
+                A::new(tags::VERIFYING_OBSERVER_NAME, Type1),
+                A::new(tags::VERIFYING_OBSERVER_IDENTIFICATION_CODE_SEQUENCE, Type2),
+                A::new(tags::VERIFYING_ORGANIZATION, Type1),
+                A::new(tags::VERIFICATION_DATE_TIME, Type1),
+            ]),
+        A::new(tags::PERFORMED_PROCEDURE_CODE_SEQUENCE, Type2),
+    ],
+};
+
+/// Code Sequence Macro (Table 8.8-1)
+static CODE_SEQUENCE_MACRO: &[A] = &[
+    A::new(tags::CODE_VALUE, Type1C).when(All(&[
+        Absent(tags::LONG_CODE_VALUE),
+        Absent(tags::URN_CODE_VALUE),
+    ])),
+    A::new(tags::CODING_SCHEME_DESIGNATOR, Type1C).when(Present(tags::CODE_VALUE)),
+    A::new(tags::CODE_MEANING, Type1),
+];
+
+/// SR Document Content Module (C.17.3)
+pub static SR_DOCUMENT_CONTENT: ModuleDefinition = ModuleDefinition {
+    name: "SR Document Content",
+    attributes: &[
+        A::new(tags::VALUE_TYPE, Type1),
+        A::new(tags::CONCEPT_NAME_CODE_SEQUENCE, Type1).with_items(CODE_SEQUENCE_MACRO),
+        A::new(tags::CONTINUITY_OF_CONTENT, Type1C).when(ValueIn(tags::VALUE_TYPE, &["CONTAINER"])),
+    ],
+};
+
+/// Multi-frame Functional Groups Module (C.7.6.16)
+pub static MULTI_FRAME_FUNCTIONAL_GROUPS: ModuleDefinition = ModuleDefinition {
+    name: "Multi-frame Functional Groups",
+    attributes: &[
+        A::new(tags::SHARED_FUNCTIONAL_GROUPS_SEQUENCE, Type2),
+        A::new(tags::PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE, Type1),
+        A::new(tags::INSTANCE_NUMBER, Type1),
+        A::new(tags::CONTENT_DATE, Type1),
+        A::new(tags::CONTENT_TIME, Type1),
+        A::new(tags::NUMBER_OF_FRAMES, Type1),
+    ],
+};
+
+/// Multi-frame Dimension Module (C.7.6.17)
+pub static MULTI_FRAME_DIMENSION: ModuleDefinition = ModuleDefinition {
+    name: "Multi-frame Dimension",
+    attributes: &[
+        A::new(tags::DIMENSION_ORGANIZATION_SEQUENCE, Type1)
+            .with_items(&[A::new(tags::DIMENSION_ORGANIZATION_UID, Type1)]),
+        A::new(tags::DIMENSION_INDEX_SEQUENCE, Type1)
+            .with_items(&[A::new(tags::DIMENSION_INDEX_POINTER, Type1)]),
+    ],
+};
+
+/// Enhanced CT Image Module (C.8.15.2)
+pub static ENHANCED_CT_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "Enhanced CT Image",
+    attributes: &[
+        A::new(tags::IMAGE_TYPE, Type1),
+        A::new(tags::SAMPLES_PER_PIXEL, Type1),
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::BITS_ALLOCATED, Type1),
+        A::new(tags::BITS_STORED, Type1),
+        A::new(tags::HIGH_BIT, Type1),
+        A::new(tags::CONTENT_QUALIFICATION, Type1),
+        A::new(tags::LOSSY_IMAGE_COMPRESSION, Type1),
+    ],
+};
+
+/// Enhanced MR Image Module (C.8.13.1)
+pub static ENHANCED_MR_IMAGE: ModuleDefinition = ModuleDefinition {
+    name: "Enhanced MR Image",
+    attributes: &[
+        A::new(tags::IMAGE_TYPE, Type1),
+        A::new(tags::SAMPLES_PER_PIXEL, Type1),
+        A::new(tags::PHOTOMETRIC_INTERPRETATION, Type1),
+        A::new(tags::BITS_ALLOCATED, Type1),
+        A::new(tags::BITS_STORED, Type1),
+        A::new(tags::HIGH_BIT, Type1),
+        A::new(tags::CONTENT_QUALIFICATION, Type1),
+        A::new(tags::PIXEL_PRESENTATION, Type1),
+        A::new(tags::VOLUMETRIC_PROPERTIES, Type1),
+        A::new(tags::VOLUME_BASED_CALCULATION_TECHNIQUE, Type1),
+    ],
+};
+
+/// SOP Common Module (C.12.1)
+pub static SOP_COMMON: ModuleDefinition = ModuleDefinition {
+    name: "SOP Common",
+    attributes: &[
+        A::new(tags::SOP_CLASS_UID, Type1),
+        A::new(tags::SOP_INSTANCE_UID, Type1),
+        A::new(tags::INSTANCE_CREATION_DATE, Type3),
+    ],
+};
+
+const fn m(module: &'static ModuleDefinition) -> IodModule {
+    IodModule {
+        module,
+        usage: Mandatory,
+    }
+}
+
+const fn u(module: &'static ModuleDefinition) -> IodModule {
+    IodModule {
+        module,
+        usage: UserOptional,
+    }
+}
+
+/// CT Image IOD (A.3)
+pub static CT_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "CT Image",
+    sop_class_uids: &["1.2.840.10008.5.1.4.1.1.2"],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        m(&FRAME_OF_REFERENCE),
+        m(&GENERAL_EQUIPMENT),
+        m(&GENERAL_IMAGE),
+        m(&IMAGE_PLANE),
+        m(&IMAGE_PIXEL),
+        m(&CT_IMAGE),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// MR Image IOD (A.4)
+pub static MR_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "MR Image",
+    sop_class_uids: &["1.2.840.10008.5.1.4.1.1.4"],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        m(&FRAME_OF_REFERENCE),
+        m(&GENERAL_EQUIPMENT),
+        m(&GENERAL_IMAGE),
+        m(&IMAGE_PLANE),
+        m(&IMAGE_PIXEL),
+        m(&MR_IMAGE),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// Computed Radiography Image IOD (A.2)
+pub static CR_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "Computed Radiography Image",
+    sop_class_uids: &["1.2.840.10008.5.1.4.1.1.1"],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        m(&CR_SERIES),
+        m(&GENERAL_EQUIPMENT),
+        m(&GENERAL_IMAGE),
+        m(&IMAGE_PIXEL),
+        m(&CR_IMAGE),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// Digital X-Ray Image IOD (A.26)
+pub static DX_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "Digital X-Ray Image",
+    sop_class_uids: &[
+        "1.2.840.10008.5.1.4.1.1.1.1",
+        "1.2.840.10008.5.1.4.1.1.1.1.1",
+    ],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        m(&DX_SERIES),
+        u(&FRAME_OF_REFERENCE),
+        m(&GENERAL_EQUIPMENT),
+        m(&GENERAL_IMAGE),
+        m(&IMAGE_PIXEL),
+        m(&DX_ANATOMY_IMAGED),
+        m(&DX_IMAGE),
+        m(&DX_DETECTOR),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// Ultrasound Image and Ultrasound Multi-frame Image IODs (A.6, A.7)
+pub static US_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "Ultrasound Image",
+    sop_class_uids: &["1.2.840.10008.5.1.4.1.1.6.1", "1.2.840.10008.5.1.4.1.1.3.1"],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        u(&FRAME_OF_REFERENCE),
+        m(&GENERAL_EQUIPMENT),
+        m(&GENERAL_IMAGE),
+        m(&IMAGE_PIXEL),
+        IodModule {
+            module: &MULTI_FRAME,
+            usage: Conditional(Present(tags::NUMBER_OF_FRAMES)),
+        },
+        m(&US_IMAGE),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// Secondary Capture Image IOD (A.8.1)
+pub static SC_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "Secondary Capture Image",
+    sop_class_uids: &["1.2.840.10008.5.1.4.1.1.7"],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        u(&GENERAL_EQUIPMENT),
+        m(&SC_EQUIPMENT),
+        m(&GENERAL_IMAGE),
+        m(&IMAGE_PIXEL),
+        m(&SC_IMAGE),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// Basic Text, Enhanced and Comprehensive SR IODs (A.35.1 - A.35.3)
+pub static SR_DOCUMENT_IOD: IodDefinition = IodDefinition {
+    name: "SR Document",
+    sop_class_uids: &[
+        "1.2.840.10008.5.1.4.1.1.88.11",
+        "1.2.840.10008.5.1.4.1.1.88.22",
+        "1.2.840.10008.5.1.4.1.1.88.33",
+    ],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&SR_DOCUMENT_SERIES),
+        m(&GENERAL_EQUIPMENT),
+        m(&SR_DOCUMENT_GENERAL),
+        m(&SR_DOCUMENT_CONTENT),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// Enhanced CT Image IOD (A.38.1)
+pub static ENHANCED_CT_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "Enhanced CT Image",
+    sop_class_uids: &["1.2.840.10008.5.1.4.1.1.2.1"],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        m(&FRAME_OF_REFERENCE),
+        m(&GENERAL_EQUIPMENT),
+        m(&ENHANCED_GENERAL_EQUIPMENT),
+        m(&IMAGE_PIXEL),
+        m(&ENHANCED_CT_IMAGE),
+        m(&MULTI_FRAME_FUNCTIONAL_GROUPS),
+        m(&MULTI_FRAME_DIMENSION),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// Enhanced MR Image IOD (A.36.2)
+pub static ENHANCED_MR_IMAGE_IOD: IodDefinition = IodDefinition {
+    name: "Enhanced MR Image",
+    sop_class_uids: &["1.2.840.10008.5.1.4.1.1.4.1"],
+    modules: &[
+        m(&PATIENT),
+        m(&GENERAL_STUDY),
+        m(&GENERAL_SERIES),
+        m(&FRAME_OF_REFERENCE),
+        m(&GENERAL_EQUIPMENT),
+        m(&ENHANCED_GENERAL_EQUIPMENT),
+        m(&IMAGE_PIXEL),
+        m(&ENHANCED_MR_IMAGE),
+        m(&MULTI_FRAME_FUNCTIONAL_GROUPS),
+        m(&MULTI_FRAME_DIMENSION),
+        m(&SOP_COMMON),
+    ],
+};
+
+/// All supported IODs.
+pub static IODS: &[&IodDefinition] = &[
+    &CT_IMAGE_IOD,
+    &MR_IMAGE_IOD,
+    &CR_IMAGE_IOD,
+    &DX_IMAGE_IOD,
+    &US_IMAGE_IOD,
+    &SC_IMAGE_IOD,
+    &SR_DOCUMENT_IOD,
+    &ENHANCED_CT_IMAGE_IOD,
+    &ENHANCED_MR_IMAGE_IOD,
+];
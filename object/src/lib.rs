@@ -20,28 +20,40 @@
 //! # }
 //! ```
 //!
-//! Elements can also be fetched by tag:
+//! Elements can also be fetched by tag,
+//! for which the constants in [`tags`] can be used:
 //!
 //! ```
 //! # use dicom_object::{DicomObject, Result, Tag};
+//! use dicom_object::tags;
 //! # fn something<T: DicomObject>(obj: T) -> Result<()> {
 //! let e = obj.element(Tag(0x0002, 0x0002))?;
+//! let e = obj.element(tags::PATIENT_NAME)?;
 //! # Ok(())
 //! # }
 //! ```
 //!
+pub mod conformance;
+pub mod dicomdir;
+pub mod diff;
 pub mod file;
+pub mod iod;
 pub mod loader;
 pub mod mem;
 pub mod meta;
 pub mod pixeldata;
+pub mod xml;
 
 mod util;
 
+#[cfg(feature = "async")]
+pub use crate::file::{from_async_reader, open_file_async};
 pub use crate::file::{from_reader, open_file};
 pub use crate::meta::FileMetaTable;
 pub use dicom_core::Tag;
+pub use dicom_dictionary_std::tags;
 pub use dicom_dictionary_std::StandardDataDictionary;
+pub use dicom_parser::dataset::ReadWarning;
 pub use dicom_parser::error::{Error, Result};
 
 /// The default implementation of a root DICOM object.
@@ -81,6 +93,7 @@ pub trait DicomObject {
 pub struct RootDicomObject<T> {
     meta: FileMetaTable,
     obj: T,
+    warnings: Vec<ReadWarning>,
 }
 
 impl<T> RootDicomObject<T> {
@@ -88,6 +101,12 @@ impl<T> RootDicomObject<T> {
     pub fn meta(&self) -> &FileMetaTable {
         &self.meta
     }
+
+    /// Retrieve the warnings about the defects in the data set
+    /// which were recovered from when reading the object.
+    pub fn warnings(&self) -> &[ReadWarning] {
+        &self.warnings
+    }
 }
 
 impl<T> ::std::ops::Deref for RootDicomObject<T> {
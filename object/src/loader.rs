@@ -1,18 +1,24 @@
-use crate::DicomObject;
+use crate::mem::{InMemDicomObject, ReadOptions};
+use crate::RootDicomObject;
+use chrono::FixedOffset;
+use dicom_core::dictionary::DataDictionary;
 use dicom_dictionary_std::StandardDataDictionary;
+use dicom_parser::error::Result;
+use std::io::Read;
 use std::marker::PhantomData;
+use std::path::Path;
+#[cfg(feature = "async")]
+use tokio::io::AsyncRead;
 
 /// A data type
 #[derive(Debug)]
 pub struct DicomLoaderOptions<D, O> {
     dict: D,
+    read_options: ReadOptions,
     phantom: PhantomData<O>,
 }
 
-impl<'s, O> DicomLoaderOptions<StandardDataDictionary, O>
-where
-    O: DicomObject,
-{
+impl<O> DicomLoaderOptions<StandardDataDictionary, O> {
     /// Construct a new DICOM loader with the standard data dictionary.
     pub fn new() -> Self {
         DicomLoaderOptions::default()
@@ -26,18 +32,17 @@ where
     fn default() -> Self {
         DicomLoaderOptions {
             dict: D::default(),
+            read_options: ReadOptions::default(),
             phantom: PhantomData,
         }
     }
 }
 
-impl<'s, D, O> DicomLoaderOptions<D, O>
-where
-    O: DicomObject,
-{
+impl<D, O> DicomLoaderOptions<D, O> {
     pub fn with_dict<NewD>(self, dict: NewD) -> DicomLoaderOptions<NewD, O> {
         DicomLoaderOptions {
             dict,
+            read_options: self.read_options,
             phantom: PhantomData,
         }
     }
@@ -45,4 +50,74 @@ where
     pub fn with_std_dict(self) -> DicomLoaderOptions<StandardDataDictionary, O> {
         self.with_dict(StandardDataDictionary)
     }
+
+    /// Define the offset from UTC of date-time (DT) values which do not
+    /// have an explicit offset, for data sets without the attribute
+    /// _Timezone Offset From UTC_ (0008,0201). The default is UTC.
+    pub fn default_utc_offset(mut self, offset: FixedOffset) -> Self {
+        self.read_options.utc_offset = offset;
+        self
+    }
+
+    /// Enable or disable the lenient mode, in which common defects in the
+    /// data set are recovered from instead of failing. The defects found
+    /// are available in [`RootDicomObject::warnings`].
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.read_options.lenient = lenient;
+        self
+    }
+}
+
+impl<D> DicomLoaderOptions<D, InMemDicomObject<D>>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    /// Load a DICOM object from a file with these options.
+    ///
+    /// This function assumes the standard file encoding structure: 128-byte
+    /// preamble, file meta group, and the rest of the data set.
+    pub fn open_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<RootDicomObject<InMemDicomObject<D>>> {
+        RootDicomObject::open_file_with_options(path, self.dict.clone(), self.read_options)
+    }
+
+    /// Load a DICOM object from a byte source with these options.
+    ///
+    /// This function assumes the standard file encoding structure without the
+    /// preamble: file meta group, followed by the rest of the data set.
+    pub fn from_reader<S>(&self, src: S) -> Result<RootDicomObject<InMemDicomObject<D>>>
+    where
+        S: Read + 'static,
+    {
+        RootDicomObject::from_reader_with_options(src, self.dict.clone(), self.read_options)
+    }
+
+    /// Load a DICOM object from a file asynchronously with these options.
+    ///
+    /// This function assumes the standard file encoding structure: 128-byte
+    /// preamble, file meta group, and the rest of the data set.
+    #[cfg(feature = "async")]
+    pub async fn open_file_async<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<RootDicomObject<InMemDicomObject<D>>> {
+        RootDicomObject::open_file_async_with_options(path, self.dict.clone(), self.read_options)
+            .await
+    }
+
+    /// Load a DICOM object from an asynchronous byte source with these options.
+    ///
+    /// This function assumes the standard file encoding structure without the
+    /// preamble: file meta group, followed by the rest of the data set.
+    #[cfg(feature = "async")]
+    pub async fn from_async_reader<S>(&self, src: S) -> Result<RootDicomObject<InMemDicomObject<D>>>
+    where
+        S: AsyncRead + Unpin,
+    {
+        RootDicomObject::from_async_reader_with_options(src, self.dict.clone(), self.read_options)
+            .await
+    }
 }
@@ -1,23 +1,30 @@
 //! This module contains the implementation for an in-memory DICOM object.
 
+#[cfg(feature = "async")]
+use dicom_parser::dataset::AsyncDataSetReader;
 use itertools::Itertools;
 use smallvec::SmallVec;
 use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Cursor, Read};
 use std::path::Path;
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt};
 
 use crate::meta::FileMetaTable;
 use crate::{DicomObject, RootDicomObject};
-use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
-use dicom_core::header::Header;
-use dicom_core::value::{DicomValueType, Value, ValueType, C};
+use chrono::FixedOffset;
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry, VirtualVr};
+use dicom_core::header::{DataElementHeader, Header};
+use dicom_core::value::partial::parse_utc_offset;
+use dicom_core::value::{DicomValueType, PrimitiveValue, Value, ValueType, C};
 use dicom_core::{DataElement, Length, Tag, VR};
+use dicom_dictionary_std::uids::{EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN};
 use dicom_dictionary_std::StandardDataDictionary;
 use dicom_encoding::text::SpecificCharacterSet;
 use dicom_parser::dataset::{DataSetReader, DataToken};
 use dicom_parser::error::{DataSetSyntaxError, Error, Result};
-use dicom_parser::parser::Parse;
+use dicom_parser::parser::{DynamicDicomParser, Parse};
 use dicom_transfer_syntax_registry::get_registry;
 
 /// A full in-memory DICOM data element.
@@ -86,10 +93,33 @@ impl RootDicomObject<InMemDicomObject<StandardDataDictionary>> {
     /// preamble: file meta group, followed by the rest of the data set.
     pub fn from_reader<S>(src: S) -> Result<Self>
     where
-        S: Read,
+        S: Read + 'static,
     {
         Self::from_reader_with_dict(src, StandardDataDictionary)
     }
+
+    /// Create a DICOM object by reading from a file asynchronously.
+    ///
+    /// This function assumes the standard file encoding structure: 128-byte
+    /// preamble, file meta group, and the rest of the data set.
+    #[cfg(feature = "async")]
+    pub async fn open_file_async<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_file_async_with_options(path, StandardDataDictionary, ReadOptions::default())
+            .await
+    }
+
+    /// Create a DICOM object by reading from an asynchronous byte source.
+    ///
+    /// This function assumes the standard file encoding structure without the
+    /// preamble: file meta group, followed by the rest of the data set.
+    #[cfg(feature = "async")]
+    pub async fn from_async_reader<S>(src: S) -> Result<Self>
+    where
+        S: AsyncRead + Unpin,
+    {
+        Self::from_async_reader_with_options(src, StandardDataDictionary, ReadOptions::default())
+            .await
+    }
 }
 
 impl InMemDicomObject<StandardDataDictionary> {
@@ -126,6 +156,7 @@ where
                 dict,
                 len: Length::UNDEFINED,
             },
+            warnings: Vec::new(),
         }
     }
 
@@ -134,6 +165,15 @@ where
     /// This function assumes the standard file encoding structure: 128-byte
     /// preamble, file meta group, and the rest of the data set.
     pub fn open_file_with_dict<P: AsRef<Path>>(path: P, dict: D) -> Result<Self> {
+        Self::open_file_with_options(path, dict, ReadOptions::default())
+    }
+
+    /// Create a DICOM object by reading from a file with the given options.
+    pub(crate) fn open_file_with_options<P: AsRef<Path>>(
+        path: P,
+        dict: D,
+        options: ReadOptions,
+    ) -> Result<Self> {
         let mut file = BufReader::new(File::open(path)?);
 
         // skip preamble
@@ -143,32 +183,82 @@ where
             file.read_exact(&mut buf)?;
         }
 
+        Self::read_with_options(file, dict, options)
+    }
+
+    /// Create a DICOM object by reading from a byte source.
+    ///
+    /// This function assumes the standard file encoding structure without the
+    /// preamble: file meta group, followed by the rest of the data set.
+    pub fn from_reader_with_dict<S>(src: S, dict: D) -> Result<Self>
+    where
+        S: Read + 'static,
+    {
+        Self::from_reader_with_options(src, dict, ReadOptions::default())
+    }
+
+    /// Create a DICOM object by reading from a byte source with the given options.
+    pub(crate) fn from_reader_with_options<S>(src: S, dict: D, options: ReadOptions) -> Result<Self>
+    where
+        S: Read + 'static,
+    {
+        Self::read_with_options(BufReader::new(src), dict, options)
+    }
+
+    /// Create a DICOM object by reading from a file asynchronously
+    /// with the given options.
+    #[cfg(feature = "async")]
+    pub(crate) async fn open_file_async_with_options<P: AsRef<Path>>(
+        path: P,
+        dict: D,
+        options: ReadOptions,
+    ) -> Result<Self> {
+        let mut file = tokio::io::BufReader::new(tokio::fs::File::open(path).await?);
+
+        // skip the preamble
+        {
+            let mut buf = [0u8; 128];
+            file.read_exact(&mut buf).await?;
+        }
+
+        Self::from_async_reader_with_options(file, dict, options).await
+    }
+
+    /// Create a DICOM object by reading from an asynchronous byte source
+    /// with the given options.
+    #[cfg(feature = "async")]
+    pub(crate) async fn from_async_reader_with_options<S>(
+        mut src: S,
+        dict: D,
+        options: ReadOptions,
+    ) -> Result<Self>
+    where
+        S: AsyncRead + Unpin,
+    {
         // read metadata header
-        let meta = FileMetaTable::from_reader(&mut file)?;
+        let meta = FileMetaTable::from_async_reader(&mut src).await?;
 
         // read rest of data according to metadata, feed it to object
         let ts = get_registry()
             .get(&meta.transfer_syntax)
             .ok_or(Error::UnsupportedTransferSyntax)?;
         let cs = SpecificCharacterSet::Default;
-        let mut dataset = DataSetReader::new_with_dictionary(file, dict.clone(), ts, cs)?;
-
+        let mut dataset = AsyncDataSetReader::new_with_dictionary(src, dict.clone(), ts, cs)?
+            .with_default_utc_offset(options.utc_offset)
+            .lenient(options.lenient);
+        let obj = InMemDicomObject::build_object_async(&mut dataset, dict).await?;
         Ok(RootDicomObject {
             meta,
-            obj: InMemDicomObject::build_object(&mut dataset, dict, false, Length::UNDEFINED)?,
+            obj,
+            warnings: dataset.take_warnings(),
         })
     }
 
-    /// Create a DICOM object by reading from a byte source.
-    ///
-    /// This function assumes the standard file encoding structure without the
-    /// preamble: file meta group, followed by the rest of the data set.
-    pub fn from_reader_with_dict<S>(src: S, dict: D) -> Result<Self>
+    /// Read the file meta group and the data set which follows it.
+    fn read_with_options<S>(mut file: S, dict: D, options: ReadOptions) -> Result<Self>
     where
-        S: Read,
+        S: Read + 'static,
     {
-        let mut file = BufReader::new(src);
-
         // read metadata header
         let meta = FileMetaTable::from_reader(&mut file)?;
 
@@ -177,14 +267,36 @@ where
             .get(&meta.transfer_syntax)
             .ok_or(Error::UnsupportedTransferSyntax)?;
         let cs = SpecificCharacterSet::Default;
-        let mut dataset = DataSetReader::new_with_dictionary(file, dict.clone(), ts, cs)?;
+        let mut dataset = DataSetReader::new_with_dictionary(file, dict.clone(), ts, cs)?
+            .with_default_utc_offset(options.utc_offset)
+            .lenient(options.lenient);
+        let obj = InMemDicomObject::build_object(&mut dataset, dict, false, Length::UNDEFINED)?;
         Ok(RootDicomObject {
             meta,
-            obj: InMemDicomObject::build_object(&mut dataset, dict, false, Length::UNDEFINED)?,
+            obj,
+            warnings: dataset.take_warnings(),
         })
     }
 }
 
+/// Options for reading a DICOM object from a source.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReadOptions {
+    /// the offset from UTC of date-time values until the data set defines one
+    pub utc_offset: FixedOffset,
+    /// whether to recover from defects in the data set
+    pub lenient: bool,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions {
+            utc_offset: utc(),
+            lenient: false,
+        }
+    }
+}
+
 impl<D> InMemDicomObject<D>
 where
     D: DataDictionary,
@@ -241,20 +353,59 @@ where
         self.entries.insert(elt.tag(), elt)
     }
 
+    /// Retrieve the value of the private creator data element which
+    /// reserves the block of the given private data element, without
+    /// padding. Returns `None` if the tag is not of a private data element
+    /// or the object has no such private creator.
+    pub fn private_creator(&self, tag: Tag) -> Option<&str> {
+        private_creator_of(&self.entries, tag)
+    }
+
+    /// Retrieve the dictionary entry of a private data element,
+    /// resolved through the private creator in this object.
+    pub fn private_entry(&self, tag: Tag) -> Option<&D::Entry> {
+        let creator = self.private_creator(tag)?;
+        self.dict
+            .by_private_tag(creator, tag.group(), tag.element() as u8)
+    }
+
+    /// Retrieve a private data element by its private creator, group, and
+    /// the low byte of its element number. The block reserved for the
+    /// creator is resolved through the private creator data elements in
+    /// this object.
+    pub fn private_element(
+        &self,
+        creator: &str,
+        group: u16,
+        element: u8,
+    ) -> Result<&InMemElement<D>> {
+        let block = (0x0010..=0x00FF)
+            .find(|&block| {
+                self.entries.get(&Tag(group, block)).and_then(creator_value) == Some(creator)
+            })
+            .ok_or(Error::NoSuchDataElement)?;
+        self.element(Tag(group, (block << 8) | u16::from(element)))
+    }
+
+    /// Retrieve the data dictionary used by this object.
+    pub(crate) fn dict(&self) -> &D {
+        &self.dict
+    }
+
     // private methods
 
-    /// Build an object by consuming a data set parser.
-    fn build_object<'s, S: 's, P>(
-        dataset: &mut DataSetReader<S, P, D>,
+    /// Build an object by consuming the tokens of a data set.
+    pub(crate) fn build_object<I>(
+        dataset: &mut I,
         dict: D,
         in_item: bool,
         len: Length,
     ) -> Result<Self>
     where
-        S: Read,
-        P: Parse<dyn Read + 's>,
+        I: Iterator<Item = Result<DataToken>>,
     {
         let mut entries: BTreeMap<Tag, InMemElement<D>> = BTreeMap::new();
+        let mut unknown_vr = UnknownVrResolver::default();
         // perform a structured parsing of incoming tokens
         while let Some(token) = dataset.next() {
             let elem = match token? {
@@ -263,7 +414,7 @@ where
                     let next_token = dataset.next().ok_or_else(|| Error::MissingElementValue)?;
                     match next_token? {
                         DataToken::PrimitiveValue(v) => {
-                            InMemElement::new(header.tag, header.vr, Value::Primitive(v))
+                            unknown_vr.resolve(&entries, &dict, header.tag, header.vr, v)
                         }
                         token => {
                             return Err(DataSetSyntaxError::UnexpectedToken(token).into());
@@ -281,29 +432,127 @@ where
                 }
                 token => return Err(DataSetSyntaxError::UnexpectedToken(token).into()),
             };
-            entries.insert(elem.tag(), elem);
+            insert_element(&mut entries, elem, in_item);
         }
 
         Ok(InMemDicomObject { entries, dict, len })
     }
 
-    /// Build a DICOM sequence by consuming a data set parser.
-    fn build_sequence<'s, S: 's, P>(
+    /// Build an object by consuming the tokens of an asynchronous data set
+    /// reader as they are read.
+    ///
+    /// Nested items are built on an explicit stack rather than recursively,
+    /// so that the resulting future does not need to be boxed.
+    #[cfg(feature = "async")]
+    pub(crate) async fn build_object_async<S, P>(
+        dataset: &mut AsyncDataSetReader<S, P, D>,
+        dict: D,
+    ) -> Result<Self>
+    where
+        S: AsyncRead + Unpin,
+        P: Parse<dyn Read>,
+    {
+        /// A data set being built, along with the sequence it is an item of
+        struct Partial<D> {
+            entries: BTreeMap<Tag, InMemElement<D>>,
+            len: Length,
+            unknown_vr: UnknownVrResolver,
+        }
+        /// A sequence being built, along with the data set it belongs to
+        struct Sequence<D> {
+            parent: Partial<D>,
+            tag: Tag,
+            len: Length,
+            items: C<InMemDicomObject<D>>,
+        }
+
+        let new_partial = |len| Partial {
+            entries: BTreeMap::new(),
+            len,
+            unknown_vr: UnknownVrResolver::default(),
+        };
+        // the data set being built, if not between the items of a sequence
+        let mut current = Some(new_partial(Length::UNDEFINED));
+        let mut sequences: Vec<Sequence<D>> = Vec::new();
+        while let Some(token) = dataset.next().await {
+            match (token?, current.as_mut()) {
+                (DataToken::ElementHeader(header), Some(partial)) => {
+                    let next_token = dataset
+                        .next()
+                        .await
+                        .ok_or_else(|| Error::MissingElementValue)?;
+                    let elem = match next_token? {
+                        DataToken::PrimitiveValue(v) => partial.unknown_vr.resolve(
+                            &partial.entries,
+                            &dict,
+                            header.tag,
+                            header.vr,
+                            v,
+                        ),
+                        token => {
+                            return Err(DataSetSyntaxError::UnexpectedToken(token).into());
+                        }
+                    };
+                    insert_element(&mut partial.entries, elem, !sequences.is_empty());
+                }
+                (DataToken::SequenceStart { tag, len }, Some(_)) => {
+                    sequences.push(Sequence {
+                        parent: current.take().unwrap(),
+                        tag,
+                        len,
+                        items: SmallVec::new(),
+                    });
+                }
+                (DataToken::ItemStart { len }, None) if !sequences.is_empty() => {
+                    current = Some(new_partial(len));
+                }
+                (DataToken::ItemEnd, Some(_)) if !sequences.is_empty() => {
+                    let item = current.take().unwrap();
+                    sequences.last_mut().unwrap().items.push(InMemDicomObject {
+                        entries: item.entries,
+                        dict: dict.clone(),
+                        len: item.len,
+                    });
+                }
+                (DataToken::SequenceEnd, None) if !sequences.is_empty() => {
+                    let Sequence {
+                        mut parent,
+                        tag,
+                        len,
+                        items,
+                    } = sequences.pop().unwrap();
+                    let elem = DataElement::new(tag, VR::SQ, Value::Sequence { items, size: len });
+                    insert_element(&mut parent.entries, elem, !sequences.is_empty());
+                    current = Some(parent);
+                }
+                (token, _) => return Err(DataSetSyntaxError::UnexpectedToken(token).into()),
+            }
+        }
+
+        match current {
+            Some(Partial { entries, len, .. }) if sequences.is_empty() => {
+                Ok(InMemDicomObject { entries, dict, len })
+            }
+            // tokens fully consumed without a sequence delimiter
+            _ => Err(DataSetSyntaxError::PrematureEnd.into()),
+        }
+    }
+
+    /// Build a DICOM sequence by consuming the tokens of a data set.
+    pub(crate) fn build_sequence<I>(
         _tag: Tag,
         _len: Length,
-        dataset: &mut DataSetReader<S, P, D>,
+        dataset: &mut I,
         dict: &D,
     ) -> Result<C<InMemDicomObject<D>>>
     where
-        S: Read,
-        P: Parse<dyn Read + 's>,
+        I: Iterator<Item = Result<DataToken>>,
     {
         let mut items: C<_> = SmallVec::new();
         while let Some(token) = dataset.next() {
             match token? {
                 DataToken::ItemStart { len } => {
-                    // TODO if length is well defined, then it should be
-                    // considered instead of finding the item delimiter.
+                    // the reader ends items of defined length on its own
                     items.push(Self::build_object(&mut *dataset, dict.clone(), true, len)?);
                 }
                 DataToken::SequenceEnd => {
@@ -325,6 +574,228 @@ where
     }
 }
 
+/// Insert a data element read from a data set into its entries.
+fn insert_element<D: Clone>(
+    entries: &mut BTreeMap<Tag, InMemElement<D>>,
+    elem: InMemElement<D>,
+    in_item: bool,
+) {
+    if !in_item && elem.tag() == Tag(0x0008, 0x0201) {
+        // the parser only applies the offset to subsequent values,
+        // so update the date-time values read before it
+        if let Some(offset) = elem
+            .value()
+            .primitive()
+            .and_then(PrimitiveValue::string)
+            .and_then(|s| parse_utc_offset(s).ok())
+        {
+            for e in entries.values_mut() {
+                apply_default_utc_offset(e, offset);
+            }
+        }
+    }
+    entries.insert(elem.tag(), elem);
+}
+
+fn utc() -> FixedOffset {
+    FixedOffset::east_opt(0).unwrap()
+}
+
+/// Assume the given offset from UTC in the date-time values of the element
+/// which do not have an explicit offset, including those in nested items.
+fn apply_default_utc_offset<D: Clone>(elem: &mut InMemElement<D>, offset: FixedOffset) {
+    let value = match elem.value() {
+        Value::Primitive(PrimitiveValue::DateTime(values)) => PrimitiveValue::DateTime(
+            values
+                .iter()
+                .map(|v| v.with_default_offset(offset))
+                .collect(),
+        )
+        .into(),
+        Value::Sequence { items, size } => {
+            let mut items = items.clone();
+            for item in &mut items {
+                for e in item.entries.values_mut() {
+                    apply_default_utc_offset(e, offset);
+                }
+            }
+            Value::Sequence { items, size: *size }
+        }
+        _ => return,
+    };
+    *elem = InMemElement::new(elem.tag(), elem.vr(), value);
+}
+
+/// Find the value of the private creator reserving the block of
+/// the given private data element.
+fn private_creator_of<D>(entries: &BTreeMap<Tag, InMemElement<D>>, tag: Tag) -> Option<&str> {
+    entries.get(&tag.private_creator()?).and_then(creator_value)
+}
+
+/// Retrieve the value of a private creator data element, without padding.
+fn creator_value<D>(elem: &InMemElement<D>) -> Option<&str> {
+    elem.value()
+        .primitive()
+        .and_then(PrimitiveValue::string)
+        .map(|s| s.trim_end_matches([' ', '\0']))
+}
+
+/// Resolves the VR of data elements of unknown VR in a data set
+/// through the dictionary, reinterpreting the value bytes accordingly.
+///
+/// Values of unknown VR are kept as they are if the attribute is not in
+/// the dictionary, or if the bytes do not fit the resolved VR.
+#[derive(Default)]
+struct UnknownVrResolver {
+    /// the parser of values in Implicit VR Little Endian,
+    /// created on the first element to resolve,
+    /// along with the character set it decodes text with
+    parser: Option<(SpecificCharacterSet, DynamicDicomParser)>,
+}
+
+impl UnknownVrResolver {
+    /// Resolve the VR of a data element, given the elements read
+    /// so far in the same data set.
+    /// Private data elements are looked up through their private creator.
+    fn resolve<D>(
+        &mut self,
+        entries: &BTreeMap<Tag, InMemElement<D>>,
+        dict: &D,
+        tag: Tag,
+        vr: VR,
+        value: PrimitiveValue,
+    ) -> InMemElement<D>
+    where
+        D: DataDictionary,
+        D: Clone,
+    {
+        let unresolved = |value| InMemElement::new(tag, vr, Value::Primitive(value));
+        if vr != VR::UN {
+            return unresolved(value);
+        }
+        let entry = if tag.is_private() {
+            private_creator_of(entries, tag)
+                .and_then(|creator| dict.by_private_tag(creator, tag.group(), tag.element() as u8))
+        } else {
+            dict.by_tag(tag)
+        };
+        let new_vr = match entry.map(DictionaryEntry::virtual_vr) {
+            Some(VirtualVr::Exact(VR::UN)) | None => return unresolved(value),
+            Some(vr) => resolve_virtual_vr(entries, tag, vr),
+        };
+        let bytes = match &value {
+            PrimitiveValue::U8(bytes) => bytes,
+            _ => return unresolved(value),
+        };
+
+        // values of unknown VR are always encoded in implicit VR little endian
+        let cs = entries
+            .get(&Tag(0x0008, 0x0005))
+            .and_then(|e| e.value().primitive())
+            .and_then(PrimitiveValue::string)
+            .and_then(|code| SpecificCharacterSet::from_code(code.trim_end()))
+            .unwrap_or(SpecificCharacterSet::Default);
+        let reinterpreted = if new_vr == VR::SQ {
+            read_unknown_sequence(tag, bytes, dict, cs)
+        } else {
+            self.parser(cs)
+                .and_then(|parser| {
+                    let header = DataElementHeader::new(tag, new_vr, Length(bytes.len() as u32));
+                    let mut src = Cursor::new(bytes.to_vec());
+                    parser.read_value(&mut src, &header)
+                })
+                .map(Value::Primitive)
+        };
+        match reinterpreted {
+            Ok(value) => InMemElement::new(tag, new_vr, value),
+            Err(_) => unresolved(value),
+        }
+    }
+
+    /// Retrieve the parser for the given character set,
+    /// creating it if necessary.
+    fn parser(&mut self, cs: SpecificCharacterSet) -> Result<&mut DynamicDicomParser> {
+        match &mut self.parser {
+            Some((parser_cs, _)) if *parser_cs == cs => {}
+            parser => {
+                let ts = get_registry()
+                    .get(IMPLICIT_VR_LITTLE_ENDIAN)
+                    .ok_or(Error::UnsupportedTransferSyntax)?;
+                *parser = Some((cs, DynamicDicomParser::new_with(ts, cs)?));
+            }
+        }
+        Ok(&mut self.parser.as_mut().unwrap().1)
+    }
+}
+
+/// Resolve a virtual VR into the VR it has in Implicit VR Little Endian,
+/// as defined in PS3.5 Annex A.1,
+/// given the elements read so far in the same data set.
+fn resolve_virtual_vr<D>(entries: &BTreeMap<Tag, InMemElement<D>>, tag: Tag, vr: VirtualVr) -> VR {
+    let value_of = |tag| {
+        entries
+            .get(&tag)
+            .and_then(|e| e.value().primitive())
+            .and_then(PrimitiveValue::uint16)
+    };
+    match vr {
+        VirtualVr::Exact(vr) => vr,
+        VirtualVr::Xs => match value_of(Tag(0x0028, 0x0103)) {
+            Some(1) => VR::SS,
+            _ => VR::US,
+        },
+        VirtualVr::Ox => {
+            let bits_allocated = if tag.group() == 0x5400 {
+                value_of(Tag(0x5400, 0x1004))
+            } else {
+                value_of(Tag(0x0028, 0x0100))
+            };
+            match bits_allocated {
+                Some(bits) if bits <= 8 => VR::OB,
+                _ => VR::OW,
+            }
+        }
+        VirtualVr::Px | VirtualVr::Lt => VR::OW,
+    }
+}
+
+/// Read the items of a sequence from the value bytes of an element of
+/// unknown VR with a defined length.
+fn read_unknown_sequence<D>(
+    tag: Tag,
+    bytes: &[u8],
+    dict: &D,
+    cs: SpecificCharacterSet,
+) -> Result<Value<InMemDicomObject<D>>>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    // enclose the items in an element of unknown VR with undefined length,
+    // whose contents the data set reader reads in implicit VR little endian
+    // with the given dictionary
+    let mut raw = Vec::with_capacity(bytes.len() + 20);
+    raw.extend_from_slice(&tag.group().to_le_bytes());
+    raw.extend_from_slice(&tag.element().to_le_bytes());
+    raw.extend_from_slice(b"UN\0\0");
+    raw.extend_from_slice(&Length::UNDEFINED.0.to_le_bytes());
+    raw.extend_from_slice(bytes);
+    raw.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+
+    let ts = get_registry()
+        .get(EXPLICIT_VR_LITTLE_ENDIAN)
+        .ok_or(Error::UnsupportedTransferSyntax)?;
+    let mut dataset = DataSetReader::new_with_dictionary(Cursor::new(raw), dict.clone(), ts, cs)?;
+    let obj = InMemDicomObject::build_object(&mut dataset, dict.clone(), false, Length::UNDEFINED)?;
+    match obj.entries.get(&tag).map(DataElement::value) {
+        Some(Value::Sequence { items, .. }) => Ok(Value::Sequence {
+            items: items.clone(),
+            size: Length(bytes.len() as u32),
+        }),
+        _ => Err(DataSetSyntaxError::PrematureEnd.into()),
+    }
+}
+
 impl<'a, D> IntoIterator for &'a InMemDicomObject<D> {
     type Item = &'a InMemElement<D>;
     type IntoIter = ::std::collections::btree_map::Values<'a, Tag, InMemElement<D>>;
@@ -410,4 +881,563 @@ mod tests {
         let elem1 = (&obj).element_by_name("PatientName").unwrap();
         assert_eq!(elem1, &another_patient_name);
     }
+
+    #[test]
+    fn apply_timezone_offset_from_utc() {
+        #[rustfmt::skip]
+        let raw: Vec<u8> = vec![
+            // (0008,002A) AcquisitionDateTime, DT
+            0x08, 0x00, 0x2A, 0x00, 14, 0, 0, 0,
+            b'2', b'0', b'1', b'9', b'0', b'3', b'1', b'5', b'1', b'0', b'3', b'0', b'0', b'0',
+            // (0008,0201) TimezoneOffsetFromUTC, SH
+            0x08, 0x00, 0x01, 0x02, 6, 0, 0, 0, b'-', b'0', b'5', b'0', b'0', b' ',
+            // (0018,9074) FrameAcquisitionDateTime, DT
+            0x18, 0x00, 0x74, 0x90, 24, 0, 0, 0,
+            b'2', b'0', b'1', b'9', b'0', b'3', b'1', b'5', b'1', b'0', b'3', b'0', b'0', b'1',
+            b'\\', b'2', b'0', b'1', b'9', b'+', b'0', b'1', b'0', b'0',
+        ];
+        let ts = get_registry().get(IMPLICIT_VR_LITTLE_ENDIAN).unwrap();
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        let est = FixedOffset::west_opt(5 * 3600).unwrap();
+        let datetimes = |obj: &InMemDicomObject<_>, tag| match obj.element(tag).unwrap().value() {
+            Value::Primitive(PrimitiveValue::DateTime(values)) => values.clone(),
+            value => panic!("unexpected value {:?}", value),
+        };
+
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw.clone()), ts, SpecificCharacterSet::Default)
+                .unwrap()
+                .with_default_utc_offset(cet);
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+
+        // values before and after the attribute both take its offset
+        let acquisition = datetimes(&obj, Tag(0x0008, 0x002A));
+        assert_eq!(acquisition[0].utc_offset(), est);
+        assert_eq!(acquisition[0].to_string(), "20190315103000");
+        assert_eq!(
+            acquisition[0].to_chrono_datetime().unwrap().to_rfc3339(),
+            "2019-03-15T10:30:00-05:00"
+        );
+        let frame = datetimes(&obj, Tag(0x0018, 0x9074));
+        assert_eq!(frame[0].utc_offset(), est);
+        // explicit offsets take precedence
+        assert_eq!(frame[1].utc_offset(), cet);
+
+        // without the attribute, the default offset applies
+        let mut dataset = DataSetReader::new_with(
+            Cursor::new(raw[..22].to_vec()),
+            ts,
+            SpecificCharacterSet::Default,
+        )
+        .unwrap()
+        .with_default_utc_offset(cet);
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+        assert_eq!(datetimes(&obj, Tag(0x0008, 0x002A))[0].utc_offset(), cet);
+    }
+
+    #[test]
+    fn resolve_private_elements() {
+        #[rustfmt::skip]
+        let raw: Vec<u8> = vec![
+            // (0019,0010) private creator
+            0x19, 0x00, 0x10, 0x00, 18, 0, 0, 0,
+            b'S', b'I', b'E', b'M', b'E', b'N', b'S', b' ', b'M', b'R',
+            b' ', b'H', b'E', b'A', b'D', b'E', b'R', b' ',
+            // (0019,100A) NumberOfImagesInMosaic, US
+            0x19, 0x00, 0x0A, 0x10, 2, 0, 0, 0, 48, 0,
+            // (0019,100C) BValue, IS
+            0x19, 0x00, 0x0C, 0x10, 4, 0, 0, 0, b'1', b'0', b'0', b'0',
+            // (0019,10EE) unknown to the dictionary
+            0x19, 0x00, 0xEE, 0x10, 2, 0, 0, 0, 1, 2,
+        ];
+        let ts = get_registry().get(IMPLICIT_VR_LITTLE_ENDIAN).unwrap();
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw), ts, SpecificCharacterSet::Default).unwrap();
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+
+        assert_eq!(
+            obj.private_creator(Tag(0x0019, 0x100C)),
+            Some("SIEMENS MR HEADER")
+        );
+        assert_eq!(obj.private_creator(Tag(0x0019, 0x0010)), None);
+
+        let mosaic = obj.element(Tag(0x0019, 0x100A)).unwrap();
+        assert_eq!(mosaic.vr(), VR::US);
+        assert_eq!(
+            mosaic.value().primitive().and_then(|v| v.uint16()),
+            Some(48)
+        );
+        assert_eq!(
+            obj.private_entry(Tag(0x0019, 0x100A)).map(|e| e.alias),
+            Some("NumberOfImagesInMosaic")
+        );
+
+        let b_value = obj
+            .private_element("SIEMENS MR HEADER", 0x0019, 0x0C)
+            .unwrap();
+        assert_eq!(b_value.vr(), VR::IS);
+        assert_eq!(
+            b_value.value().primitive().and_then(|v| v.int32()),
+            Some(1000)
+        );
+
+        let unknown = obj.element(Tag(0x0019, 0x10EE)).unwrap();
+        assert_eq!(unknown.vr(), VR::UN);
+        assert!(obj.private_entry(Tag(0x0019, 0x10EE)).is_none());
+        assert!(obj.private_element("GEMS_ACQU_01", 0x0019, 0x0C).is_err());
+    }
+
+    #[test]
+    fn reinterpret_unknown_vr() {
+        #[rustfmt::skip]
+        let raw: Vec<u8> = vec![
+            // (0008,1115) ReferencedSeriesSequence, UN of defined length
+            0x08, 0x00, 0x15, 0x11, b'U', b'N', 0, 0, 28, 0, 0, 0,
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // (0020,000E) SeriesInstanceUID, implicit VR
+            0x20, 0x00, 0x0E, 0x00, 4, 0, 0, 0, b'1', b'.', b'2', 0,
+            0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0,
+            // (0008,1140) ReferencedImageSequence, UN of undefined length
+            0x08, 0x00, 0x40, 0x11, b'U', b'N', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // (0008,1150) ReferencedSOPClassUID, implicit VR
+            0x08, 0x00, 0x50, 0x11, 6, 0, 0, 0, b'1', b'.', b'2', b'.', b'3', 0,
+            0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0,
+            0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0,
+            // (0010,0010) PatientName, UN
+            0x10, 0x00, 0x10, 0x00, b'U', b'N', 0, 0, 8, 0, 0, 0,
+            b'D', b'o', b'e', b'^', b'J', b'o', b'h', b'n',
+            // (0028,0030) PixelSpacing, UN
+            0x28, 0x00, 0x30, 0x00, b'U', b'N', 0, 0, 8, 0, 0, 0,
+            b'0', b'.', b'5', b'0', b'\\', b'0', b'.', b'5',
+            // (0028,0103) PixelRepresentation, signed
+            0x28, 0x00, 0x03, 0x01, b'U', b'S', 2, 0, 1, 0,
+            // (0028,0106) SmallestImagePixelValue, UN
+            0x28, 0x00, 0x06, 0x01, b'U', b'N', 0, 0, 2, 0, 0, 0, 0xFE, 0xFF,
+        ];
+        let ts = get_registry()
+            .get(EXPLICIT_VR_LITTLE_ENDIAN)
+            .expect("Explicit VR Little Endian should be registered");
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw), ts, SpecificCharacterSet::Default).unwrap();
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+
+        let name = obj.element(Tag(0x0010, 0x0010)).unwrap();
+        assert_eq!(name.vr(), VR::PN);
+        assert_eq!(name.value().to_str().unwrap(), "Doe^John");
+
+        let spacing = obj.element(Tag(0x0028, 0x0030)).unwrap();
+        assert_eq!(spacing.vr(), VR::DS);
+        assert_eq!(spacing.to_multi_float::<f64>(), Ok(vec![0.5, 0.5]));
+
+        let smallest = obj.element(Tag(0x0028, 0x0106)).unwrap();
+        assert_eq!(smallest.vr(), VR::SS);
+        assert_eq!(
+            smallest.value().primitive().and_then(PrimitiveValue::int16),
+            Some(-2)
+        );
+
+        let item_uid = |tag, item_tag| match obj.element(tag).unwrap().value() {
+            Value::Sequence { items, .. } => {
+                assert_eq!(items.len(), 1);
+                items[0]
+                    .element(item_tag)
+                    .unwrap()
+                    .value()
+                    .primitive()
+                    .and_then(PrimitiveValue::string)
+                    .map(str::to_string)
+            }
+            value => panic!("unexpected value {:?}", value),
+        };
+        assert_eq!(obj.element(Tag(0x0008, 0x1115)).unwrap().vr(), VR::SQ);
+        assert_eq!(
+            item_uid(Tag(0x0008, 0x1115), Tag(0x0020, 0x000E)).as_deref(),
+            Some("1.2")
+        );
+        assert_eq!(
+            item_uid(Tag(0x0008, 0x1140), Tag(0x0008, 0x1150)).as_deref(),
+            Some("1.2.3")
+        );
+    }
+
+    #[test]
+    fn read_leniently() {
+        use dicom_parser::dataset::ReadWarning;
+
+        #[rustfmt::skip]
+        let raw: Vec<u8> = vec![
+            // (0008,0016) SOPClassUID, odd length
+            0x08, 0x00, 0x16, 0x00, b'U', b'I', 5, 0, b'1', b'.', b'2', b'.', b'3',
+            // (0020,0013) InstanceNumber, wrong VR
+            0x20, 0x00, 0x13, 0x00, b'D', b'A', 2, 0, b'1', b'2',
+            // (0028,0030) PixelSpacing, invalid value
+            0x28, 0x00, 0x30, 0x00, b'D', b'S', 4, 0, b'a', b'b', b'c', b' ',
+            // (0040,A730) ContentSequence, undefined length in OB
+            0x40, 0x00, 0x30, 0xA7, b'O', b'B', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // (0040,A010) RelationshipType, then no delimiters
+            0x40, 0x00, 0x10, 0xA0, b'C', b'S', 8, 0,
+            b'C', b'O', b'N', b'T', b'A', b'I', b'N', b'S',
+        ];
+        let ts = get_registry()
+            .get(EXPLICIT_VR_LITTLE_ENDIAN)
+            .expect("Explicit VR Little Endian should be registered");
+
+        // fails in strict mode
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw.clone()), ts, SpecificCharacterSet::Default)
+                .unwrap();
+        assert!(InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .is_err());
+
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw.clone()), ts, SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true);
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+        let warnings = dataset.warnings();
+        assert_eq!(warnings.len(), 6);
+        assert_eq!(
+            warnings[0],
+            ReadWarning::OddLength {
+                tag: Tag(0x0008, 0x0016),
+                len: 5
+            }
+        );
+        assert_eq!(
+            warnings[1],
+            ReadWarning::VrMismatch {
+                tag: Tag(0x0020, 0x0013),
+                found: VR::DA,
+                expected: VR::IS
+            }
+        );
+        assert!(matches!(
+            warnings[2],
+            ReadWarning::InvalidValue {
+                tag: Tag(0x0028, 0x0030),
+                vr: VR::DS,
+                ..
+            }
+        ));
+        assert_eq!(
+            warnings[3],
+            ReadWarning::UndefinedLength {
+                tag: Tag(0x0040, 0xA730),
+                vr: VR::OB
+            }
+        );
+        assert_eq!(
+            warnings[4..],
+            [
+                ReadWarning::MissingItemDelimiter,
+                ReadWarning::MissingSequenceDelimiter
+            ]
+        );
+        assert_eq!(
+            dataset.warnings()[1].to_string(),
+            "(0020,0013): VR is DA, expected IS"
+        );
+
+        let number = obj.element(Tag(0x0020, 0x0013)).unwrap();
+        assert_eq!(number.vr(), VR::IS);
+        assert_eq!(number.to_int::<u32>(), Ok(12));
+        let spacing = obj.element(Tag(0x0028, 0x0030)).unwrap();
+        assert_eq!(spacing.vr(), VR::UN);
+        assert_eq!(spacing.value().as_u8().unwrap(), b"abc ");
+        match obj.element(Tag(0x0040, 0xA730)).unwrap().value() {
+            Value::Sequence { items, .. } => {
+                assert_eq!(items.len(), 1);
+                assert!(items[0].element(Tag(0x0040, 0xA010)).is_ok());
+            }
+            value => panic!("unexpected value {:?}", value),
+        }
+
+        // padding after the data set is ignored
+        let mut raw = raw[..13].to_vec();
+        raw.extend(vec![0; 16]);
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw), ts, SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true);
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+        assert_eq!(obj.into_iter().count(), 1);
+        assert_eq!(dataset.warnings().last(), Some(&ReadWarning::TrailingData));
+
+        // elements out of order are read all the same
+        #[rustfmt::skip]
+        let raw: Vec<u8> = vec![
+            // (0010,0020) PatientID
+            0x10, 0x00, 0x20, 0x00, b'L', b'O', 4, 0, b'I', b'D', b'0', b'1',
+            // (0010,0010) PatientName, out of order
+            0x10, 0x00, 0x10, 0x00, b'P', b'N', 4, 0, b'D', b'o', b'e', b'^',
+            // (0010,0010) PatientName, repeated
+            0x10, 0x00, 0x10, 0x00, b'P', b'N', 4, 0, b'R', b'o', b'e', b'^',
+            // (0020,0013) InstanceNumber
+            0x20, 0x00, 0x13, 0x00, b'I', b'S', 2, 0, b'1', b'2',
+            // incomplete header
+            0x08, 0x00, 0x16,
+        ];
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw), ts, SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true);
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+        assert_eq!(obj.clone().into_iter().count(), 3);
+        assert_eq!(
+            obj.element(Tag(0x0010, 0x0010)).unwrap().to_str().unwrap(),
+            "Roe^"
+        );
+        assert!(obj.element(Tag(0x0020, 0x0013)).is_ok());
+        assert_eq!(
+            dataset.warnings(),
+            &[
+                ReadWarning::OutOfOrder {
+                    tag: Tag(0x0010, 0x0010),
+                    previous: Tag(0x0010, 0x0020),
+                },
+                ReadWarning::DuplicateElement {
+                    tag: Tag(0x0010, 0x0010)
+                },
+                ReadWarning::TrailingData,
+            ]
+        );
+    }
+
+    #[test]
+    fn read_defined_length_sequence() {
+        use dicom_parser::dataset::ReadWarning;
+
+        #[rustfmt::skip]
+        let raw: Vec<u8> = vec![
+            // (0040,A730) ContentSequence, 24 bytes
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0, 0, 24, 0, 0, 0,
+            // item, 16 bytes
+            0xFE, 0xFF, 0x00, 0xE0, 16, 0, 0, 0,
+            // (0040,A010) RelationshipType
+            0x40, 0x00, 0x10, 0xA0, b'C', b'S', 8, 0,
+            b'C', b'O', b'N', b'T', b'A', b'I', b'N', b'S',
+            // (0042,0010) DocumentTitle, after the sequence
+            0x42, 0x00, 0x10, 0x00, b'S', b'T', 4, 0, b'T', b'E', b'X', b'T',
+        ];
+        let ts = get_registry()
+            .get(EXPLICIT_VR_LITTLE_ENDIAN)
+            .expect("Explicit VR Little Endian should be registered");
+
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw.clone()), ts, SpecificCharacterSet::Default)
+                .unwrap();
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+        match obj.element(Tag(0x0040, 0xA730)).unwrap().value() {
+            Value::Sequence { items, .. } => {
+                assert_eq!(items.len(), 1);
+                assert!(items[0].element(Tag(0x0040, 0xA010)).is_ok());
+                assert!(items[0].element(Tag(0x0042, 0x0010)).is_err());
+            }
+            value => panic!("unexpected value {:?}", value),
+        }
+        assert_eq!(
+            obj.element(Tag(0x0042, 0x0010)).unwrap().to_str().unwrap(),
+            "TEXT"
+        );
+
+        // the item is too short for its contents
+        let mut raw = raw;
+        raw[16] = 12;
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw.clone()), ts, SpecificCharacterSet::Default)
+                .unwrap();
+        assert!(InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .is_err());
+
+        let mut dataset =
+            DataSetReader::new_with(Cursor::new(raw), ts, SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true);
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+        assert_eq!(obj.into_iter().count(), 2);
+        assert_eq!(
+            dataset.warnings(),
+            &[ReadWarning::LengthOverrun { excess: 4 }]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn read_from_async_reader() {
+        #[rustfmt::skip]
+        let mut raw = vec![
+            b'D', b'I', b'C', b'M',
+            // (0002,0000) FileMetaInformationGroupLength, 108
+            0x02, 0x00, 0x00, 0x00, b'U', b'L', 4, 0, 108, 0, 0, 0,
+            // (0002,0001) FileMetaInformationVersion
+            0x02, 0x00, 0x01, 0x00, b'O', b'B', 0, 0, 2, 0, 0, 0, 0x00, 0x01,
+            // (0002,0002) MediaStorageSOPClassUID
+            0x02, 0x00, 0x02, 0x00, b'U', b'I', 26, 0,
+        ];
+        raw.extend(b"1.2.840.10008.5.1.4.1.1.1\0");
+        // (0002,0003) MediaStorageSOPInstanceUID
+        raw.extend(&[0x02, 0x00, 0x03, 0x00, b'U', b'I', 8, 0]);
+        raw.extend(b"1.2.3.4\0");
+        // (0002,0010) TransferSyntaxUID
+        raw.extend(&[0x02, 0x00, 0x10, 0x00, b'U', b'I', 20, 0]);
+        raw.extend(b"1.2.840.10008.1.2.1\0");
+        // (0002,0012) ImplementationClassUID
+        raw.extend(&[0x02, 0x00, 0x12, 0x00, b'U', b'I', 8, 0]);
+        raw.extend(b"1.2.3.5\0");
+        // (0010,0010) PatientName
+        raw.extend(&[0x10, 0x00, 0x10, 0x00, b'P', b'N', 8, 0]);
+        raw.extend(b"Doe^John");
+        #[rustfmt::skip]
+        raw.extend(&[
+            // (0040,A730) ContentSequence, undefined length
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // (0040,A010) RelationshipType
+            0x40, 0x00, 0x10, 0xA0, b'C', b'S', 8, 0,
+            b'C', b'O', b'N', b'T', b'A', b'I', b'N', b'S',
+            0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0,
+            0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0,
+        ]);
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .build()
+            .unwrap();
+        // the loader can be spawned on a multi-threaded runtime
+        fn is_send<T: Send>(_: &T) {}
+        let loading = RootDicomObject::from_async_reader(std::io::Cursor::new(raw.clone()));
+        is_send(&loading);
+        let obj = runtime.block_on(runtime.spawn(loading)).unwrap().unwrap();
+        assert_eq!(obj.meta().transfer_syntax, "1.2.840.10008.1.2.1\0");
+        assert_eq!(
+            obj.element(Tag(0x0010, 0x0010)).unwrap().to_str().unwrap(),
+            "Doe^John"
+        );
+        match obj.element(Tag(0x0040, 0xA730)).unwrap().value() {
+            Value::Sequence { items, .. } => {
+                assert_eq!(items.len(), 1);
+                assert!(items[0].element(Tag(0x0040, 0xA010)).is_ok());
+            }
+            value => panic!("unexpected value {:?}", value),
+        }
+        is_send(&RootDicomObject::open_file_async("file.dcm"));
+
+        // the data must not end in the sequence
+        let truncated = &raw[..raw.len() - 8];
+        assert!(runtime
+            .block_on(RootDicomObject::from_async_reader(truncated))
+            .is_err());
+
+        // the data must not end in the file meta group
+        let truncated = &raw[..100];
+        assert!(runtime
+            .block_on(RootDicomObject::from_async_reader(truncated))
+            .is_err());
+
+        // nor may it declare a bogus group length
+        let mut bogus = raw;
+        bogus[12..16].copy_from_slice(&[0xF0, 0xFF, 0xFF, 0xFF]);
+        assert!(runtime
+            .block_on(RootDicomObject::from_async_reader(&bogus[..]))
+            .is_err());
+    }
+
+    #[test]
+    fn inmem_object_get_by_name_with_layered_dict() {
+        use dicom_core::dictionary::{
+            DictionaryEntryBuf, InMemDataDictionary, LayeredDataDictionary, TagRange,
+        };
+
+        let site = InMemDataDictionary::new().with_entry(DictionaryEntryBuf {
+            tag: TagRange::Single(Tag(0x0011, 0x1001)),
+            alias: "SiteRoutingCode".to_string(),
+            name: None,
+            vr: VR::LO.into(),
+            vm: None,
+            retired: false,
+        });
+        let dict = LayeredDataDictionary::new()
+            .with_layer(StandardDataDictionary)
+            .with_layer(site);
+
+        let routing_code = DataElement::new(
+            Tag(0x0011, 0x1001),
+            VR::LO,
+            PrimitiveValue::Str("WARD-7".to_string()).into(),
+        );
+        let mut obj = InMemDicomObject::new_empty_with_dict(dict);
+        obj.put(routing_code.clone());
+        obj.put(DataElement::empty(Tag(0x0010, 0x0010), VR::PN));
+        assert_eq!(
+            obj.element_by_name("SiteRoutingCode").unwrap(),
+            &routing_code
+        );
+        assert!(obj.element_by_name("PatientName").is_ok());
+    }
 }
@@ -2,12 +2,15 @@
 use byteordered::byteorder::{ByteOrder, LittleEndian};
 use dicom_core::header::Header;
 use dicom_core::{Length, Tag};
+use dicom_dictionary_std::tags;
 use dicom_encoding::decode;
 use dicom_encoding::decode::Decode;
 use dicom_encoding::text;
 use dicom_encoding::text::TextCodec;
 use dicom_parser::error::{Error, InvalidValueReadError, Result};
 use std::io::Read;
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt};
 
 const DICM_MAGIC_CODE: [u8; 4] = [b'D', b'I', b'C', b'M'];
 
@@ -96,6 +99,29 @@ impl FileMetaTable {
         FileMetaTable::read_from(file)
     }
 
+    /// Read the file meta group from an asynchronous source,
+    /// leaving the source at the start of the data set.
+    #[cfg(feature = "async")]
+    pub async fn from_async_reader<R: AsyncRead + Unpin>(file: &mut R) -> Result<Self> {
+        // magic code and File Meta Information Group Length
+        let mut buff = vec![0u8; 16];
+        file.read_exact(&mut buff).await?;
+        if buff[..4] != DICM_MAGIC_CODE {
+            return Err(Error::InvalidFormat);
+        }
+        let group_length = u64::from(LittleEndian::read_u32(&buff[12..]));
+        // read through `take` rather than allocating the declared length
+        // up front, as it may be bogus
+        let read = (&mut *file)
+            .take(group_length)
+            .read_to_end(&mut buff)
+            .await?;
+        if (read as u64) < group_length {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+        FileMetaTable::read_from(&buff[..])
+    }
+
     fn read_from<S: Read>(mut file: S) -> Result<Self> {
         let mut buff: [u8; 4] = [0; 4];
         {
@@ -147,28 +173,28 @@ impl FileMetaTable {
                 &decoder,
                 &text,
                 &mut group_length_remaining,
-                Tag(0x0002, 0x0002),
+                tags::MEDIA_STORAGE_SOP_CLASS_UID,
             )?)
             .media_storage_sop_instance_uid(read_str_as_tag(
                 &mut file,
                 &decoder,
                 &text,
                 &mut group_length_remaining,
-                Tag(0x0002, 0x0003),
+                tags::MEDIA_STORAGE_SOP_INSTANCE_UID,
             )?)
             .transfer_syntax(read_str_as_tag(
                 &mut file,
                 &decoder,
                 &text,
                 &mut group_length_remaining,
-                Tag(0x0002, 0x0010),
+                tags::TRANSFER_SYNTAX_UID,
             )?)
             .implementation_class_uid(read_str_as_tag(
                 &mut file,
                 &decoder,
                 &text,
                 &mut group_length_remaining,
-                Tag(0x0002, 0x0012),
+                tags::IMPLEMENTATION_CLASS_UID,
             )?);
 
         // Fetch optional data elements
@@ -181,42 +207,42 @@ impl FileMetaTable {
                 Some(len) => len as usize,
             };
             builder = match elem.tag() {
-                Tag(0x0002, 0x0013) => {
+                tags::IMPLEMENTATION_VERSION_NAME => {
                     // Implementation Version Name
                     let mut v = vec![0; elem_len];
                     file.read_exact(&mut v)?;
                     group_length_remaining -= 8 + elem_len as u32;
                     builder.implementation_version_name(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0016) => {
+                tags::SOURCE_APPLICATION_ENTITY_TITLE => {
                     // Source Application Entity Title
                     let mut v = vec![0; elem_len];
                     file.read_exact(&mut v)?;
                     group_length_remaining -= 8 + elem_len as u32;
                     builder.source_application_entity_title(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0017) => {
+                tags::SENDING_APPLICATION_ENTITY_TITLE => {
                     // Sending Application Entity Title
                     let mut v = vec![0; elem_len];
                     file.read_exact(&mut v)?;
                     group_length_remaining -= 8 + elem_len as u32;
                     builder.sending_application_entity_title(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0018) => {
+                tags::RECEIVING_APPLICATION_ENTITY_TITLE => {
                     // Receiving Application Entity Title
                     let mut v = vec![0; elem_len];
                     file.read_exact(&mut v)?;
                     group_length_remaining -= 8 + elem_len as u32;
                     builder.receiving_application_entity_title(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0100) => {
+                tags::PRIVATE_INFORMATION_CREATOR_UID => {
                     // Private Information Creator UID
                     let mut v = vec![0; elem_len];
                     file.read_exact(&mut v)?;
                     group_length_remaining -= 8 + elem_len as u32;
                     builder.private_information_creator_uid(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0102) => {
+                tags::PRIVATE_INFORMATION => {
                     // Private Information
                     let mut v = vec![0; elem_len];
                     file.read_exact(&mut v)?;
@@ -1,3 +1,11 @@
+#[cfg(test)]
+use crate::mem::InMemDicomObject;
+#[cfg(test)]
+use dicom_core::dictionary::DataDictionary;
+use dicom_core::value::PrimitiveValue;
+use dicom_core::VR;
+#[cfg(test)]
+use dicom_core::{DataElement, Tag};
 use std::io;
 use std::io::{Read, Seek, SeekFrom};
 
@@ -21,3 +29,116 @@ where
 /// A trait that combines for `Read` and `Seek`.
 pub trait ReadSeek: Read + Seek {}
 impl<T: ?Sized> ReadSeek for T where T: Read + Seek {}
+
+/// Whether values of this VR are encoded as text.
+pub(crate) fn is_text_vr(vr: VR) -> bool {
+    matches!(
+        vr,
+        VR::AE
+            | VR::AS
+            | VR::CS
+            | VR::DA
+            | VR::DS
+            | VR::DT
+            | VR::IS
+            | VR::LO
+            | VR::LT
+            | VR::PN
+            | VR::SH
+            | VR::ST
+            | VR::TM
+            | VR::UC
+            | VR::UI
+            | VR::UR
+            | VR::UT
+    )
+}
+
+/// Split a text value into its individual values,
+/// which are separated by backslashes
+/// unless the VR only admits a single value.
+pub(crate) fn split_text(vr: VR, text: &str) -> Vec<&str> {
+    match vr {
+        VR::ST | VR::LT | VR::UT | VR::UR => vec![text],
+        _ => text.split('\\').collect(),
+    }
+}
+
+/// Convert a primitive value of the given VR into its individual textual
+/// values, without trailing padding.
+pub(crate) fn value_to_strings(vr: VR, value: &PrimitiveValue) -> Vec<String> {
+    use PrimitiveValue::*;
+
+    fn trim(s: &str) -> String {
+        s.trim_end_matches([' ', '\0']).to_string()
+    }
+
+    match value {
+        Empty => vec![],
+        Str(s) => split_text(vr, s).into_iter().map(trim).collect(),
+        Strs(c) => c.iter().map(|s| trim(s)).collect(),
+        Tags(c) => c
+            .iter()
+            .map(|t| format!("{:04X}{:04X}", t.group(), t.element()))
+            .collect(),
+        U8(c) => c.iter().map(ToString::to_string).collect(),
+        I16(c) => c.iter().map(ToString::to_string).collect(),
+        U16(c) => c.iter().map(ToString::to_string).collect(),
+        I32(c) => c.iter().map(ToString::to_string).collect(),
+        U32(c) => c.iter().map(ToString::to_string).collect(),
+        I64(c) => c.iter().map(ToString::to_string).collect(),
+        U64(c) => c.iter().map(ToString::to_string).collect(),
+        F32(c) => c.iter().map(ToString::to_string).collect(),
+        F64(c) => c.iter().map(ToString::to_string).collect(),
+        // the original text of numeric strings is kept
+        DecimalString(c) => c.iter().map(ToString::to_string).collect(),
+        IntegerString(c) => c.iter().map(ToString::to_string).collect(),
+        Date(c) => c.iter().map(ToString::to_string).collect(),
+        Time(c) => c.iter().map(ToString::to_string).collect(),
+        DateTime(c) => c.iter().map(ToString::to_string).collect(),
+    }
+}
+
+/// Insert a text element with the given values into the object.
+#[cfg(test)]
+pub(crate) fn put_strs<D>(obj: &mut InMemDicomObject<D>, tag: Tag, vr: VR, values: &[&str])
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    obj.put(DataElement::new(
+        tag,
+        vr,
+        PrimitiveValue::Strs(values.iter().map(|s| s.to_string()).collect()).into(),
+    ));
+}
+
+/// Convert a primitive value into a little endian byte sequence.
+pub(crate) fn value_to_bytes(value: &PrimitiveValue) -> Vec<u8> {
+    use PrimitiveValue::*;
+    match value {
+        U8(c) => c.to_vec(),
+        I16(c) => c.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect(),
+        U16(c) => c.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect(),
+        I32(c) => c.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect(),
+        U32(c) => c.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect(),
+        I64(c) => c.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect(),
+        U64(c) => c.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect(),
+        F32(c) => c
+            .iter()
+            .flat_map(|v| v.to_bits().to_le_bytes().to_vec())
+            .collect(),
+        F64(c) => c
+            .iter()
+            .flat_map(|v| v.to_bits().to_le_bytes().to_vec())
+            .collect(),
+        Str(s) => s.as_bytes().to_vec(),
+        Strs(c) => c
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\\")
+            .into_bytes(),
+        value => value_to_strings(VR::UN, value).join("\\").into_bytes(),
+    }
+}
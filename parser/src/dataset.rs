@@ -4,27 +4,37 @@
 //! The rest of the crate is used to obtain DICOM element headers and values.
 //! At this level, headers and values are treated as tokens which can be used
 //! to form a syntax tree of a full data set.
-use crate::error::{Error, InvalidValueReadError, Result};
+use crate::error::{DataSetSyntaxError, Error, InvalidValueReadError, Result};
 use crate::parser::{DicomParser, DynamicDicomParser, Parse};
-use crate::util::{ReadSeek, SeekInterval};
-use dicom_core::dictionary::DataDictionary;
+use crate::util::{CountingReader, ReadSeek, SeekInterval};
+use chrono::FixedOffset;
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry, VirtualVr};
 use dicom_core::header::{DataElementHeader, Header, Length, SequenceItemHeader};
+use dicom_core::value::partial::parse_utc_offset;
 use dicom_core::value::{DicomValueType, PrimitiveValue};
 use dicom_core::{Tag, VR};
 use dicom_dictionary_std::StandardDataDictionary;
-use dicom_encoding::text::SpecificCharacterSet;
+use dicom_encoding::decode::basic::LittleEndianBasicDecoder;
+use dicom_encoding::text::{SpecificCharacterSet, TextCodec};
+use dicom_encoding::transfer_syntax::implicit_le::ImplicitVRLittleEndianDecoder;
 use dicom_encoding::transfer_syntax::TransferSyntax;
 use std::fmt;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::iter::Iterator;
 use std::marker::PhantomData;
 use std::ops::DerefMut;
 
+#[cfg(feature = "async")]
+mod asynchronous;
+
+#[cfg(feature = "async")]
+pub use self::asynchronous::AsyncDataSetReader;
+
 /// A higher-level reader for retrieving structure in a DICOM data set from an
 /// arbitrary data source.
 #[derive(Debug)]
 pub struct DataSetReader<S, P, D> {
-    source: S,
+    source: CountingReader<S>,
     parser: P,
     dict: D,
     /// the current depth in the sequence tree
@@ -35,6 +45,41 @@ pub struct DataSetReader<S, P, D> {
     hard_break: bool,
     /// last decoded header
     last_header: Option<DataElementHeader>,
+    /// the outermost sequence of unknown VR being read,
+    /// whose contents are always in implicit VR little endian
+    implicit_vr: Option<ImplicitVrSequence<D>>,
+    /// the specific character set of the data set read so far
+    charset: SpecificCharacterSet,
+    /// the offset from UTC of date-time values read so far
+    utc_offset: FixedOffset,
+    /// whether to recover from defects in the data set
+    lenient: bool,
+    /// the defects recovered from so far
+    warnings: Vec<ReadWarning>,
+    /// a value which was read along with its header
+    pending_value: Option<PrimitiveValue>,
+    /// the tag of the last data element at the root of the data set
+    last_root_tag: Option<Tag>,
+    /// the position in the source at which each open sequence and item
+    /// ends, if it has a defined length, from the outermost one
+    ends: Vec<Option<u64>>,
+}
+
+/// The parser of the contents of sequences of unknown VR.
+type ImplicitVrParser<D> = DicomParser<
+    ImplicitVRLittleEndianDecoder<dyn Read, D>,
+    LittleEndianBasicDecoder,
+    dyn Read,
+    Box<dyn TextCodec>,
+>;
+
+/// A sequence of unknown VR with undefined length, along with the parser
+/// of its contents.
+#[derive(Debug)]
+struct ImplicitVrSequence<D> {
+    /// the depth of the sequence
+    depth: u32,
+    parser: ImplicitVrParser<D>,
 }
 
 fn is_parse<S: ?Sized, P>(_: &P)
@@ -48,19 +93,7 @@ impl<'s, S: 's> DataSetReader<S, DynamicDicomParser, StandardDataDictionary> {
     /// Creates a new iterator with the given random access source,
     /// while considering the given transfer syntax and specific character set.
     pub fn new_with(source: S, ts: &TransferSyntax, cs: SpecificCharacterSet) -> Result<Self> {
-        let parser = DynamicDicomParser::new_with(ts, cs)?;
-
-        is_parse(&parser);
-
-        Ok(DataSetReader {
-            source,
-            parser,
-            dict: StandardDataDictionary,
-            depth: 0,
-            in_sequence: false,
-            hard_break: false,
-            last_header: None,
-        })
+        DataSetReader::new_with_dictionary(source, StandardDataDictionary, ts, cs)
     }
 }
 
@@ -77,15 +110,7 @@ impl<'s, S: 's, D> DataSetReader<S, DynamicDicomParser, D> {
 
         is_parse(&parser);
 
-        Ok(DataSetReader {
-            source,
-            parser,
-            dict,
-            depth: 0,
-            in_sequence: false,
-            hard_break: false,
-            last_header: None,
-        })
+        Ok(DataSetReader::with_parts(source, parser, dict, cs))
     }
 }
 
@@ -96,14 +121,194 @@ where
 {
     /// Create a new iterator with the given parser.
     pub fn new(source: S, parser: P) -> Self {
-        DataSetReader {
+        DataSetReader::with_parts(
             source,
             parser,
-            dict: StandardDataDictionary,
+            StandardDataDictionary,
+            SpecificCharacterSet::Default,
+        )
+    }
+}
+
+impl<S, P, D> DataSetReader<S, P, D> {
+    /// Create a reader at the start of the data set,
+    /// of which the parser starts with the given character set.
+    fn with_parts(source: S, parser: P, dict: D, charset: SpecificCharacterSet) -> Self {
+        DataSetReader {
+            source: CountingReader::new(source),
+            parser,
+            dict,
             depth: 0,
             in_sequence: false,
             hard_break: false,
             last_header: None,
+            implicit_vr: None,
+            charset,
+            utc_offset: FixedOffset::east_opt(0).unwrap(),
+            lenient: false,
+            warnings: Vec::new(),
+            pending_value: None,
+            last_root_tag: None,
+            ends: Vec::new(),
+        }
+    }
+}
+
+impl<'s, S: 's, P, D> DataSetReader<S, P, D>
+where
+    P: Parse<dyn Read + 's>,
+{
+    /// Define the offset from UTC of date-time (DT) values without an
+    /// explicit offset, until the data set specifies one in the attribute
+    /// _Timezone Offset From UTC_ (0008,0201).
+    pub fn with_default_utc_offset(mut self, offset: FixedOffset) -> Self {
+        self.parser.set_utc_offset(offset);
+        self.utc_offset = offset;
+        self
+    }
+}
+
+impl<S, P, D> DataSetReader<S, P, D> {
+    /// Enable or disable the lenient mode, in which the reader recovers
+    /// from common defects in the data set instead of failing,
+    /// recording a warning for each of them.
+    ///
+    /// In lenient mode, a value which cannot be read with its VR is read
+    /// with the VR in the dictionary, or kept as bytes of unknown VR,
+    /// data elements other than sequences with an undefined length are
+    /// read as sequences, delimiters missing at the end of the data are
+    /// assumed, and zero padding or an incomplete header after the end of
+    /// the data set is ignored. Data elements at the root which are
+    /// repeated or out of order are reported, and read all the same.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Retrieve the warnings about the defects found so far.
+    pub fn warnings(&self) -> &[ReadWarning] {
+        &self.warnings
+    }
+
+    /// The number of bytes read from the source so far.
+    pub fn position(&self) -> u64 {
+        self.source.position()
+    }
+
+    /// Turn this reader into an iterator of tokens annotated with
+    /// their byte offset and encoded length in the source.
+    pub fn with_positions(self) -> PositionedDataSetReader<S, P, D> {
+        PositionedDataSetReader {
+            inner: self,
+            value_span: None,
+        }
+    }
+
+    /// Take the warnings about the defects found so far,
+    /// leaving none behind.
+    pub fn take_warnings(&mut self) -> Vec<ReadWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+}
+
+/// A defect in a data set which the reader recovered from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReadWarning {
+    /// The value of a data element has an odd length.
+    OddLength {
+        /// the tag of the data element
+        tag: Tag,
+        /// the length of the value
+        len: u32,
+    },
+    /// The explicit VR of a data element is not the one in the dictionary.
+    VrMismatch {
+        /// the tag of the data element
+        tag: Tag,
+        /// the VR in the data set
+        found: VR,
+        /// the VR in the dictionary
+        expected: VR,
+    },
+    /// The value of a data element could not be read with its VR,
+    /// so it was kept as bytes of unknown VR.
+    InvalidValue {
+        /// the tag of the data element
+        tag: Tag,
+        /// the VR in the data set
+        vr: VR,
+        /// the reason why the value could not be read
+        message: String,
+    },
+    /// A data element other than a sequence has an undefined length,
+    /// so it was read as a sequence.
+    UndefinedLength {
+        /// the tag of the data element
+        tag: Tag,
+        /// the VR in the data set
+        vr: VR,
+    },
+    /// The data ended before the delimiter of an item.
+    MissingItemDelimiter,
+    /// The data ended before the delimiter of a sequence.
+    MissingSequenceDelimiter,
+    /// The contents of a sequence or item went past its defined length.
+    LengthOverrun {
+        /// the number of bytes past the end
+        excess: u64,
+    },
+    /// A data element at the root of the data set has the same tag as the
+    /// one before it.
+    DuplicateElement {
+        /// the tag of the data element
+        tag: Tag,
+    },
+    /// A data element at the root of the data set has a lower tag than
+    /// the one before it.
+    OutOfOrder {
+        /// the tag of the data element
+        tag: Tag,
+        /// the tag of the data element before it
+        previous: Tag,
+    },
+    /// The padding or incomplete header after the end of the data set
+    /// was ignored.
+    TrailingData,
+}
+
+impl fmt::Display for ReadWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadWarning::OddLength { tag, len } => {
+                write!(f, "{}: value has an odd length of {}", tag, len)
+            }
+            ReadWarning::VrMismatch {
+                tag,
+                found,
+                expected,
+            } => write!(f, "{}: VR is {}, expected {}", tag, found, expected),
+            ReadWarning::InvalidValue { tag, vr, message } => {
+                write!(f, "{}: value is not a valid {}: {}", tag, vr, message)
+            }
+            ReadWarning::UndefinedLength { tag, vr } => {
+                write!(f, "{}: undefined length in element of VR {}", tag, vr)
+            }
+            ReadWarning::MissingItemDelimiter => {
+                f.write_str("data ended before the end of an item")
+            }
+            ReadWarning::MissingSequenceDelimiter => {
+                f.write_str("data ended before the end of a sequence")
+            }
+            ReadWarning::LengthOverrun { excess } => write!(
+                f,
+                "contents of a sequence or item exceed its length by {} bytes",
+                excess
+            ),
+            ReadWarning::DuplicateElement { tag } => write!(f, "{}: repeated data element", tag),
+            ReadWarning::OutOfOrder { tag, previous } => {
+                write!(f, "{}: data element out of order after {}", tag, previous)
+            }
+            ReadWarning::TrailingData => f.write_str("ignored data after the end of the data set"),
         }
     }
 }
@@ -140,7 +345,7 @@ impl<'s, S: 's, P, D> Iterator for DataSetReader<S, P, D>
 where
     S: Read,
     P: Parse<dyn Read + 's>,
-    D: DataDictionary,
+    D: DataDictionary + Clone,
 {
     type Item = Result<DataToken>;
 
@@ -148,12 +353,17 @@ where
         if self.hard_break {
             return None;
         }
+        if self.last_header.is_none() {
+            if let Some(token) = self.close_exhausted() {
+                return Some(token);
+            }
+        }
         if self.in_sequence {
-            match self.parser.decode_item_header(&mut self.source) {
+            match self.decode_item_header() {
                 Ok(header) => match header {
                     SequenceItemHeader::Item { len } => {
                         // entered a new item
-                        self.in_sequence = false;
+                        self.enter_item(len);
                         Some(Ok(DataToken::ItemStart { len }))
                     }
                     SequenceItemHeader::ItemDelimiter => {
@@ -163,11 +373,17 @@ where
                     }
                     SequenceItemHeader::SequenceDelimiter => {
                         // closed a sequence
-                        self.depth -= 1;
-                        self.in_sequence = false;
+                        self.leave_sequence();
                         Some(Ok(DataToken::SequenceEnd))
                     }
                 },
+                Err(Error::Io(ref e))
+                    if self.lenient && e.kind() == ::std::io::ErrorKind::UnexpectedEof =>
+                {
+                    self.warnings.push(ReadWarning::MissingSequenceDelimiter);
+                    self.leave_sequence();
+                    Some(Ok(DataToken::SequenceEnd))
+                }
                 Err(e) => {
                     self.hard_break = true;
                     Some(Err(e))
@@ -176,7 +392,11 @@ where
         } else if self.last_header.is_some() {
             // a plain element header was read, so a value is expected
             let header = self.last_header.unwrap();
-            let v = match self.parser.read_value(&mut self.source, &header) {
+            let v = match self.pending_value.take() {
+                Some(v) => Ok(v),
+                None => self.read_value(&header),
+            };
+            let v = match v {
                 Ok(v) => v,
                 Err(e) => {
                     self.hard_break = true;
@@ -185,61 +405,460 @@ where
                 }
             };
 
-            // if it's a Specific Character Set, update the parser immediately.
-            if let Some(DataElementHeader {
-                tag: Tag(0x0008, 0x0005),
-                ..
-            }) = self.last_header
-            {
-                // TODO trigger an error or warning on unsupported specific character sets.
-                // Edge case handling strategies should be considered in the future.
-                if let Some(charset) = v.string().and_then(SpecificCharacterSet::from_code) {
-                    if let Err(e) = self.parser.set_character_set(charset) {
-                        self.hard_break = true;
-                        self.last_header = None;
-                        return Some(Err(e));
+            match self.last_header {
+                // if it's a Specific Character Set, update the parser immediately.
+                Some(DataElementHeader {
+                    tag: Tag(0x0008, 0x0005),
+                    ..
+                }) => {
+                    // TODO trigger an error or warning on unsupported specific character sets.
+                    // Edge case handling strategies should be considered in the future.
+                    if let Some(charset) = v.string().and_then(SpecificCharacterSet::from_code) {
+                        if let Err(e) = self.set_character_set(charset) {
+                            self.hard_break = true;
+                            self.last_header = None;
+                            return Some(Err(e));
+                        }
+                    }
+                }
+                // likewise for the Timezone Offset From UTC,
+                // which applies to subsequent date-time values
+                Some(DataElementHeader {
+                    tag: Tag(0x0008, 0x0201),
+                    ..
+                }) => {
+                    if let Some(offset) = v.string().and_then(|s| parse_utc_offset(s).ok()) {
+                        self.set_utc_offset(offset);
                     }
                 }
+                _ => {}
             }
             self.last_header = None;
             Some(Ok(DataToken::PrimitiveValue(v)))
         } else {
             // a data element header or item delimiter is expected
-            match self.parser.decode_header(&mut self.source) {
-                Ok(DataElementHeader {
+            let start = self.source.position();
+            let header = match self.decode_header() {
+                Ok(header) => header,
+                Err(Error::Io(ref e)) if e.kind() == ::std::io::ErrorKind::UnexpectedEof => {
+                    if self.lenient && self.depth > 0 {
+                        // close the item which the data ended in
+                        self.warnings.push(ReadWarning::MissingItemDelimiter);
+                        self.leave_item();
+                        return Some(Ok(DataToken::ItemEnd));
+                    }
+                    if self.lenient && self.source.position() > start {
+                        // an incomplete header at the end of the data
+                        self.warnings.push(ReadWarning::TrailingData);
+                    }
+                    self.hard_break = true;
+                    return None;
+                }
+                Err(e) => {
+                    self.hard_break = true;
+                    if self.lenient && self.depth == 0 && self.last_root_tag.is_some() {
+                        if let Ok(true) = self.skip_padding() {
+                            self.warnings.push(ReadWarning::TrailingData);
+                            return None;
+                        }
+                    }
+                    return Some(Err(e));
+                }
+            };
+            if self.lenient && self.depth == 0 {
+                if let Some(last) = self.last_root_tag {
+                    if header.tag == Tag(0x0000, 0x0000) {
+                        // zero padding after the end of the data set
+                        self.hard_break = true;
+                        return match self.skip_padding() {
+                            Ok(true) => {
+                                self.warnings.push(ReadWarning::TrailingData);
+                                None
+                            }
+                            Ok(false) => Some(Err(DataSetSyntaxError::UnexpectedToken(
+                                DataToken::ElementHeader(header),
+                            )
+                            .into())),
+                            Err(e) => Some(Err(e)),
+                        };
+                    }
+                    if header.tag == last {
+                        self.warnings
+                            .push(ReadWarning::DuplicateElement { tag: header.tag });
+                    } else if header.tag < last {
+                        self.warnings.push(ReadWarning::OutOfOrder {
+                            tag: header.tag,
+                            previous: last,
+                        });
+                    }
+                }
+                self.last_root_tag = Some(header.tag);
+            }
+            match header {
+                DataElementHeader {
                     tag,
                     vr: VR::SQ,
                     len,
-                }) => {
-                    self.in_sequence = true;
-                    self.depth += 1;
+                } => {
+                    self.enter_sequence(len);
                     Some(Ok(DataToken::SequenceStart { tag, len }))
                 }
-                Ok(DataElementHeader {
+                DataElementHeader {
+                    tag,
+                    vr: VR::UN,
+                    len,
+                } if len.is_undefined() => {
+                    // a value of unknown VR with undefined length is a
+                    // sequence in implicit VR little endian (PS3.5 6.2.2)
+                    self.enter_sequence(len);
+                    if self.implicit_vr.is_none() {
+                        match self.implicit_vr_parser() {
+                            Ok(parser) => {
+                                self.implicit_vr = Some(ImplicitVrSequence {
+                                    depth: self.depth,
+                                    parser,
+                                });
+                            }
+                            Err(e) => {
+                                self.hard_break = true;
+                                return Some(Err(e));
+                            }
+                        }
+                    }
+                    Some(Ok(DataToken::SequenceStart { tag, len }))
+                }
+                DataElementHeader {
                     tag: Tag(0xFFFE, 0xE00D),
                     ..
-                }) => {
-                    self.in_sequence = true;
+                } => {
+                    self.leave_item();
                     Some(Ok(DataToken::ItemEnd))
                 }
-                Ok(header) => {
+                DataElementHeader { tag, vr, len }
+                    if self.lenient && len.is_undefined() && tag != Tag(0x7FE0, 0x0010) =>
+                {
+                    self.warnings.push(ReadWarning::UndefinedLength { tag, vr });
+                    self.enter_sequence(len);
+                    Some(Ok(DataToken::SequenceStart { tag, len }))
+                }
+                header => {
+                    if let Some(len) = header.len().get().filter(|len| len % 2 == 1) {
+                        self.warnings.push(ReadWarning::OddLength {
+                            tag: header.tag,
+                            len,
+                        });
+                    }
+                    let header = if self.lenient {
+                        match self.read_value_leniently(header) {
+                            Ok(header) => header,
+                            Err(e) => {
+                                self.hard_break = true;
+                                return Some(Err(e));
+                            }
+                        }
+                    } else {
+                        header
+                    };
                     // save it for the next step
                     self.last_header = Some(header);
                     Some(Ok(DataToken::ElementHeader(header)))
                 }
-                Err(Error::Io(ref e)) if e.kind() == ::std::io::ErrorKind::UnexpectedEof => {
-                    // TODO there might be a better way to check for the end of
-                    // a DICOM object. This approach might ignore trailing
-                    // garbage.
-                    self.hard_break = true;
-                    None
+            }
+        }
+    }
+}
+
+impl<'s, S: 's, P, D> DataSetReader<S, P, D>
+where
+    S: Read,
+    P: Parse<dyn Read + 's>,
+    D: DataDictionary + Clone,
+{
+    /// Enter a sequence of the given length,
+    /// whose items are expected next.
+    fn enter_sequence(&mut self, len: Length) {
+        self.in_sequence = true;
+        self.depth += 1;
+        let end = len.get().map(|len| self.source.position() + u64::from(len));
+        self.ends.push(end);
+    }
+
+    /// Enter an item of the given length in the current sequence.
+    fn enter_item(&mut self, len: Length) {
+        self.in_sequence = false;
+        let end = len.get().map(|len| self.source.position() + u64::from(len));
+        self.ends.push(end);
+        match &mut self.implicit_vr {
+            Some(sequence) => sequence.parser.enter_item(),
+            None => self.parser.enter_item(),
+        }
+    }
+
+    /// Leave the current item.
+    fn leave_item(&mut self) {
+        self.in_sequence = true;
+        self.ends.pop();
+        match &mut self.implicit_vr {
+            Some(sequence) => sequence.parser.leave_item(),
+            None => self.parser.leave_item(),
+        }
+    }
+
+    /// Close the current sequence or item if it has a defined length
+    /// and all of its contents were read.
+    fn close_exhausted(&mut self) -> Option<Result<DataToken>> {
+        let end = (*self.ends.last()?)?;
+        let position = self.source.position();
+        if position < end {
+            return None;
+        }
+        if position > end {
+            if !self.lenient {
+                self.hard_break = true;
+                return Some(Err(DataSetSyntaxError::LengthOverrun.into()));
+            }
+            self.warnings.push(ReadWarning::LengthOverrun {
+                excess: position - end,
+            });
+        }
+        if self.in_sequence {
+            self.leave_sequence();
+            Some(Ok(DataToken::SequenceEnd))
+        } else {
+            self.leave_item();
+            Some(Ok(DataToken::ItemEnd))
+        }
+    }
+
+    /// Leave the current sequence.
+    fn leave_sequence(&mut self) {
+        self.ends.pop();
+        self.depth -= 1;
+        self.in_sequence = false;
+        if matches!(&self.implicit_vr, Some(sequence) if self.depth < sequence.depth) {
+            self.implicit_vr = None;
+        }
+    }
+
+    /// Read the rest of the data,
+    /// and check whether it is made of zeros only.
+    fn skip_padding(&mut self) -> Result<bool> {
+        let mut rest = Vec::new();
+        self.source.read_to_end(&mut rest)?;
+        Ok(rest.iter().all(|b| *b == 0))
+    }
+
+    /// Create the parser of the contents of a sequence of unknown VR,
+    /// in implicit VR little endian with the dictionary of the reader.
+    fn implicit_vr_parser(&self) -> Result<ImplicitVrParser<D>> {
+        let text = self
+            .charset
+            .get_codec()
+            .ok_or(Error::UnsupportedCharacterSet)?;
+        let mut parser = DicomParser::new(
+            ImplicitVRLittleEndianDecoder::with_dict(self.dict.clone()),
+            LittleEndianBasicDecoder,
+            text,
+        );
+        parser.set_utc_offset(self.utc_offset);
+        Ok(parser)
+    }
+
+    /// Decode the next data element header.
+    fn decode_header(&mut self) -> Result<DataElementHeader> {
+        match &mut self.implicit_vr {
+            Some(sequence) => {
+                let mut buf = [0; 8];
+                self.source.read_exact(&mut buf)?;
+                sequence.parser.decode_header(&mut Cursor::new(buf))
+            }
+            None => self.parser.decode_header(&mut self.source),
+        }
+    }
+
+    /// Decode the next item header.
+    fn decode_item_header(&mut self) -> Result<SequenceItemHeader> {
+        match &mut self.implicit_vr {
+            Some(sequence) => {
+                let mut buf = [0; 8];
+                self.source.read_exact(&mut buf)?;
+                sequence.parser.decode_item_header(&mut Cursor::new(buf))
+            }
+            None => self.parser.decode_item_header(&mut self.source),
+        }
+    }
+
+    /// Read the value of the given data element header.
+    fn read_value(&mut self, header: &DataElementHeader) -> Result<PrimitiveValue> {
+        match &mut self.implicit_vr {
+            Some(sequence) => {
+                let mut bytes = Vec::new();
+                (&mut self.source)
+                    .take(u64::from(header.len().0))
+                    .read_to_end(&mut bytes)?;
+                sequence.parser.read_value(&mut Cursor::new(bytes), header)
+            }
+            None => self.parser.read_value(&mut self.source, header),
+        }
+    }
+
+    /// Define the specific character set of subsequent text values.
+    fn set_character_set(&mut self, charset: SpecificCharacterSet) -> Result<()> {
+        self.charset = charset;
+        if let Some(sequence) = &mut self.implicit_vr {
+            sequence.parser.set_character_set(charset)?;
+        }
+        self.parser.set_character_set(charset)
+    }
+
+    /// Define the offset from UTC of subsequent date-time values.
+    fn set_utc_offset(&mut self, offset: FixedOffset) {
+        self.utc_offset = offset;
+        if let Some(sequence) = &mut self.implicit_vr {
+            sequence.parser.set_utc_offset(offset);
+        }
+        self.parser.set_utc_offset(offset);
+    }
+
+    /// Read the value of a data element along with its header,
+    /// so that a value which does not fit the VR in the header can be
+    /// read with the VR in the dictionary, or kept as bytes of unknown VR.
+    /// Returns the header with the VR which the value was read with.
+    fn read_value_leniently(&mut self, header: DataElementHeader) -> Result<DataElementHeader> {
+        let len = match header.len().get() {
+            Some(len) => len as usize,
+            None => return Ok(header),
+        };
+        let mut bytes = vec![0; len];
+        self.source.read_exact(&mut bytes)?;
+
+        // values of unknown VR are resolved by the object
+        let expected = match header.vr {
+            VR::UN => None,
+            vr => self
+                .expected_vr(header.tag)
+                .filter(|expected| *expected != vr),
+        };
+        if let Some(expected) = expected {
+            self.warnings.push(ReadWarning::VrMismatch {
+                tag: header.tag,
+                found: header.vr,
+                expected,
+            });
+        }
+        let mut error = None;
+        for vr in std::iter::once(header.vr).chain(expected) {
+            let header = DataElementHeader::new(header.tag, vr, header.len);
+            let mut src = Cursor::new(bytes);
+            let value = match &mut self.implicit_vr {
+                Some(sequence) => sequence.parser.read_value(&mut src, &header),
+                None => self.parser.read_value(&mut src, &header),
+            };
+            match value {
+                Ok(value) => {
+                    self.pending_value = Some(value);
+                    return Ok(header);
                 }
                 Err(e) => {
-                    self.hard_break = true;
-                    Some(Err(e))
+                    bytes = src.into_inner();
+                    error.get_or_insert(e);
                 }
             }
         }
+        self.warnings.push(ReadWarning::InvalidValue {
+            tag: header.tag,
+            vr: header.vr,
+            message: error.map(|e| e.to_string()).unwrap_or_default(),
+        });
+        self.pending_value = Some(PrimitiveValue::U8(bytes.into()));
+        Ok(DataElementHeader::new(header.tag, VR::UN, header.len))
+    }
+
+    /// The VR of a standard attribute in the dictionary,
+    /// if it is not ambiguous.
+    fn expected_vr(&self, tag: Tag) -> Option<VR> {
+        if tag.is_private() || tag.element() == 0 || tag.group() == 0xFFFE {
+            return None;
+        }
+        match self.dict.by_tag(tag)?.virtual_vr() {
+            VirtualVr::Exact(VR::UN) => None,
+            VirtualVr::Exact(vr) => Some(vr),
+            _ => None,
+        }
+    }
+}
+
+/// A data set token along with its place in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionedToken {
+    /// the byte offset of the token from the start of the source
+    pub position: u64,
+    /// the number of bytes which the token is encoded with,
+    /// which is zero for the delimiters implied by a defined length
+    /// or assumed in lenient mode
+    pub len: u64,
+    /// the token itself
+    pub token: DataToken,
+}
+
+/// An iterator of data set tokens with their byte offsets and encoded
+/// lengths, obtained with [`DataSetReader::with_positions`].
+/// The offsets are counted from the bytes read,
+/// so the source does not need to be seekable.
+#[derive(Debug)]
+pub struct PositionedDataSetReader<S, P, D> {
+    inner: DataSetReader<S, P, D>,
+    /// the place of a value which was read along with its header
+    value_span: Option<(u64, u64)>,
+}
+
+impl<S, P, D> PositionedDataSetReader<S, P, D> {
+    /// Retrieve the underlying data set reader.
+    pub fn inner(&self) -> &DataSetReader<S, P, D> {
+        &self.inner
+    }
+
+    /// Retrieve the underlying data set reader for modification.
+    pub fn inner_mut(&mut self) -> &mut DataSetReader<S, P, D> {
+        &mut self.inner
+    }
+
+    /// Recover the underlying data set reader.
+    pub fn into_inner(self) -> DataSetReader<S, P, D> {
+        self.inner
+    }
+}
+
+impl<'s, S: 's, P, D> Iterator for PositionedDataSetReader<S, P, D>
+where
+    S: Read,
+    P: Parse<dyn Read + 's>,
+    D: DataDictionary + Clone,
+{
+    type Item = Result<PositionedToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.inner.position();
+        let token = match self.inner.next()? {
+            Ok(token) => token,
+            Err(e) => return Some(Err(e)),
+        };
+        let end = self.inner.position();
+        let (position, len) = match &token {
+            DataToken::ElementHeader(header) if self.inner.pending_value.is_some() => {
+                // the value was read too, so it ends the bytes read
+                let value_len = u64::from(header.len().0);
+                self.value_span = Some((end - value_len, value_len));
+                (start, end - start - value_len)
+            }
+            DataToken::PrimitiveValue(_) => self.value_span.take().unwrap_or((start, end - start)),
+            _ => (start, end - start),
+        };
+        Some(Ok(PositionedToken {
+            position,
+            len,
+            token,
+        }))
     }
 }
 
@@ -444,3 +1063,193 @@ impl Header for DicomElementMarker {
         self.header.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{DataSetReader, DataToken, PositionedToken};
+    use crate::parser::DicomParser;
+    use dicom_core::header::{DataElementHeader, Length, Tag, VR};
+    use dicom_core::value::PrimitiveValue;
+    use dicom_encoding::decode::basic::{BigEndianBasicDecoder, LittleEndianBasicDecoder};
+    use dicom_encoding::text::{DefaultCharacterSetCodec, TextCodec};
+    use dicom_encoding::transfer_syntax::explicit_be::ExplicitVRBigEndianDecoder;
+    use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianDecoder;
+    use dicom_encoding::transfer_syntax::implicit_le::ImplicitVRLittleEndianDecoder;
+    use smallvec::smallvec;
+    use std::io::Read;
+
+    #[test]
+    fn read_tokens_with_positions() {
+        #[rustfmt::skip]
+        let raw: &[u8] = &[
+            // (0040,A730) ContentSequence, 24 bytes
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0, 0, 24, 0, 0, 0,
+            // item, 16 bytes
+            0xFE, 0xFF, 0x00, 0xE0, 16, 0, 0, 0,
+            // (0040,A010) RelationshipType
+            0x40, 0x00, 0x10, 0xA0, b'C', b'S', 8, 0,
+            b'C', b'O', b'N', b'T', b'A', b'I', b'N', b'S',
+        ];
+        let parser = DicomParser::new(
+            ExplicitVRLittleEndianDecoder::<dyn Read>::default(),
+            LittleEndianBasicDecoder,
+            Box::new(DefaultCharacterSetCodec) as Box<dyn TextCodec>,
+        );
+        let tokens: Vec<_> = DataSetReader::new(raw, parser)
+            .with_positions()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        // values read along with their header in lenient mode
+        // are placed all the same
+        let parser = DicomParser::new(
+            ExplicitVRLittleEndianDecoder::<dyn Read>::default(),
+            LittleEndianBasicDecoder,
+            Box::new(DefaultCharacterSetCodec) as Box<dyn TextCodec>,
+        );
+        let lenient_tokens: Vec<_> = DataSetReader::new(raw, parser)
+            .lenient(true)
+            .with_positions()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(lenient_tokens, tokens);
+
+        let token = |position, len, token| PositionedToken {
+            position,
+            len,
+            token,
+        };
+        assert_eq!(
+            tokens,
+            vec![
+                token(
+                    0,
+                    12,
+                    DataToken::SequenceStart {
+                        tag: Tag(0x0040, 0xA730),
+                        len: Length(24),
+                    }
+                ),
+                token(12, 8, DataToken::ItemStart { len: Length(16) }),
+                token(
+                    20,
+                    8,
+                    DataToken::ElementHeader(DataElementHeader::new(
+                        Tag(0x0040, 0xA010),
+                        VR::CS,
+                        Length(8),
+                    ))
+                ),
+                token(
+                    28,
+                    8,
+                    DataToken::PrimitiveValue(PrimitiveValue::Strs(smallvec![
+                        "CONTAINS".to_string()
+                    ]))
+                ),
+                token(36, 0, DataToken::ItemEnd),
+                token(36, 0, DataToken::SequenceEnd),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_vrs_from_the_context_of_each_item() {
+        #[rustfmt::skip]
+        let raw: &[u8] = &[
+            // (0028,0103) PixelRepresentation: 1
+            0x28, 0x00, 0x03, 0x01, 2, 0, 0, 0, 1, 0,
+            // (0028,3000) ModalityLUTSequence, 28 bytes
+            0x28, 0x00, 0x00, 0x30, 28, 0, 0, 0,
+            // item, 20 bytes
+            0xFE, 0xFF, 0x00, 0xE0, 20, 0, 0, 0,
+            // (0028,0103) PixelRepresentation: 0
+            0x28, 0x00, 0x03, 0x01, 2, 0, 0, 0, 0, 0,
+            // (0028,0106) SmallestImagePixelValue
+            0x28, 0x00, 0x06, 0x01, 2, 0, 0, 0, 1, 0,
+            // (0028,3002) LUTDescriptor
+            0x28, 0x00, 0x02, 0x30, 6, 0, 0, 0, 0, 1, 0, 0, 16, 0,
+        ];
+        let parser = DicomParser::new(
+            ImplicitVRLittleEndianDecoder::<dyn Read, _>::default(),
+            LittleEndianBasicDecoder,
+            Box::new(DefaultCharacterSetCodec) as Box<dyn TextCodec>,
+        );
+        let vrs: Vec<_> = DataSetReader::new(raw, parser)
+            .filter_map(|token| match token.unwrap() {
+                DataToken::ElementHeader(header) => Some((header.tag, header.vr)),
+                _ => None,
+            })
+            .collect();
+        // the pixel representation of the item does not leak
+        // into the rest of the root data set
+        assert_eq!(
+            vrs,
+            vec![
+                (Tag(0x0028, 0x0103), VR::US),
+                (Tag(0x0028, 0x0103), VR::US),
+                (Tag(0x0028, 0x0106), VR::US),
+                (Tag(0x0028, 0x3002), VR::SS),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_sequence_of_unknown_vr_in_big_endian() {
+        #[rustfmt::skip]
+        let raw: &[u8] = &[
+            // (0009,1010) UN, undefined length, in explicit VR big endian
+            0x00, 0x09, 0x10, 0x10, b'U', b'N', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // item, undefined length, in implicit VR little endian
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // (0028,0103) PixelRepresentation: 1
+            0x28, 0x00, 0x03, 0x01, 2, 0, 0, 0, 1, 0,
+            // (0028,0106) SmallestImagePixelValue: -2
+            0x28, 0x00, 0x06, 0x01, 2, 0, 0, 0, 0xFE, 0xFF,
+            // item delimiter
+            0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0,
+            // sequence delimiter
+            0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0,
+            // (0010,0020) PatientID, in explicit VR big endian
+            0x00, 0x10, 0x00, 0x20, b'L', b'O', 0, 4, b'I', b'D', b'0', b'1',
+        ];
+        let parser = DicomParser::new(
+            ExplicitVRBigEndianDecoder::<dyn Read>::default(),
+            BigEndianBasicDecoder,
+            Box::new(DefaultCharacterSetCodec) as Box<dyn TextCodec>,
+        );
+        let tokens: Vec<_> = DataSetReader::new(raw, parser)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        // undefined lengths are never equal to each other
+        assert!(matches!(
+            tokens[0],
+            DataToken::SequenceStart { tag: Tag(0x0009, 0x1010), len } if len.is_undefined()
+        ));
+        assert!(matches!(tokens[1], DataToken::ItemStart { len } if len.is_undefined()));
+        assert_eq!(
+            tokens[2..],
+            [
+                DataToken::ElementHeader(DataElementHeader::new(
+                    Tag(0x0028, 0x0103),
+                    VR::US,
+                    Length(2),
+                )),
+                DataToken::PrimitiveValue(PrimitiveValue::U16(smallvec![1])),
+                DataToken::ElementHeader(DataElementHeader::new(
+                    Tag(0x0028, 0x0106),
+                    VR::SS,
+                    Length(2),
+                )),
+                DataToken::PrimitiveValue(PrimitiveValue::I16(smallvec![-2])),
+                DataToken::ItemEnd,
+                DataToken::SequenceEnd,
+                DataToken::ElementHeader(DataElementHeader::new(
+                    Tag(0x0010, 0x0020),
+                    VR::LO,
+                    Length(4),
+                )),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(smallvec!["ID01".to_string()])),
+            ]
+        );
+    }
+}
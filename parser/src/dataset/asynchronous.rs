@@ -0,0 +1,296 @@
+//! Reading of data sets from asynchronous sources.
+use super::{DataSetReader, DataToken, ReadWarning};
+use crate::error::{Error, Result};
+use crate::parser::{DynamicDicomParser, Parse};
+use chrono::FixedOffset;
+use dicom_core::dictionary::DataDictionary;
+use dicom_core::header::Header;
+use dicom_core::VR;
+use dicom_dictionary_std::StandardDataDictionary;
+use dicom_encoding::text::SpecificCharacterSet;
+use dicom_encoding::transfer_syntax::TransferSyntax;
+use std::collections::VecDeque;
+use std::io::{Cursor, Read};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// The greatest size of a data element header.
+const MAX_HEADER_LEN: usize = 12;
+
+/// The size of the chunks read from the source.
+const CHUNK_LEN: usize = 8192;
+
+/// A reader of the tokens of a DICOM data set from an asynchronous source,
+/// with the same behavior as [`DataSetReader`].
+///
+/// The bytes of each token are read from the source into a buffer before
+/// the token is parsed, so parsing never waits on the source.
+/// The futures of this reader are `Send` when the source and the
+/// data dictionary are, so they can be spawned on a multi-threaded runtime.
+#[derive(Debug)]
+pub struct AsyncDataSetReader<S, P, D> {
+    source: S,
+    reader: DataSetReader<VecDeque<u8>, P, D>,
+    /// whether the source has no more data
+    eof: bool,
+}
+
+impl<S> AsyncDataSetReader<S, DynamicDicomParser, StandardDataDictionary> {
+    /// Creates a new reader with the given source,
+    /// while considering the given transfer syntax and specific character set.
+    pub fn new_with(source: S, ts: &TransferSyntax, cs: SpecificCharacterSet) -> Result<Self> {
+        Ok(AsyncDataSetReader::new(
+            source,
+            DataSetReader::new_with(VecDeque::new(), ts, cs)?,
+        ))
+    }
+}
+
+impl<S, D> AsyncDataSetReader<S, DynamicDicomParser, D> {
+    /// Creates a new reader with the given source and data dictionary,
+    /// while considering the given transfer syntax and specific character set.
+    pub fn new_with_dictionary(
+        source: S,
+        dict: D,
+        ts: &TransferSyntax,
+        cs: SpecificCharacterSet,
+    ) -> Result<Self> {
+        Ok(AsyncDataSetReader::new(
+            source,
+            DataSetReader::new_with_dictionary(VecDeque::new(), dict, ts, cs)?,
+        ))
+    }
+}
+
+impl<S, P, D> AsyncDataSetReader<S, P, D> {
+    /// Creates a new reader of the given source,
+    /// which parses the data with the given reader of a buffer.
+    pub fn new(source: S, reader: DataSetReader<VecDeque<u8>, P, D>) -> Self {
+        AsyncDataSetReader {
+            source,
+            reader,
+            eof: false,
+        }
+    }
+
+    /// Enable or disable the lenient mode.
+    /// See [`DataSetReader::lenient`].
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.reader = self.reader.lenient(lenient);
+        self
+    }
+
+    /// Retrieve the warnings about the defects found so far.
+    pub fn warnings(&self) -> &[ReadWarning] {
+        self.reader.warnings()
+    }
+
+    /// Take the warnings about the defects found so far,
+    /// leaving none behind.
+    pub fn take_warnings(&mut self) -> Vec<ReadWarning> {
+        self.reader.take_warnings()
+    }
+
+    /// The number of bytes parsed so far.
+    pub fn position(&self) -> u64 {
+        self.reader.position()
+    }
+}
+
+impl<S, P, D> AsyncDataSetReader<S, P, D>
+where
+    P: Parse<dyn Read>,
+{
+    /// Define the offset from UTC of date-time (DT) values without an
+    /// explicit offset. See [`DataSetReader::with_default_utc_offset`].
+    pub fn with_default_utc_offset(mut self, offset: FixedOffset) -> Self {
+        self.reader = self.reader.with_default_utc_offset(offset);
+        self
+    }
+}
+
+impl<S, P, D> AsyncDataSetReader<S, P, D>
+where
+    S: AsyncRead + Unpin,
+    P: Parse<dyn Read>,
+    D: DataDictionary + Clone,
+{
+    /// Read the next token of the data set,
+    /// or `None` at the end of the data set.
+    pub async fn next(&mut self) -> Option<Result<DataToken>> {
+        // the demand may grow once the buffer holds the header
+        let mut demand = self.demand();
+        loop {
+            if let Err(e) = self.fill(demand).await {
+                self.reader.hard_break = true;
+                return Some(Err(e));
+            }
+            let new_demand = self.demand();
+            if new_demand == demand {
+                break;
+            }
+            demand = new_demand;
+        }
+        self.reader.next()
+    }
+
+    /// Read the bytes in the source into the buffer
+    /// until it holds the given number of bytes,
+    /// or all of them if `None`.
+    async fn fill(&mut self, demand: Option<usize>) -> Result<()> {
+        let buffer = self.reader.source.get_mut();
+        let mut chunk = [0; CHUNK_LEN];
+        while !self.eof && !matches!(demand, Some(len) if buffer.len() >= len) {
+            let len = self.source.read(&mut chunk).await.map_err(Error::from)?;
+            if len == 0 {
+                self.eof = true;
+            }
+            buffer.extend(&chunk[..len]);
+        }
+        Ok(())
+    }
+
+    /// The number of bytes which the next token needs in the buffer,
+    /// or `None` if it needs the rest of the data.
+    fn demand(&mut self) -> Option<usize> {
+        let reader = &mut self.reader;
+        if reader.hard_break {
+            return Some(0);
+        }
+        if let Some(header) = reader.last_header {
+            if reader.pending_value.is_some() {
+                return Some(0);
+            }
+            return header.len().get().map(|len| len as usize);
+        }
+        if matches!(reader.ends.last(), Some(Some(end)) if reader.source.position() >= *end) {
+            // the current sequence or item is closed without reading
+            return Some(0);
+        }
+        if reader.in_sequence {
+            // item header
+            return Some(8);
+        }
+
+        // decode the header from a copy of the start of the buffer
+        let buffer = reader.source.get_mut();
+        let len = buffer.len().min(MAX_HEADER_LEN);
+        let mut peek = Cursor::new(buffer.make_contiguous()[..len].to_vec());
+        let header = match &mut reader.implicit_vr {
+            Some(sequence) => sequence.parser.decode_header(&mut peek),
+            None => reader.parser.decode_header(&mut peek),
+        };
+        match header {
+            Ok(header) => {
+                let header_len = peek.position() as usize;
+                // the lenient reader reads values along with their header
+                match header.len().get() {
+                    Some(len) if reader.lenient && header.vr != VR::SQ => {
+                        Some(header_len + len as usize)
+                    }
+                    _ => Some(header_len),
+                }
+            }
+            Err(_) if len < MAX_HEADER_LEN => Some(MAX_HEADER_LEN),
+            // let the reader report the error
+            Err(_) => Some(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsyncDataSetReader;
+    use crate::dataset::DataSetReader;
+    use crate::parser::DicomParser;
+    use dicom_encoding::decode::basic::LittleEndianBasicDecoder;
+    use dicom_encoding::text::{DefaultCharacterSetCodec, SpecificCharacterSet, TextCodec};
+    use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianDecoder;
+    use dicom_encoding::transfer_syntax::{Codec, Endianness, TransferSyntax};
+    use std::collections::VecDeque;
+    use std::io::Read;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use tokio::io::{AsyncRead, ReadBuf};
+
+    /// A source which yields one byte at a time.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl AsyncRead for Trickle<'_> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context,
+            buf: &mut ReadBuf,
+        ) -> Poll<std::io::Result<()>> {
+            if let Some((first, rest)) = self.0.split_first() {
+                buf.put_slice(&[*first]);
+                self.0 = rest;
+            }
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn read_tokens_from_async_source() {
+        #[rustfmt::skip]
+        let raw: &[u8] = &[
+            // (0008,0016) SOPClassUID, odd length
+            0x08, 0x00, 0x16, 0x00, b'U', b'I', 5, 0, b'1', b'.', b'2', b'.', b'3',
+            // (0040,A730) ContentSequence, 24 bytes
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0, 0, 24, 0, 0, 0,
+            // item, 16 bytes
+            0xFE, 0xFF, 0x00, 0xE0, 16, 0, 0, 0,
+            // (0040,A010) RelationshipType
+            0x40, 0x00, 0x10, 0xA0, b'C', b'S', 8, 0,
+            b'C', b'O', b'N', b'T', b'A', b'I', b'N', b'S',
+            // (0042,0011) EncapsulatedDocument
+            0x42, 0x00, 0x11, 0x00, b'O', b'B', 0, 0, 4, 0, 0, 0, 1, 2, 3, 4,
+        ];
+        let reader = |src| {
+            let parser = DicomParser::new(
+                ExplicitVRLittleEndianDecoder::<dyn Read>::default(),
+                LittleEndianBasicDecoder,
+                Box::new(DefaultCharacterSetCodec) as Box<dyn TextCodec>,
+            );
+            DataSetReader::new(src, parser)
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for &lenient in &[false, true] {
+            let expected: Vec<_> = reader(VecDeque::from(raw.to_vec()))
+                .lenient(lenient)
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            let mut dataset =
+                AsyncDataSetReader::new(Trickle(raw), reader(VecDeque::new())).lenient(lenient);
+            let tokens = runtime.block_on(async {
+                let mut tokens = Vec::new();
+                while let Some(token) = dataset.next().await {
+                    tokens.push(token.unwrap());
+                }
+                tokens
+            });
+            assert_eq!(tokens, expected);
+            assert_eq!(dataset.position(), raw.len() as u64);
+            assert_eq!(dataset.warnings().len(), 1);
+        }
+    }
+
+    #[test]
+    fn tokens_can_be_read_from_another_thread() {
+        fn is_send<T: Send>(_: &T) {}
+
+        let ts = TransferSyntax::new(
+            "1.2.840.10008.1.2.1",
+            "Explicit VR Little Endian",
+            Endianness::Little,
+            true,
+            Codec::None,
+        );
+        let mut dataset =
+            AsyncDataSetReader::new_with(Trickle(&[]), &ts, SpecificCharacterSet::Default).unwrap();
+        is_send(&dataset.next());
+    }
+}
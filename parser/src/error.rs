@@ -122,12 +122,16 @@ impl From<EncodingError> for Error {
 pub enum DataSetSyntaxError {
     PrematureEnd,
     UnexpectedToken(DataToken),
+    /// The contents of a sequence or item exceed its defined length.
+    LengthOverrun,
 }
 
 impl fmt::Display for DataSetSyntaxError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            DataSetSyntaxError::PrematureEnd => f.write_str(self.description()),
+            DataSetSyntaxError::PrematureEnd | DataSetSyntaxError::LengthOverrun => {
+                f.write_str(self.description())
+            }
             DataSetSyntaxError::UnexpectedToken(ref token) => {
                 write!(f, "{} {}", self.description(), token)
             }
@@ -140,6 +144,7 @@ impl ::std::error::Error for DataSetSyntaxError {
         match self {
             DataSetSyntaxError::PrematureEnd => "data set ended prematurely",
             DataSetSyntaxError::UnexpectedToken(_) => "unexpected data set token",
+            DataSetSyntaxError::LengthOverrun => "sequence or item content exceeds its length",
         }
     }
 }
@@ -3,7 +3,9 @@
 //! ultimately enables the user to perceive the DICOM object as a sequence of
 //! tokens.
 //!
-//! For the time being, all APIs are based on synchronous I/O.
+//! Data sets are read with synchronous I/O, or from sources implementing
+//! tokio's `AsyncRead` with `dataset::AsyncDataSetReader`
+//! when the `async` feature is enabled.
 //!
 //! For a more intuitive, object-oriented API, please see the `dicom-object`
 //! crate.
@@ -120,6 +120,41 @@ where
     }
 }
 
+/// A reader which keeps count of the bytes read from its source,
+/// so that the position in the source is known without seeking.
+#[derive(Debug)]
+pub struct CountingReader<S> {
+    inner: S,
+    position: u64,
+}
+
+impl<S> CountingReader<S> {
+    pub fn new(inner: S) -> Self {
+        CountingReader { inner, position: 0 }
+    }
+
+    /// The number of bytes read so far.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Retrieve the underlying source for modification.
+    /// Reading from it directly is not counted.
+    #[cfg(feature = "async")]
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+}
+
+impl<S: Read> Read for CountingReader<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
 /// Obtain an iterator of `n` void elements.
 /// Useful for doing something N times as efficiently as possible.
 pub fn n_times(n: usize) -> VoidRepeatN {
@@ -157,8 +192,8 @@ impl ExactSizeIterator for VoidRepeatN {
 #[cfg(test)]
 mod tests {
     use super::n_times;
-    use super::SeekInterval;
-    use std::io::{Cursor, Write};
+    use super::{CountingReader, SeekInterval};
+    use std::io::{Cursor, Read, Write};
 
     #[test]
     fn void_repeat_n() {
@@ -199,4 +234,16 @@ mod tests {
         }
         assert_eq!(buf.into_inner(), vec![0xFFu8, 0xFF, 0, 0, 0, 0, 0, 0])
     }
+
+    #[test]
+    fn counting_reader() {
+        let mut reader = CountingReader::new(&[1u8, 2, 3, 4, 5][..]);
+        let mut buf = [0; 3];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(reader.position(), 3);
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, vec![4, 5]);
+        assert_eq!(reader.position(), 5);
+    }
 }
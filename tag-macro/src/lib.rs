@@ -0,0 +1,181 @@
+//! This crate provides the [`tag!`] macro, which resolves a DICOM
+//! attribute tag at compile time against the standard dictionary.
+//!
+//! The macro expands to a [`Tag`] value, so `dicom_core` must be
+//! a dependency of the crate using it.
+//!
+//! ```
+//! use dicom_core::Tag;
+//! use dicom_tag_macro::tag;
+//!
+//! assert_eq!(tag!(PatientName), Tag(0x0010, 0x0010));
+//! assert_eq!(tag!("(7FE0,0010)"), Tag(0x7FE0, 0x0010));
+//! assert_eq!(tag!("00080060"), Tag(0x0008, 0x0060));
+//! // repeating groups and elements take the actual group or element
+//! assert_eq!(tag!(OverlayData[0x6002]), Tag(0x6002, 0x3000));
+//! ```
+//!
+//! Attributes which are not in the dictionary are rejected:
+//!
+//! ```compile_fail
+//! # use dicom_tag_macro::tag;
+//! let tag = tag!(PatientNmae);
+//! ```
+//!
+//! [`Tag`]: dicom_core::Tag
+extern crate proc_macro;
+
+use dicom_core::dictionary::{DataDictionary, TagRange};
+use dicom_core::Tag;
+use dicom_dictionary_std::StandardDataDictionary;
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{bracketed, Ident, LitInt, LitStr, Token};
+
+/// The input of the `tag!` macro.
+#[derive(Debug, Clone, PartialEq)]
+enum TagInput {
+    /// a tag in text form, e.g. `"(0010,0010)"`
+    Text(String),
+    /// an attribute keyword, with an optional group or element number
+    /// for repeating attributes, e.g. `OverlayData[0x6002]`
+    Keyword(String, Option<u16>),
+}
+
+impl Parse for TagInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        let out = if lookahead.peek(LitStr) {
+            TagInput::Text(input.parse::<LitStr>()?.value())
+        } else if lookahead.peek(Ident) {
+            let keyword = input.parse::<Ident>()?.to_string();
+            let index = if input.peek(syn::token::Bracket) {
+                let content;
+                bracketed!(content in input);
+                Some(content.parse::<LitInt>()?.base10_parse::<u16>()?)
+            } else {
+                None
+            };
+            TagInput::Keyword(keyword, index)
+        } else {
+            return Err(lookahead.error());
+        };
+        // allow a trailing comma
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(out)
+    }
+}
+
+/// Resolve the macro input into a tag.
+fn resolve(input: &TagInput) -> Result<Tag, String> {
+    match input {
+        TagInput::Text(text) => text.parse::<Tag>().map_err(|e| {
+            if text.trim().parse::<TagRange>().is_ok() {
+                format!("`{}` is a tag range, not a single tag", text)
+            } else {
+                format!("invalid tag `{}`: {}", text, e)
+            }
+        }),
+        TagInput::Keyword(keyword, index) => {
+            let entry = StandardDataDictionary
+                .by_name(keyword)
+                .ok_or_else(|| format!("unknown attribute `{}`", keyword))?;
+            match (entry.tag, *index) {
+                (TagRange::Single(tag), None) => Ok(tag),
+                (TagRange::Single(_), Some(_)) => Err(format!(
+                    "attribute `{}` is not a repeating group or element",
+                    keyword
+                )),
+                // the first of the range
+                (TagRange::Group100(tag), None) | (TagRange::Element100(tag), None) => Ok(tag),
+                (TagRange::Group100(Tag(group, elem)), Some(g)) => {
+                    if g & 0xFF00 != group {
+                        return Err(format!(
+                            "group {:04X} is not in the range of `{}` ({:02X}xx,{:04X})",
+                            g,
+                            keyword,
+                            group >> 8,
+                            elem
+                        ));
+                    }
+                    Ok(Tag(g, elem))
+                }
+                (TagRange::Element100(Tag(group, elem)), Some(e)) => {
+                    if e & 0xFF00 != elem {
+                        return Err(format!(
+                            "element {:04X} is not in the range of `{}` ({:04X},{:02X}xx)",
+                            e,
+                            keyword,
+                            group,
+                            elem >> 8
+                        ));
+                    }
+                    Ok(Tag(group, e))
+                }
+            }
+        }
+    }
+}
+
+/// Resolve a DICOM attribute tag at compile time.
+///
+/// The macro accepts either:
+///
+/// - the keyword of an attribute in the standard dictionary,
+///   such as `tag!(PatientName)`;
+/// - a tag in text form, such as `tag!("(0010,0010)")`, in any of the
+///   forms accepted by the `FromStr` implementation of `Tag`;
+/// - the keyword of a repeating attribute followed by the actual group
+///   or element number in brackets, such as `tag!(OverlayData[0x6002])`.
+///
+/// Unknown keywords, malformed tags and out of range group or element
+/// numbers result in a compile error.
+#[proc_macro]
+pub fn tag(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as TagInput);
+    match resolve(&input) {
+        Ok(Tag(group, element)) => quote!(::dicom_core::Tag(#group, #element)).into(),
+        Err(msg) => syn::Error::new(proc_macro2::Span::call_site(), msg)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_tags() {
+        let keyword = |k: &str, i| TagInput::Keyword(k.to_string(), i);
+        assert_eq!(
+            resolve(&keyword("PatientName", None)),
+            Ok(Tag(0x0010, 0x0010))
+        );
+        assert_eq!(
+            resolve(&keyword("CommandField", None)),
+            Ok(Tag(0x0000, 0x0100))
+        );
+        assert_eq!(
+            resolve(&TagInput::Text("(0008,0060)".to_string())),
+            Ok(Tag(0x0008, 0x0060))
+        );
+        assert_eq!(
+            resolve(&keyword("OverlayData", Some(0x6002))),
+            Ok(Tag(0x6002, 0x3000))
+        );
+        assert_eq!(
+            resolve(&keyword("SourceImageIDs", Some(0x3105))),
+            Ok(Tag(0x0020, 0x3105))
+        );
+
+        assert!(resolve(&keyword("PatientNmae", None)).is_err());
+        assert!(resolve(&keyword("PatientName", Some(0x0011))).is_err());
+        assert!(resolve(&keyword("OverlayData", Some(0x7002))).is_err());
+        assert!(resolve(&TagInput::Text("(60xx,3000)".to_string())).is_err());
+        assert!(resolve(&TagInput::Text("(0010,00G0)".to_string())).is_err());
+    }
+}